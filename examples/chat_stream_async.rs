@@ -0,0 +1,55 @@
+//! Example: Streaming chat completion (async)
+//!
+//! This example demonstrates how to print the assistant's reply
+//! as it is generated, using the streaming chat API.
+//!
+//! Run with: cargo run --example chat_stream_async
+//!
+//! Note: Requires a running Ollama server with a model installed
+//! (e.g., qwen3:0.6b, llama3.2, etc.)
+
+use std::io::Write;
+
+use futures::StreamExt;
+use ollama_oxide::{ChatMessage, ChatRequest, OllamaApiAsync, OllamaClient};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create client with default configuration
+    let client = OllamaClient::default()?;
+
+    // Model to use (change to your installed model)
+    let model = "qwen3:0.6b";
+
+    println!("Streaming chat with model: {}\n", model);
+
+    let request = ChatRequest::new(
+        model,
+        [
+            ChatMessage::system("You are a helpful assistant. Be concise."),
+            ChatMessage::user("Explain Rust ownership in three sentences."),
+        ],
+    );
+
+    let mut stream = client.chat_stream(&request).await?;
+
+    print!("Assistant: ");
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        print!("{}", chunk.content().unwrap_or(""));
+        std::io::stdout().flush()?;
+
+        // The final chunk carries timing and token counts
+        if chunk.is_done() {
+            println!("\n");
+            println!("Done reason: {:?}", chunk.done_reason());
+            println!("Prompt tokens: {:?}", chunk.prompt_tokens());
+            println!("Completion tokens: {:?}", chunk.completion_tokens());
+            if let Some(tps) = chunk.tokens_per_second() {
+                println!("Tokens/sec: {:.2}", tps);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! Example: Tool-calling agent loop
+//!
+//! Demonstrates how `Agent` runs the chat and tool-calling loop over a
+//! `ToolRegistry` until the model answers without requesting tools.
+//!
+//! Run with: cargo run --example chat_with_agent_async --features conveniences,tools
+
+use ollama_oxide::conveniences::Agent;
+use ollama_oxide::tools::{Tool, ToolRegistry, ToolResult};
+use ollama_oxide::{ChatMessage, ChatRequest, OllamaClient};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct MultiplyParams {
+    a: f64,
+    b: f64,
+}
+
+#[derive(Serialize)]
+struct MultiplyOutput {
+    product: f64,
+}
+
+struct MultiplyTool;
+
+impl Tool for MultiplyTool {
+    type Params = MultiplyParams;
+    type Output = MultiplyOutput;
+
+    fn name(&self) -> String {
+        "multiply".to_string()
+    }
+
+    fn description(&self) -> String {
+        "Multiply two numbers".to_string()
+    }
+
+    async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
+        Ok(MultiplyOutput {
+            product: params.a * params.b,
+        })
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = OllamaClient::default()?;
+
+    let mut registry = ToolRegistry::new();
+    registry.register(MultiplyTool);
+    let agent = Agent::new(registry).with_max_iterations(5);
+
+    let request = ChatRequest::new(
+        "qwen3:0.6b",
+        [ChatMessage::user("What is 15 multiplied by 7?")],
+    );
+    let run = agent.run(&client, &request).await?;
+
+    println!("Tool calls:");
+    for invocation in &run.tool_invocations {
+        println!(
+            "  [{}] {:?}({:?}) -> {:?}",
+            invocation.iteration,
+            invocation.call.function_name(),
+            invocation.call.arguments(),
+            invocation.result
+        );
+    }
+    println!("\nAnswer after {} requests:", run.iterations);
+    println!("{}", run.response.content().unwrap_or("No response"));
+
+    Ok(())
+}
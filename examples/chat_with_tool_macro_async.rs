@@ -0,0 +1,101 @@
+//! Example: Tools generated with the `#[tool]` attribute
+//!
+//! Demonstrates how `#[tool]` turns async functions into `Tool`
+//! implementations, with doc comments as tool and parameter descriptions.
+//!
+//! Run with: cargo run --example chat_with_tool_macro_async --features macros
+
+use ollama_oxide::tools::{ToolError, ToolRegistry, ToolResult, tool};
+use ollama_oxide::{ChatMessage, ChatRequest, OllamaApiAsync, OllamaClient};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Weather {
+    location: String,
+    temperature: f32,
+    unit: String,
+}
+
+/// Get the current weather for a location
+#[tool]
+async fn get_weather(
+    /// The city name, e.g. "Paris" or "New York"
+    location: String,
+    /// Temperature unit, "celsius" or "fahrenheit"
+    unit: Option<String>,
+) -> ToolResult<Weather> {
+    let unit = unit.unwrap_or_else(|| "celsius".to_string());
+    let temperature = if unit == "fahrenheit" { 71.6 } else { 22.0 };
+    Ok(Weather {
+        location,
+        temperature,
+        unit,
+    })
+}
+
+/// Perform basic math operations (add, subtract, multiply, divide)
+#[tool]
+async fn calculator(
+    /// First operand
+    a: f64,
+    /// Second operand
+    b: f64,
+    /// One of "add", "subtract", "multiply" or "divide"
+    operation: String,
+) -> ToolResult<f64> {
+    match operation.as_str() {
+        "add" => Ok(a + b),
+        "subtract" => Ok(a - b),
+        "multiply" => Ok(a * b),
+        "divide" => Ok(a / b),
+        _ => Err(ToolError::ExecutionError(format!(
+            "Unknown operation: {}",
+            operation
+        ))),
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let client = OllamaClient::default()?;
+    let model = "qwen3:0.6b";
+
+    // GetWeather and Calculator are generated by #[tool]
+    let mut registry = ToolRegistry::new();
+    registry.register(GetWeather);
+    registry.register(Calculator);
+
+    for definition in registry.definitions() {
+        println!(
+            "{}: {}",
+            definition.name(),
+            serde_json::to_string_pretty(&definition.function.parameters)?
+        );
+    }
+
+    let request = ChatRequest::new(
+        model,
+        [ChatMessage::user("What's the weather like in Paris?")],
+    )
+    .with_tools(registry.definitions());
+
+    println!("\nSending request to model...");
+    let response = client.chat(&request).await?;
+
+    if response.has_tool_calls() {
+        let results = registry.execute_all(&response).await;
+
+        for (call, result) in response.tool_calls().unwrap().iter().zip(results.iter()) {
+            println!("  Tool: {:?}", call.function_name());
+            println!("  Args: {:?}", call.arguments());
+            match result {
+                Ok(output) => println!("  Result: {}", output),
+                Err(e) => println!("  Error: {}", e),
+            }
+        }
+    } else {
+        println!("Response: {}", response.content().unwrap_or("No response"));
+    }
+
+    Ok(())
+}
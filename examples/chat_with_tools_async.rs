@@ -127,7 +127,7 @@ impl WeatherService {
             51 | 53 | 55 => "Drizzle",
             61 | 63 | 65 => "Rain",
             71 | 73 | 75 => "Snow",
-            80 | 81 | 82 => "Rain showers",
+            80..=82 => "Rain showers",
             95 => "Thunderstorm",
             _ => "Unknown",
         }
@@ -219,12 +219,12 @@ impl Tool for GetCurrentWeatherTool {
     type Params = GetCurrentWeatherParams;
     type Output = GetCurrentWeatherOutput;
 
-    fn name(&self) -> &'static str {
-        "get_current_weather"
+    fn name(&self) -> String {
+        "get_current_weather".to_string()
     }
 
-    fn description(&self) -> &'static str {
-        "Get the current weather for a location"
+    fn description(&self) -> String {
+        "Get the current weather for a location".to_string()
     }
 
     async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -30,12 +30,12 @@ impl Tool for CalculatorTool {
     type Params = CalculatorParams;
     type Output = CalculatorOutput;
 
-    fn name(&self) -> &'static str {
-        "calculator"
+    fn name(&self) -> String {
+        "calculator".to_string()
     }
 
-    fn description(&self) -> &'static str {
-        "Perform basic math operations (add, subtract, multiply, divide)"
+    fn description(&self) -> String {
+        "Perform basic math operations (add, subtract, multiply, divide)".to_string()
     }
 
     async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -74,12 +74,12 @@ impl Tool for FormatTool {
     type Params = FormatParams;
     type Output = FormatOutput;
 
-    fn name(&self) -> &'static str {
-        "format_text"
+    fn name(&self) -> String {
+        "format_text".to_string()
     }
 
-    fn description(&self) -> &'static str {
-        "Format text (uppercase, lowercase, reverse)"
+    fn description(&self) -> String {
+        "Format text (uppercase, lowercase, reverse)".to_string()
     }
 
     async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -112,12 +112,12 @@ impl Tool for RandomTool {
     type Params = RandomParams;
     type Output = RandomOutput;
 
-    fn name(&self) -> &'static str {
-        "random_number"
+    fn name(&self) -> String {
+        "random_number".to_string()
     }
 
-    fn description(&self) -> &'static str {
-        "Generate a random number between min and max"
+    fn description(&self) -> String {
+        "Generate a random number between min and max".to_string()
     }
 
     async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
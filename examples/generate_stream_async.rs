@@ -0,0 +1,48 @@
+//! Example: Streaming text generation (async)
+//!
+//! This example demonstrates how to print a completion as it is
+//! generated, using the streaming generate API.
+//!
+//! Run with: cargo run --example generate_stream_async
+//!
+//! Note: Requires a running Ollama server with a model installed
+//! (e.g., qwen3:0.6b, llama3.2, etc.)
+
+use std::io::Write;
+
+use futures::StreamExt;
+use ollama_oxide::{GenerateRequest, OllamaApiAsync, OllamaClient};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create client with default configuration
+    let client = OllamaClient::default()?;
+
+    // Model to use (change to your installed model)
+    let model = "qwen3:0.6b";
+
+    println!("Streaming generation with model: {}\n", model);
+
+    let request = GenerateRequest::new(model, "Write a haiku about the ocean.");
+    let mut stream = client.generate_stream(&request).await?;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        print!("{}", chunk.text().unwrap_or(""));
+        std::io::stdout().flush()?;
+
+        // The final chunk carries timing and token counts
+        if chunk.is_done() {
+            println!("\n");
+            println!("Done reason: {:?}", chunk.done_reason);
+            if let Some(ms) = chunk.total_duration_ms() {
+                println!("Total duration: {:.2}ms", ms);
+            }
+            if let Some(tps) = chunk.tokens_per_second() {
+                println!("Tokens/sec: {:.2}", tps);
+            }
+        }
+    }
+
+    Ok(())
+}
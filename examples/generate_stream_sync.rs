@@ -0,0 +1,41 @@
+//! Example: Streaming text generation (sync/blocking)
+//!
+//! This example demonstrates how to print a completion as it is
+//! generated, using the blocking streaming generate API.
+//!
+//! Run with: cargo run --example generate_stream_sync
+//!
+//! Note: Requires a running Ollama server with a model installed
+//! (e.g., qwen3:0.6b, llama3.2, etc.)
+
+use std::io::Write;
+
+use ollama_oxide::{GenerateRequest, OllamaApiSync, OllamaClient};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Create client with default configuration
+    let client = OllamaClient::default()?;
+
+    // Model to use (change to your installed model)
+    let model = "qwen3:0.6b";
+
+    println!("Streaming generation with model: {}\n", model);
+
+    let request = GenerateRequest::new(model, "Write a haiku about the mountains.");
+
+    for chunk in client.generate_stream_blocking(&request)? {
+        let chunk = chunk?;
+        print!("{}", chunk.text().unwrap_or(""));
+        std::io::stdout().flush()?;
+
+        // The final chunk carries timing and token counts
+        if chunk.is_done() {
+            println!("\n");
+            if let Some(tps) = chunk.tokens_per_second() {
+                println!("Tokens/sec: {:.2}", tps);
+            }
+        }
+    }
+
+    Ok(())
+}
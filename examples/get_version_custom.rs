@@ -9,7 +9,7 @@
 //! cargo run --example get_version_custom
 //! ```
 
-use ollama_oxide::{ClientConfig, OllamaApiAsync, OllamaClient, Result};
+use ollama_oxide::{ClientConfig, OllamaApiAsync, OllamaClient, Result, RetryPolicy};
 use std::time::Duration;
 
 #[tokio::main]
@@ -19,6 +19,11 @@ async fn main() -> Result<()> {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(10),
         max_retries: 5,
+        // Back off from 250ms up to 5s between attempts, honoring Retry-After
+        retry_policy: RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(250))
+            .with_max_delay(Duration::from_secs(5)),
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config)?;
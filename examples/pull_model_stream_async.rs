@@ -0,0 +1,69 @@
+//! Example: Pull (download) a model with progress (async)
+//!
+//! This example demonstrates how to download a model from the Ollama registry
+//! while drawing a progress bar from the streamed progress events.
+//!
+//! # Prerequisites
+//!
+//! - Ollama server running at http://localhost:11434
+//! - Internet connection to download from registry
+//!
+//! # Usage
+//!
+//! ```sh
+//! cargo run --example pull_model_stream_async --features model
+//! ```
+
+use std::io::Write;
+
+use futures::StreamExt;
+use ollama_oxide::{OllamaApiAsync, OllamaClient, PullRequest, TransferProgress};
+
+const BAR_WIDTH: usize = 40;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("=== Pull Model with Progress Example (Async) ===\n");
+
+    // Create client with default configuration
+    let client = OllamaClient::default()?;
+
+    // Pull a small model (all-minilm:33m is very small ~67MB)
+    let model_name = "all-minilm:33m";
+    println!("Pulling model: {}\n", model_name);
+
+    let request = PullRequest::new(model_name);
+    let mut stream = client.pull_model_stream(&request).await?;
+    let mut progress = TransferProgress::new();
+
+    while let Some(event) = stream.next().await {
+        progress.update(&event?);
+
+        let status = progress.status().unwrap_or("");
+        match progress.percentage() {
+            Some(pct) => {
+                let filled = (pct / 100.0 * BAR_WIDTH as f64) as usize;
+                print!(
+                    "\r[{}{}] {:5.1}% {:>6} / {:>6} MB  {:<30}",
+                    "#".repeat(filled),
+                    " ".repeat(BAR_WIDTH - filled),
+                    pct,
+                    progress.completed() / 1_000_000,
+                    progress.total() / 1_000_000,
+                    status
+                );
+            }
+            None => print!("\r{:<80}", status),
+        }
+        std::io::stdout().flush()?;
+    }
+    println!();
+
+    if progress.is_success() {
+        println!("\nModel '{}' downloaded successfully!", model_name);
+    } else {
+        println!("\nPull ended with status: {:?}", progress.status());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Example: Pull (download) a model with progress (sync)
+//!
+//! This example demonstrates how to download a model from the Ollama registry
+//! while printing progress from the streamed events, using the blocking API.
+//!
+//! # Prerequisites
+//!
+//! - Ollama server running at http://localhost:11434
+//! - Internet connection to download from registry
+//!
+//! # Usage
+//!
+//! ```sh
+//! cargo run --example pull_model_stream_sync --features model
+//! ```
+
+use std::io::Write;
+
+use ollama_oxide::{OllamaApiSync, OllamaClient, PullRequest, TransferProgress};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("=== Pull Model with Progress Example (Sync) ===\n");
+
+    // Create client with default configuration
+    let client = OllamaClient::default()?;
+
+    // Pull a small model (all-minilm:33m is very small ~67MB)
+    let model_name = "all-minilm:33m";
+    println!("Pulling model: {}\n", model_name);
+
+    let request = PullRequest::new(model_name);
+    let mut progress = TransferProgress::new();
+
+    for event in client.pull_model_stream_blocking(&request)? {
+        progress.update(&event?);
+
+        match progress.percentage() {
+            Some(pct) => print!("\r{:5.1}% {:<40}", pct, progress.status().unwrap_or("")),
+            None => print!("\r{:<47}", progress.status().unwrap_or("")),
+        }
+        std::io::stdout().flush()?;
+    }
+    println!();
+
+    if progress.is_success() {
+        println!("\nModel '{}' downloaded successfully!", model_name);
+    } else {
+        println!("\nPull ended with status: {:?}", progress.status());
+    }
+
+    Ok(())
+}
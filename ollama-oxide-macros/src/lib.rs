@@ -0,0 +1,327 @@
+//! Procedural macros for `ollama-oxide`
+//!
+//! This crate is not meant to be used directly. Enable the `macros`
+//! feature of `ollama-oxide` and import the macros from
+//! `ollama_oxide::tools`:
+//!
+//! ```toml
+//! [dependencies]
+//! ollama-oxide = { version = "0.1", features = ["macros"] }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Error, Expr, ExprLit, FnArg, GenericArgument, Ident, ItemFn, Lit, LitStr, Meta, Pat,
+    PathArguments, Result, ReturnType, Type, parse_macro_input,
+};
+
+/// Path of the support re-exports in `ollama-oxide`
+const SUPPORT: &str = "::ollama_oxide::tools::__private";
+
+/// Generate a `Tool` implementation from an async function
+///
+/// For `async fn get_weather(...)`, the macro keeps the function and adds:
+/// - `GetWeatherParams`, a struct with one field per argument, deriving
+///   `Deserialize` and `JsonSchema`
+/// - `GetWeather`, a unit struct implementing `Tool` by calling the function
+///
+/// The function must be async, take only named arguments and return
+/// `ToolResult<T>`, or a `Result<T, E>` with `E: Into<ToolError>`.
+///
+/// The doc comment of the function becomes the tool description and the
+/// doc comments of the arguments become parameter descriptions in the
+/// generated schema. `#[serde(...)]` and `#[schemars(...)]` attributes on
+/// arguments are moved to the generated fields.
+///
+/// # Arguments
+///
+/// * `name = "..."` - Tool name, defaults to the function name
+/// * `description = "..."` - Tool description, defaults to the doc comment
+///
+/// # Examples
+///
+/// ```ignore
+/// use ollama_oxide::tools::{ToolRegistry, ToolResult, tool};
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Weather {
+///     temperature: f32,
+/// }
+///
+/// /// Get the current weather for a location
+/// #[tool]
+/// async fn get_weather(
+///     /// City name, e.g. "Paris"
+///     location: String,
+///     /// Temperature unit, "celsius" or "fahrenheit"
+///     unit: Option<String>,
+/// ) -> ToolResult<Weather> {
+///     Ok(Weather { temperature: 22.0 })
+/// }
+///
+/// let mut registry = ToolRegistry::new();
+/// registry.register(GetWeather);
+/// ```
+#[proc_macro_attribute]
+pub fn tool(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut args = ToolArgs::default();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            args.name = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("description") {
+            args.description = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `name` or `description`"))
+        }
+    });
+    parse_macro_input!(attr with parser);
+
+    let function = parse_macro_input!(item as ItemFn);
+    expand(args, function)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Arguments of `#[tool(...)]`
+#[derive(Default)]
+struct ToolArgs {
+    name: Option<LitStr>,
+    description: Option<LitStr>,
+}
+
+fn expand(args: ToolArgs, mut function: ItemFn) -> Result<TokenStream2> {
+    let sig = &function.sig;
+    if sig.asyncness.is_none() {
+        return Err(Error::new_spanned(
+            sig.fn_token,
+            "#[tool] functions must be async",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &sig.generics,
+            "#[tool] functions cannot be generic",
+        ));
+    }
+    let output = output_type(&sig.output)?.clone();
+
+    let support: syn::Path = syn::parse_str(SUPPORT)?;
+    let serde_crate = format!("{}::serde", SUPPORT);
+    let schemars_crate = format!("{}::schemars", SUPPORT);
+
+    let fn_ident = sig.ident.clone();
+    let vis = function.vis.clone();
+    let tool_ident = format_ident!("{}", camel_case(&fn_ident.to_string()));
+    let params_ident = format_ident!("{}Params", tool_ident);
+
+    let name = match args.name {
+        Some(name) => name.value(),
+        None => fn_ident.to_string(),
+    };
+    let description = match args.description {
+        Some(description) => description.value(),
+        None => doc_text(&function.attrs),
+    };
+
+    let mut fields = Vec::new();
+    let mut field_idents = Vec::new();
+    for input in function.sig.inputs.iter_mut() {
+        let FnArg::Typed(arg) = input else {
+            return Err(Error::new_spanned(
+                input,
+                "#[tool] functions cannot take `self`",
+            ));
+        };
+        let Pat::Ident(pat) = arg.pat.as_ref() else {
+            return Err(Error::new_spanned(
+                &arg.pat,
+                "#[tool] arguments must be plain identifiers",
+            ));
+        };
+
+        // Docs and serde/schemars attributes belong to the field; the
+        // compiler rejects doc comments left on function arguments
+        let (field_attrs, kept): (Vec<_>, Vec<_>) =
+            arg.attrs.drain(..).partition(is_field_attribute);
+        arg.attrs = kept;
+
+        let ident: &Ident = &pat.ident;
+        let ty = &arg.ty;
+        fields.push(quote! {
+            #(#field_attrs)*
+            pub #ident: #ty
+        });
+        field_idents.push(ident.clone());
+    }
+
+    let params_doc = format!("Parameters of the `{}` tool", name);
+    let tool_doc = format!("Tool calling [`{}`]", fn_ident);
+
+    Ok(quote! {
+        #function
+
+        #[doc = #params_doc]
+        #[derive(Debug, #support::serde::Deserialize, #support::schemars::JsonSchema)]
+        #[serde(crate = #serde_crate)]
+        // Keep the doc comment out of the schema sent to the model
+        #[schemars(crate = #schemars_crate, description = "")]
+        #vis struct #params_ident {
+            #(#fields,)*
+        }
+
+        #[doc = #tool_doc]
+        #[derive(Debug, Clone, Copy, Default)]
+        #vis struct #tool_ident;
+
+        impl ::ollama_oxide::tools::Tool for #tool_ident {
+            type Params = #params_ident;
+            type Output = #output;
+
+            fn name(&self) -> ::std::string::String {
+                ::std::string::String::from(#name)
+            }
+
+            fn description(&self) -> ::std::string::String {
+                ::std::string::String::from(#description)
+            }
+
+            async fn execute(
+                &self,
+                params: Self::Params,
+            ) -> ::ollama_oxide::tools::ToolResult<Self::Output> {
+                let #params_ident { #(#field_idents),* } = params;
+                #fn_ident(#(#field_idents),*)
+                    .await
+                    .map_err(::core::convert::Into::into)
+            }
+        }
+    })
+}
+
+/// Extract `T` from a `ToolResult<T>` or `Result<T, E>` return type
+fn output_type(output: &ReturnType) -> Result<&Type> {
+    let error = || {
+        Error::new_spanned(
+            output,
+            "#[tool] functions must return `ToolResult<T>` or `Result<T, E>`",
+        )
+    };
+
+    let ReturnType::Type(_, ty) = output else {
+        return Err(error());
+    };
+    let Type::Path(path) = ty.as_ref() else {
+        return Err(error());
+    };
+    let segment = path.path.segments.last().ok_or_else(error)?;
+    if segment.ident != "ToolResult" && segment.ident != "Result" {
+        return Err(error());
+    }
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return Err(error());
+    };
+    match generics.args.first() {
+        Some(GenericArgument::Type(ty)) => Ok(ty),
+        _ => Err(error()),
+    }
+}
+
+/// Check whether an argument attribute is moved to the generated field
+fn is_field_attribute(attr: &Attribute) -> bool {
+    let path = attr.path();
+    path.is_ident("doc") || path.is_ident("serde") || path.is_ident("schemars")
+}
+
+/// Join the lines of the doc comments in `attrs`
+fn doc_text(attrs: &[Attribute]) -> String {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(text),
+                    ..
+                }) => Some(text.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .map(|line| {
+            line.strip_prefix(' ')
+                .unwrap_or(&line)
+                .trim_end()
+                .to_string()
+        })
+        .collect();
+
+    lines.join("\n").trim().to_string()
+}
+
+/// Convert a `snake_case` function name to a `CamelCase` type name
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case() {
+        assert_eq!(camel_case("get_weather"), "GetWeather");
+        assert_eq!(camel_case("add"), "Add");
+        assert_eq!(camel_case("_private_tool"), "PrivateTool");
+        assert_eq!(camel_case("to_v2"), "ToV2");
+    }
+
+    #[test]
+    fn test_doc_text() {
+        let function: ItemFn = syn::parse_quote! {
+            /// Get the weather
+            ///
+            /// Uses the local station.
+            async fn get_weather() {}
+        };
+        assert_eq!(
+            doc_text(&function.attrs),
+            "Get the weather\n\nUses the local station."
+        );
+    }
+
+    #[test]
+    fn test_output_type() {
+        let function: ItemFn = syn::parse_quote! {
+            async fn add() -> ToolResult<Sum> {}
+        };
+        let ty = output_type(&function.sig.output).unwrap();
+        assert_eq!(quote!(#ty).to_string(), "Sum");
+
+        let function: ItemFn = syn::parse_quote! {
+            async fn add() -> i32 {}
+        };
+        assert!(output_type(&function.sig.output).is_err());
+    }
+
+    #[test]
+    fn test_expand_rejects_sync_functions() {
+        let function: ItemFn = syn::parse_quote! {
+            fn add(a: i32) -> ToolResult<i32> { Ok(a) }
+        };
+        let err = expand(ToolArgs::default(), function).err().unwrap();
+        assert!(err.to_string().contains("must be async"));
+    }
+}
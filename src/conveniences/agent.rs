@@ -0,0 +1,244 @@
+//! Automatic tool-calling loop over a `ToolRegistry`
+
+use serde_json::Value;
+
+use crate::tools::{ToolRegistry, ToolResult};
+use crate::{
+    ChatMessage, ChatRequest, ChatResponse, Error, OllamaApiAsync, OllamaApiSync, Result, ToolCall,
+};
+
+/// Default limit on chat requests per run
+const DEFAULT_MAX_ITERATIONS: u32 = 10;
+
+/// Runs the chat and tool-calling loop over a [`ToolRegistry`]
+///
+/// Each iteration sends the conversation to the model. When the reply
+/// requests tool calls, they are executed through the registry, the
+/// assistant message (with its thinking and tool calls) and one tool
+/// message per result, named after its tool, are appended to the
+/// conversation, and the next iteration starts. The run ends with the
+/// first reply that requests no tools.
+///
+/// The calls of one reply run concurrently, under the concurrency limit
+/// and timeouts configured on the registry.
+///
+/// Tool failures do not end the run: the error, including every schema
+/// violation of invalid arguments, is sent back to the model as the tool
+/// result so it can correct its call.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::conveniences::Agent;
+/// use ollama_oxide::tools::ToolRegistry;
+/// use ollama_oxide::{ChatMessage, ChatRequest, OllamaClient};
+///
+/// # async fn example(registry: ToolRegistry) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OllamaClient::default()?;
+/// let agent = Agent::new(registry).with_max_iterations(5);
+///
+/// let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("What is 15 * 7?")]);
+/// let run = agent.run(&client, &request).await?;
+///
+/// for invocation in &run.tool_invocations {
+///     println!("{:?} -> {:?}", invocation.call.function_name(), invocation.result);
+/// }
+/// println!("{}", run.response.content().unwrap_or_default());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Agent {
+    registry: ToolRegistry,
+    max_iterations: u32,
+}
+
+/// Outcome of an [`Agent`] run
+#[derive(Debug)]
+pub struct AgentRun {
+    /// Final model reply, which requested no tool calls
+    pub response: ChatResponse,
+
+    /// Full conversation, from the request messages to the final reply
+    pub messages: Vec<ChatMessage>,
+
+    /// Every tool call made during the run, in order
+    pub tool_invocations: Vec<ToolInvocation>,
+
+    /// Number of chat requests sent
+    pub iterations: u32,
+}
+
+/// A tool call requested by the model and its result
+#[derive(Debug)]
+pub struct ToolInvocation {
+    /// Iteration in which the model requested the call (starting at 1)
+    pub iteration: u32,
+
+    /// Tool call as sent by the model
+    pub call: ToolCall,
+
+    /// Output of the tool, or why it failed
+    pub result: ToolResult<Value>,
+}
+
+impl Agent {
+    /// Create an agent dispatching tool calls to `registry`
+    ///
+    /// At most 10 chat requests are sent per run; see
+    /// [`with_max_iterations`](Self::with_max_iterations).
+    pub fn new(registry: ToolRegistry) -> Self {
+        Self {
+            registry,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    /// Set the maximum number of chat requests per run
+    pub fn with_max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Get the tool registry
+    pub fn registry(&self) -> &ToolRegistry {
+        &self.registry
+    }
+
+    /// Run the loop until the model replies without tool calls (async)
+    ///
+    /// When `request` has no tools, the registry's definitions are added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A chat request fails
+    /// - The model still requests tools after `max_iterations` requests
+    ///   ([`Error::MaxIterationsExceededError`])
+    pub async fn run<C>(&self, client: &C, request: &ChatRequest) -> Result<AgentRun>
+    where
+        C: OllamaApiAsync + ?Sized,
+    {
+        let mut request = self.prepare(request);
+        let mut tool_invocations = Vec::new();
+
+        for iteration in 1..=self.max_iterations {
+            let response = client.chat(&request).await?;
+            let Some(calls) = response.tool_calls().filter(|calls| !calls.is_empty()) else {
+                return Ok(Self::finish(request, response, tool_invocations, iteration));
+            };
+
+            let results = self.registry.execute_calls(calls).await;
+            Self::record(
+                &mut request,
+                &response,
+                results,
+                iteration,
+                &mut tool_invocations,
+            );
+        }
+
+        Err(Error::MaxIterationsExceededError(self.max_iterations))
+    }
+
+    /// Run the loop until the model replies without tool calls (blocking)
+    ///
+    /// Blocking counterpart of [`run`](Self::run); tools are executed with
+    /// [`ToolRegistry::execute_calls_blocking`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A chat request fails
+    /// - The model still requests tools after `max_iterations` requests
+    ///   ([`Error::MaxIterationsExceededError`])
+    pub fn run_blocking<C>(&self, client: &C, request: &ChatRequest) -> Result<AgentRun>
+    where
+        C: OllamaApiSync + ?Sized,
+    {
+        let mut request = self.prepare(request);
+        let mut tool_invocations = Vec::new();
+
+        for iteration in 1..=self.max_iterations {
+            let response = client.chat_blocking(&request)?;
+            let Some(calls) = response.tool_calls().filter(|calls| !calls.is_empty()) else {
+                return Ok(Self::finish(request, response, tool_invocations, iteration));
+            };
+
+            let results = self.registry.execute_calls_blocking(calls);
+            Self::record(
+                &mut request,
+                &response,
+                results,
+                iteration,
+                &mut tool_invocations,
+            );
+        }
+
+        Err(Error::MaxIterationsExceededError(self.max_iterations))
+    }
+
+    /// Copy the request, adding the registry's tools if it has none
+    fn prepare(&self, request: &ChatRequest) -> ChatRequest {
+        let request = request.clone();
+        if request.has_tools() {
+            request
+        } else {
+            request.with_tools(self.registry.definitions())
+        }
+    }
+
+    /// Append a tool-calling reply and its results to the conversation
+    fn record(
+        request: &mut ChatRequest,
+        response: &ChatResponse,
+        results: Vec<ToolResult<Value>>,
+        iteration: u32,
+        tool_invocations: &mut Vec<ToolInvocation>,
+    ) {
+        let calls = response.tool_calls().unwrap_or_default().to_vec();
+        request.messages.push(assistant_message(response));
+
+        for (call, result) in calls.into_iter().zip(results) {
+            let mut message = match &result {
+                Ok(output) => ChatMessage::tool(output.to_string()),
+                Err(e) => e.to_chat_message(),
+            };
+            if let Some(name) = call.function_name() {
+                message = message.with_tool_name(name);
+            }
+            request.messages.push(message);
+            tool_invocations.push(ToolInvocation {
+                iteration,
+                call,
+                result,
+            });
+        }
+    }
+
+    /// Build the outcome from the final reply
+    fn finish(
+        request: ChatRequest,
+        response: ChatResponse,
+        tool_invocations: Vec<ToolInvocation>,
+        iterations: u32,
+    ) -> AgentRun {
+        let mut messages = request.messages;
+        messages.push(assistant_message(&response));
+        AgentRun {
+            response,
+            messages,
+            tool_invocations,
+            iterations,
+        }
+    }
+}
+
+/// Assistant message replaying a reply, with its thinking and tool calls
+fn assistant_message(response: &ChatResponse) -> ChatMessage {
+    response
+        .message
+        .clone()
+        .map(ChatMessage::from)
+        .unwrap_or_else(|| ChatMessage::assistant(""))
+}
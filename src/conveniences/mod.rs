@@ -0,0 +1,22 @@
+//! High-level conveniences built on the low-level API
+//!
+//! # Feature Flag
+//!
+//! This module requires the `conveniences` feature. Items that dispatch
+//! tool calls also require the `tools` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! ollama-oxide = { version = "0.1", features = ["conveniences", "tools"] }
+//! ```
+//!
+//! # Components
+//!
+//! - [`Agent`] - Runs the chat and tool-calling loop over a `ToolRegistry`
+//! - [`AgentRun`] - Final response and transcript of an agent run
+
+#[cfg(feature = "tools")]
+mod agent;
+
+#[cfg(feature = "tools")]
+pub use agent::{Agent, AgentRun, ToolInvocation};
@@ -17,8 +17,15 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     SerializationError(String),
 
-    #[error("API error: {message}")]
-    ApiError { message: String },
+    #[error("API error ({status}): {message}")]
+    ApiError {
+        /// HTTP status code of the response that carried the error
+        status: u16,
+        /// Classification of the error message
+        kind: ApiErrorKind,
+        /// Error message sent by the server
+        message: String,
+    },
 
     #[error("Connection error: {0}")]
     ConnectionError(String),
@@ -31,6 +38,165 @@ pub enum Error {
 
     #[error("Maximum retry attempts ({0}) exceeded")]
     MaxRetriesExceededError(u32),
+
+    #[error("Maximum agent iterations ({0}) exceeded")]
+    MaxIterationsExceededError(u32),
+
+    #[error("Authentication error: {0}")]
+    AuthError(String),
+
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+
+    #[error("I/O error: {0}")]
+    IoError(String),
+
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+
+    #[error("Cassette mismatch: {0}")]
+    CassetteMismatchError(String),
+
+    #[error("Structured output error: {message}")]
+    StructuredOutputError {
+        /// Why the reply could not be parsed
+        message: String,
+        /// Reply text returned by the model
+        raw: String,
+    },
+}
+
+impl Error {
+    /// Build an error from a non-success response
+    ///
+    /// Ollama reports failures as `{"error": "..."}`. When the body has that
+    /// shape the message is kept in an [`Error::ApiError`]; otherwise only
+    /// the status code is reported as [`Error::HttpStatusError`].
+    #[cfg(feature = "http")]
+    pub(crate) fn from_response_body(status: u16, body: &str) -> Self {
+        let message = serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .and_then(|value| value.get("error")?.as_str().map(str::to_string));
+
+        match message {
+            Some(message) => Self::api(status, message),
+            None => Error::HttpStatusError(status),
+        }
+    }
+
+    /// Build an [`Error::ApiError`], classifying the message
+    #[cfg(feature = "http")]
+    pub(crate) fn api(status: u16, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Error::ApiError {
+            status,
+            kind: ApiErrorKind::classify(status, &message),
+            message,
+        }
+    }
+
+    /// HTTP status code associated with the error, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ollama_oxide::Error;
+    ///
+    /// assert_eq!(Error::HttpStatusError(404).status_code(), Some(404));
+    /// assert_eq!(Error::HttpError("refused".into()).status_code(), None);
+    /// ```
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Error::HttpStatusError(status) | Error::ApiError { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Classification of an API error, if this is an [`Error::ApiError`]
+    pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+        match self {
+            Error::ApiError { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
+    /// Check if the error reports a model that is not available locally
+    ///
+    /// Returns `true` for both [`ApiErrorKind::ModelNotFound`] and
+    /// [`ApiErrorKind::ModelNeedsPulling`].
+    pub fn is_model_not_found(&self) -> bool {
+        matches!(
+            self.api_error_kind(),
+            Some(ApiErrorKind::ModelNotFound | ApiErrorKind::ModelNeedsPulling)
+        )
+    }
+}
+
+/// Classification of an error message returned by the Ollama API
+///
+/// Ollama reports errors as free-form strings. The most common cases are
+/// recognized so callers can match on them instead of parsing messages.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{ApiErrorKind, ChatMessage, ChatRequest, Error, OllamaApiAsync, OllamaClient};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OllamaClient::default()?;
+/// let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+/// match client.chat(&request).await {
+///     Ok(response) => println!("{:?}", response.content()),
+///     Err(Error::ApiError { kind: ApiErrorKind::ModelNeedsPulling, .. }) => {
+///         println!("Run `ollama pull qwen3:0.6b` first");
+///     }
+///     Err(e) => return Err(e.into()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiErrorKind {
+    /// The model does not exist locally or in the registry
+    ModelNotFound,
+    /// The requested resource does not exist, such as an unknown endpoint
+    NotFound,
+    /// The model exists in the registry but must be pulled before use
+    ModelNeedsPulling,
+    /// The request was malformed or had invalid parameters
+    InvalidRequest,
+    /// Authentication failed or access was denied
+    Unauthorized,
+    /// The server failed while handling the request
+    ServerError,
+    /// Any other error
+    Other,
+}
+
+impl ApiErrorKind {
+    /// Classify an error from its HTTP status code and message
+    pub fn classify(status: u16, message: &str) -> Self {
+        let message = message.to_lowercase();
+
+        if message.contains("try pulling it first") {
+            return ApiErrorKind::ModelNeedsPulling;
+        }
+        // A 404 alone is not enough: proxies and older servers answer
+        // unknown routes with one too
+        let missing = message.contains("not found") || message.contains("does not exist");
+        if missing && (message.contains("model") || message.contains("manifest")) {
+            return ApiErrorKind::ModelNotFound;
+        }
+
+        match status {
+            400 | 422 => ApiErrorKind::InvalidRequest,
+            401 | 403 => ApiErrorKind::Unauthorized,
+            404 => ApiErrorKind::NotFound,
+            500..=599 => ApiErrorKind::ServerError,
+            _ => ApiErrorKind::Other,
+        }
+    }
 }
 
 impl From<reqwest::Error> for Error {
@@ -45,5 +211,116 @@ impl From<serde_json::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::IoError(err.to_string())
+    }
+}
+
 /// Result type alias for ollama-oxide operations
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn test_from_response_body_with_error_message() {
+        let error = Error::from_response_body(400, r#"{"error":"invalid options"}"#);
+
+        match error {
+            Error::ApiError {
+                status,
+                kind,
+                message,
+            } => {
+                assert_eq!(status, 400);
+                assert_eq!(kind, ApiErrorKind::InvalidRequest);
+                assert_eq!(message, "invalid options");
+            }
+            other => panic!("expected ApiError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn test_from_response_body_without_error_message() {
+        assert!(matches!(
+            Error::from_response_body(502, "<html>Bad Gateway</html>"),
+            Error::HttpStatusError(502)
+        ));
+        assert!(matches!(
+            Error::from_response_body(404, ""),
+            Error::HttpStatusError(404)
+        ));
+        assert!(matches!(
+            Error::from_response_body(500, r#"{"detail":"oops"}"#),
+            Error::HttpStatusError(500)
+        ));
+    }
+
+    #[test]
+    fn test_classify_model_needs_pulling() {
+        let kind =
+            ApiErrorKind::classify(404, "model \"llama3.2\" not found, try pulling it first");
+        assert_eq!(kind, ApiErrorKind::ModelNeedsPulling);
+    }
+
+    #[test]
+    fn test_classify_model_not_found() {
+        assert_eq!(
+            ApiErrorKind::classify(404, "model 'nonexistent' not found"),
+            ApiErrorKind::ModelNotFound
+        );
+        assert_eq!(
+            ApiErrorKind::classify(500, "pull model manifest: file does not exist"),
+            ApiErrorKind::ModelNotFound
+        );
+        assert_eq!(
+            ApiErrorKind::classify(200, "model 'x' not found"),
+            ApiErrorKind::ModelNotFound
+        );
+    }
+
+    #[test]
+    fn test_classify_unrelated_404_is_not_model_not_found() {
+        let kind = ApiErrorKind::classify(404, "404 page not found");
+
+        assert_eq!(kind, ApiErrorKind::NotFound);
+        assert_eq!(
+            ApiErrorKind::classify(404, "route does not exist"),
+            ApiErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn test_classify_by_status() {
+        assert_eq!(
+            ApiErrorKind::classify(400, "invalid format"),
+            ApiErrorKind::InvalidRequest
+        );
+        assert_eq!(
+            ApiErrorKind::classify(401, "unauthorized"),
+            ApiErrorKind::Unauthorized
+        );
+        assert_eq!(
+            ApiErrorKind::classify(503, "server busy"),
+            ApiErrorKind::ServerError
+        );
+        assert_eq!(
+            ApiErrorKind::classify(200, "out of memory"),
+            ApiErrorKind::Other
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "http")]
+    fn test_is_model_not_found() {
+        assert!(Error::api(404, "model 'x' not found").is_model_not_found());
+        assert!(Error::api(404, "model 'x' not found, try pulling it first").is_model_not_found());
+        assert!(!Error::api(400, "invalid format").is_model_not_found());
+        assert!(!Error::api(404, "404 page not found").is_model_not_found());
+        assert!(!Error::HttpStatusError(404).is_model_not_found());
+    }
+}
@@ -5,16 +5,31 @@ use crate::{
     Result, VersionResponse,
 };
 
+#[cfg(feature = "tools")]
+use crate::FormatSetting;
+#[cfg(feature = "tools")]
+use schemars::JsonSchema;
+#[cfg(feature = "tools")]
+use serde::de::DeserializeOwned;
+
 #[cfg(feature = "model")]
 use crate::{
-    CopyRequest, CreateRequest, CreateResponse, DeleteRequest, ListResponse, PsResponse,
-    PullRequest, PullResponse, PushRequest, PushResponse, ShowRequest, ShowResponse,
+    CopyRequest, CreateRequest, CreateResponse, DeleteRequest, ListResponse, ProgressResponse,
+    PsResponse, PullRequest, PullResponse, PushRequest, PushResponse, ShowRequest, ShowResponse,
 };
 
 use async_trait::async_trait;
+#[cfg(feature = "model")]
+use std::path::Path;
 
-use super::OllamaClient;
+#[cfg(feature = "model")]
+use super::blob::file_name;
+use super::client::validate_options;
 use super::endpoints::Endpoints;
+use super::response_stream::ndjson_stream;
+#[cfg(feature = "tools")]
+use super::structured::{chat_correction, generate_correction, parse_reply};
+use super::{OllamaClient, ResponseStream};
 
 /// Async API operations trait
 ///
@@ -306,6 +321,50 @@ pub trait OllamaApiAsync: Send + Sync {
     /// ```
     async fn generate(&self, request: &GenerateRequest) -> Result<GenerateResponse>;
 
+    /// Generate text completion (async, streaming)
+    ///
+    /// Generates a text completion for the provided prompt, yielding partial
+    /// responses as the model produces them. The request is sent with
+    /// `stream: true` regardless of its `stream` field.
+    ///
+    /// The final chunk has `done: true` and holds the timing and token
+    /// counts; the stream ends after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Generate request containing model, prompt, and options
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Model doesn't exist (404)
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// Errors that occur after streaming has started, including an
+    /// `{"error": ...}` object sent by the server, are yielded as stream items.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync, GenerateRequest};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = GenerateRequest::new("qwen3:0.6b", "Why is the sky blue?");
+    /// let mut stream = client.generate_stream(&request).await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     print!("{}", chunk?.text().unwrap_or(""));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn generate_stream(
+        &self,
+        request: &GenerateRequest,
+    ) -> Result<ResponseStream<GenerateResponse>>;
+
     /// Chat completion (async, non-streaming)
     ///
     /// Generates the next message in a chat conversation.
@@ -385,6 +444,158 @@ pub trait OllamaApiAsync: Send + Sync {
     /// ```
     async fn chat(&self, request: &ChatRequest) -> Result<ChatResponse>;
 
+    /// Chat completion (async, streaming)
+    ///
+    /// Generates the next message in a chat conversation, yielding partial
+    /// responses as the model produces them. The request is sent with
+    /// `stream: true` regardless of its `stream` field.
+    ///
+    /// Each chunk carries a fragment of the assistant message. The final
+    /// chunk has `done: true` and holds the timing and token counts; the
+    /// stream ends after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Chat request containing model, messages, and options
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Model doesn't exist (404)
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// Errors that occur after streaming has started are yielded as stream items.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync, ChatRequest, ChatMessage};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = ChatRequest::new("qwen3:0.6b", [
+    ///     ChatMessage::user("Tell me a story.")
+    /// ]);
+    /// let mut stream = client.chat_stream(&request).await?;
+    /// while let Some(chunk) = stream.next().await {
+    ///     let chunk = chunk?;
+    ///     print!("{}", chunk.content().unwrap_or(""));
+    ///     if chunk.is_done() {
+    ///         println!("\n{:?} tokens/s", chunk.tokens_per_second());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn chat_stream(&self, request: &ChatRequest) -> Result<ResponseStream<ChatResponse>>;
+
+    /// Send a chat request and parse the reply into `T` (async)
+    ///
+    /// The request's `format` is replaced by the JSON schema of `T`, derived
+    /// with `schemars`, so the model is constrained to produce matching JSON.
+    /// The reply is then deserialized into `T`.
+    ///
+    /// When parsing fails and `max_corrections` is not yet used up,
+    /// the failed reply and a user message describing the
+    /// parse error are appended to the conversation and the request is sent
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Chat request; `format` is overwritten
+    /// * `max_corrections` - How many corrective follow-ups to send (0 = none)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The reply still cannot be parsed into `T`, as
+    ///   [`Error::StructuredOutputError`](crate::Error::StructuredOutputError)
+    ///   holding the raw reply text
+    /// - Any error returned by `chat`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{ChatMessage, ChatRequest, OllamaClient, OllamaApiAsync};
+    /// use schemars::JsonSchema;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, JsonSchema)]
+    /// struct Capital {
+    ///     country: String,
+    ///     city: String,
+    /// }
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("What is the capital of France?")]);
+    /// let capital: Capital = client.chat_structured(&request, 2).await?;
+    /// println!("{} -> {}", capital.country, capital.city);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tools")]
+    async fn chat_structured<T>(&self, request: &ChatRequest, max_corrections: u32) -> Result<T>
+    where
+        Self: Sized,
+        T: JsonSchema + DeserializeOwned + Send;
+
+    /// Send a generate request and parse the reply into `T` (async)
+    ///
+    /// The request's `format` is replaced by the JSON schema of `T`, derived
+    /// with `schemars`, so the model is constrained to produce matching JSON.
+    /// The reply is then deserialized into `T`.
+    ///
+    /// When parsing fails and `max_corrections` is not yet used up,
+    /// the failed reply and a note describing the parse error
+    /// are appended to the prompt and the request is sent again.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Generate request; `format` is overwritten
+    /// * `max_corrections` - How many corrective follow-ups to send (0 = none)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The reply still cannot be parsed into `T`, as
+    ///   [`Error::StructuredOutputError`](crate::Error::StructuredOutputError)
+    ///   holding the raw reply text
+    /// - Any error returned by `generate`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{GenerateRequest, OllamaClient, OllamaApiAsync};
+    /// use schemars::JsonSchema;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, JsonSchema)]
+    /// struct Capital {
+    ///     country: String,
+    ///     city: String,
+    /// }
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = GenerateRequest::new("qwen3:0.6b", "What is the capital of France?");
+    /// let capital: Capital = client.generate_structured(&request, 2).await?;
+    /// println!("{} -> {}", capital.country, capital.city);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tools")]
+    async fn generate_structured<T>(
+        &self,
+        request: &GenerateRequest,
+        max_corrections: u32,
+    ) -> Result<T>
+    where
+        Self: Sized,
+        T: JsonSchema + DeserializeOwned + Send;
+
     /// Create a custom model (async, non-streaming)
     ///
     /// Creates a new model from an existing model with custom configuration.
@@ -419,6 +630,166 @@ pub trait OllamaApiAsync: Send + Sync {
     #[cfg(feature = "model")]
     async fn create_model(&self, request: &CreateRequest) -> Result<CreateResponse>;
 
+    /// Create a custom model (async, streaming)
+    ///
+    /// Creates a new model like `create_model`, yielding a `CreateResponse` for
+    /// each step the server reports, such as "reading model metadata",
+    /// "quantizing" or "writing manifest". The request is sent with
+    /// `stream: true` regardless of its `stream` field.
+    ///
+    /// The last status is "success" and the stream ends after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Create request containing model name, base model, and options
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Base model doesn't exist (404)
+    /// - Model name is invalid
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// Errors that occur after streaming has started, including an
+    /// `{"error": ...}` object sent by the server, are yielded as stream items.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync, CreateRequest};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = CreateRequest::from_model("qwen-q4", "qwen3:0.6b")
+    ///     .with_quantize("q4_K_M");
+    /// let mut stream = client.create_model_stream(&request).await?;
+    /// while let Some(event) = stream.next().await {
+    ///     println!("{}", event?.status().unwrap_or(""));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    async fn create_model_stream(
+        &self,
+        request: &CreateRequest,
+    ) -> Result<ResponseStream<CreateResponse>>;
+
+    /// Check whether a blob exists on the server (async)
+    ///
+    /// Sends `HEAD /api/blobs/:digest`. Blobs are the files a model is
+    /// created from, such as GGUF weights or LoRA adapters.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Blob digest in the form `sha256:<hex>`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// A missing blob is not an error; `Ok(false)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let digest = "sha256:29fdb92e57cf0827ded04ae6461b5931d01fa595843f55d36f5b275a52087dd2";
+    /// if !client.blob_exists(digest).await? {
+    ///     println!("blob must be uploaded first");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    async fn blob_exists(&self, digest: &str) -> Result<bool>;
+
+    /// Upload a file as a blob (async)
+    ///
+    /// Sends `POST /api/blobs/:digest`, streaming the file from disk so
+    /// large model files are never loaded into memory. The server checks
+    /// the content against `digest`.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Blob digest in the form `sha256:<hex>`
+    /// * `path` - File to upload
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file cannot be read
+    /// - The digest does not match the file (400)
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync};
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let digest = "sha256:29fdb92e57cf0827ded04ae6461b5931d01fa595843f55d36f5b275a52087dd2";
+    /// client.push_blob(digest, Path::new("model.gguf")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    async fn push_blob(&self, digest: &str, path: &Path) -> Result<()>;
+
+    /// Create a model from a local GGUF file (async)
+    ///
+    /// Computes the SHA-256 digest of the model file and of each adapter,
+    /// uploads the ones the server does not have yet, and then calls
+    /// `create_model` with `files` and `adapters` mapping each file name
+    /// to its digest. Other fields of `request`, such as `system` or
+    /// `quantize`, are sent unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Create request with the model name and options
+    /// * `path` - GGUF model file
+    /// * `adapters` - LoRA adapter files, may be empty
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A file cannot be read
+    /// - An upload fails
+    /// - The server rejects the model
+    /// - Network request fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync, CreateRequest};
+    /// use std::path::Path;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = CreateRequest::new("my-model").with_quantize("q4_K_M");
+    /// let response = client.create_from_gguf(&request, Path::new("model.gguf"), &[]).await?;
+    /// println!("Status: {:?}", response.status());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    async fn create_from_gguf(
+        &self,
+        request: &CreateRequest,
+        path: &Path,
+        adapters: &[&Path],
+    ) -> Result<CreateResponse>;
+
     /// Pull (download) a model from the Ollama registry.
     ///
     /// Downloads the specified model from the remote registry to the local
@@ -435,7 +806,10 @@ pub trait OllamaApiAsync: Send + Sync {
     ///
     /// # Errors
     ///
-    /// * `HttpStatusError(404)` - Model not found in registry
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found in registry; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `HttpStatusError` - Non-success status without a parseable error body
     /// * `HttpError` - Network or HTTP errors
     /// * `MaxRetriesExceededError` - Server errors after all retries
     ///
@@ -455,6 +829,58 @@ pub trait OllamaApiAsync: Send + Sync {
     #[cfg(feature = "model")]
     async fn pull_model(&self, request: &PullRequest) -> Result<PullResponse>;
 
+    /// Pull (download) a model from the Ollama registry, streaming progress.
+    ///
+    /// Yields a [`ProgressResponse`](crate::ProgressResponse) for every status
+    /// change and download tick reported by the server, so long downloads
+    /// can be shown to the user as they happen.
+    ///
+    /// The last event has the status `"success"` and the stream ends after it.
+    /// Combine events with [`TransferProgress`](crate::TransferProgress) to
+    /// get overall bytes done and a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The pull request containing the model name and options
+    ///
+    /// # Errors
+    ///
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found in registry; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `HttpStatusError` - Non-success status without a parseable error body
+    /// * `HttpError` - Network or HTTP errors
+    /// * `MaxRetriesExceededError` - Server errors after all retries
+    ///
+    /// Errors that occur after streaming has started are yielded as stream items.
+    /// An `{"error": ...}` object sent by the server mid-stream, such as a
+    /// failed transfer, arrives as an `Err(Error::ApiError { .. })` item,
+    /// even though the call itself returned `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync, PullRequest, TransferProgress};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = PullRequest::new("all-minilm:33m");
+    /// let mut stream = client.pull_model_stream(&request).await?;
+    /// let mut progress = TransferProgress::new();
+    /// while let Some(event) = stream.next().await {
+    ///     progress.update(&event?);
+    ///     println!("{:?} {:?}", progress.status(), progress.percentage());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    async fn pull_model_stream(
+        &self,
+        request: &PullRequest,
+    ) -> Result<ResponseStream<ProgressResponse>>;
+
     /// Push (upload) a model to the Ollama registry.
     ///
     /// Uploads the specified model to a remote registry. Requires proper
@@ -470,8 +896,12 @@ pub trait OllamaApiAsync: Send + Sync {
     ///
     /// # Errors
     ///
-    /// * `HttpStatusError(404)` - Model not found locally
-    /// * `HttpStatusError(401)` - Unauthorized (invalid credentials)
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found locally; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `ApiError` with [`ApiErrorKind::Unauthorized`](crate::ApiErrorKind::Unauthorized) -
+    ///   Invalid credentials for the registry
+    /// * `HttpStatusError` - Non-success status without a parseable error body
     /// * `HttpError` - Network or HTTP errors
     /// * `MaxRetriesExceededError` - Server errors after all retries
     ///
@@ -490,75 +920,261 @@ pub trait OllamaApiAsync: Send + Sync {
     /// ```
     #[cfg(feature = "model")]
     async fn push_model(&self, request: &PushRequest) -> Result<PushResponse>;
+
+    /// Push (upload) a model to the Ollama registry, streaming progress.
+    ///
+    /// Yields a [`ProgressResponse`](crate::ProgressResponse) for every status
+    /// change and upload tick reported by the server. Requires proper
+    /// authentication and namespace permissions.
+    ///
+    /// The last event has the status `"success"` and the stream ends after it.
+    /// Combine events with [`TransferProgress`](crate::TransferProgress) to
+    /// get overall bytes done and a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The push request containing the model name and options
+    ///
+    /// # Errors
+    ///
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found locally; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `ApiError` with [`ApiErrorKind::Unauthorized`](crate::ApiErrorKind::Unauthorized) -
+    ///   Invalid credentials for the registry
+    /// * `HttpStatusError` - Non-success status without a parseable error body
+    /// * `HttpError` - Network or HTTP errors
+    /// * `MaxRetriesExceededError` - Server errors after all retries
+    ///
+    /// Errors that occur after streaming has started are yielded as stream items.
+    /// An `{"error": ...}` object sent by the server mid-stream, such as a
+    /// failed transfer, arrives as an `Err(Error::ApiError { .. })` item,
+    /// even though the call itself returned `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use ollama_oxide::{OllamaClient, OllamaApiAsync, PushRequest, TransferProgress};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = PushRequest::new("myuser/mymodel:latest");
+    /// let mut stream = client.push_model_stream(&request).await?;
+    /// let mut progress = TransferProgress::new();
+    /// while let Some(event) = stream.next().await {
+    ///     progress.update(&event?);
+    ///     println!("{:?} {:?}", progress.status(), progress.percentage());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    async fn push_model_stream(
+        &self,
+        request: &PushRequest,
+    ) -> Result<ResponseStream<ProgressResponse>>;
 }
 
 #[async_trait]
 impl OllamaApiAsync for OllamaClient {
     async fn version(&self) -> Result<VersionResponse> {
-        let url = self.config.url(Endpoints::VERSION);
-        self.get_with_retry(&url).await
+        self.get_with_retry(Endpoints::VERSION).await
     }
 
     #[cfg(feature = "model")]
     async fn list_models(&self) -> Result<ListResponse> {
-        let url = self.config.url(Endpoints::TAGS);
-        self.get_with_retry(&url).await
+        self.get_with_retry(Endpoints::TAGS).await
     }
 
     #[cfg(feature = "model")]
     async fn copy_model(&self, request: &CopyRequest) -> Result<()> {
-        let url = self.config.url(Endpoints::COPY);
-        self.post_empty_with_retry(&url, request).await
+        self.post_empty_with_retry(Endpoints::COPY, request).await
     }
 
     #[cfg(feature = "model")]
     async fn list_running_models(&self) -> Result<PsResponse> {
-        let url = self.config.url(Endpoints::PS);
-        self.get_with_retry(&url).await
+        self.get_with_retry(Endpoints::PS).await
     }
 
     #[cfg(feature = "model")]
     async fn delete_model(&self, request: &DeleteRequest) -> Result<()> {
-        let url = self.config.url(Endpoints::DELETE);
-        self.delete_empty_with_retry(&url, request).await
+        self.delete_empty_with_retry(Endpoints::DELETE, request)
+            .await
     }
 
     #[cfg(feature = "model")]
     async fn show_model(&self, request: &ShowRequest) -> Result<ShowResponse> {
-        let url = self.config.url(Endpoints::SHOW);
-        self.post_with_retry(&url, request).await
+        self.post_with_retry(Endpoints::SHOW, request).await
     }
 
     async fn embed(&self, request: &EmbedRequest) -> Result<EmbedResponse> {
-        let url = self.config.url(Endpoints::EMBED);
-        self.post_with_retry(&url, request).await
+        validate_options(request.options.as_ref())?;
+        self.post_with_retry(Endpoints::EMBED, request).await
     }
 
     async fn generate(&self, request: &GenerateRequest) -> Result<GenerateResponse> {
-        let url = self.config.url(Endpoints::GENERATE);
-        self.post_with_retry(&url, request).await
+        validate_options(request.options.as_ref())?;
+        self.post_with_retry(Endpoints::GENERATE, request).await
+    }
+
+    async fn generate_stream(
+        &self,
+        request: &GenerateRequest,
+    ) -> Result<ResponseStream<GenerateResponse>> {
+        validate_options(request.options.as_ref())?;
+        let request = GenerateRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self
+            .post_stream_with_retry(Endpoints::GENERATE, &request)
+            .await?;
+        Ok(ndjson_stream(response, GenerateResponse::is_done))
     }
 
     async fn chat(&self, request: &ChatRequest) -> Result<ChatResponse> {
-        let url = self.config.url(Endpoints::CHAT);
-        self.post_with_retry(&url, request).await
+        validate_options(request.options.as_ref())?;
+        self.post_with_retry(Endpoints::CHAT, request).await
+    }
+
+    async fn chat_stream(&self, request: &ChatRequest) -> Result<ResponseStream<ChatResponse>> {
+        validate_options(request.options.as_ref())?;
+        let request = ChatRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self
+            .post_stream_with_retry(Endpoints::CHAT, &request)
+            .await?;
+        Ok(ndjson_stream(response, ChatResponse::is_done))
+    }
+
+    #[cfg(feature = "tools")]
+    async fn chat_structured<T>(&self, request: &ChatRequest, max_corrections: u32) -> Result<T>
+    where
+        T: JsonSchema + DeserializeOwned + Send,
+    {
+        let mut request = request
+            .clone()
+            .with_format(FormatSetting::schema_for::<T>());
+        for _ in 0..max_corrections {
+            let response = self.chat(&request).await?;
+            match parse_reply(response.content()) {
+                Err(e) => chat_correction(&mut request, &e),
+                result => return result,
+            }
+        }
+        parse_reply(self.chat(&request).await?.content())
+    }
+
+    #[cfg(feature = "tools")]
+    async fn generate_structured<T>(
+        &self,
+        request: &GenerateRequest,
+        max_corrections: u32,
+    ) -> Result<T>
+    where
+        T: JsonSchema + DeserializeOwned + Send,
+    {
+        let original = request
+            .clone()
+            .with_format(FormatSetting::schema_for::<T>());
+        let mut request = original.clone();
+        for _ in 0..max_corrections {
+            let response = self.generate(&request).await?;
+            match parse_reply(response.text()) {
+                Err(e) => request = generate_correction(&original, &e),
+                result => return result,
+            }
+        }
+        parse_reply(self.generate(&request).await?.text())
     }
 
     #[cfg(feature = "model")]
     async fn create_model(&self, request: &CreateRequest) -> Result<CreateResponse> {
-        let url = self.config.url(Endpoints::CREATE);
-        self.post_with_retry(&url, request).await
+        self.post_with_retry(Endpoints::CREATE, request).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn create_model_stream(
+        &self,
+        request: &CreateRequest,
+    ) -> Result<ResponseStream<CreateResponse>> {
+        let request = CreateRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self
+            .post_stream_with_retry(Endpoints::CREATE, &request)
+            .await?;
+        Ok(ndjson_stream(response, CreateResponse::is_success))
+    }
+
+    #[cfg(feature = "model")]
+    async fn blob_exists(&self, digest: &str) -> Result<bool> {
+        self.blob_exists_with_retry(digest).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn push_blob(&self, digest: &str, path: &Path) -> Result<()> {
+        self.post_file_with_retry(digest, path).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn create_from_gguf(
+        &self,
+        request: &CreateRequest,
+        path: &Path,
+        adapters: &[&Path],
+    ) -> Result<CreateResponse> {
+        let mut request = request
+            .clone()
+            .with_file(file_name(path)?, self.upload_blob_if_missing(path).await?);
+        for adapter in adapters {
+            let digest = self.upload_blob_if_missing(adapter).await?;
+            request = request.with_adapter(file_name(adapter)?, digest);
+        }
+        self.create_model(&request).await
     }
 
     #[cfg(feature = "model")]
     async fn pull_model(&self, request: &PullRequest) -> Result<PullResponse> {
-        let url = self.config.url(Endpoints::PULL);
-        self.post_with_retry(&url, request).await
+        self.post_with_retry(Endpoints::PULL, request).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn pull_model_stream(
+        &self,
+        request: &PullRequest,
+    ) -> Result<ResponseStream<ProgressResponse>> {
+        let request = PullRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self
+            .post_stream_with_retry(Endpoints::PULL, &request)
+            .await?;
+        Ok(ndjson_stream(response, ProgressResponse::is_success))
     }
 
     #[cfg(feature = "model")]
     async fn push_model(&self, request: &PushRequest) -> Result<PushResponse> {
-        let url = self.config.url(Endpoints::PUSH);
-        self.post_with_retry(&url, request).await
+        self.post_with_retry(Endpoints::PUSH, request).await
+    }
+
+    #[cfg(feature = "model")]
+    async fn push_model_stream(
+        &self,
+        request: &PushRequest,
+    ) -> Result<ResponseStream<ProgressResponse>> {
+        let request = PushRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self
+            .post_stream_with_retry(Endpoints::PUSH, &request)
+            .await?;
+        Ok(ndjson_stream(response, ProgressResponse::is_success))
     }
 }
@@ -5,14 +5,29 @@ use crate::{
     Result, VersionResponse,
 };
 
+#[cfg(feature = "tools")]
+use crate::FormatSetting;
+#[cfg(feature = "tools")]
+use schemars::JsonSchema;
+#[cfg(feature = "tools")]
+use serde::de::DeserializeOwned;
+
 #[cfg(feature = "model")]
 use crate::{
-    CopyRequest, CreateRequest, CreateResponse, DeleteRequest, ListResponse, PsResponse,
-    PullRequest, PullResponse, PushRequest, PushResponse, ShowRequest, ShowResponse,
+    CopyRequest, CreateRequest, CreateResponse, DeleteRequest, ListResponse, ProgressResponse,
+    PsResponse, PullRequest, PullResponse, PushRequest, PushResponse, ShowRequest, ShowResponse,
 };
 
-use super::OllamaClient;
+#[cfg(feature = "model")]
+use std::path::Path;
+
+#[cfg(feature = "model")]
+use super::blob::file_name;
+use super::client::validate_options;
 use super::endpoints::Endpoints;
+#[cfg(feature = "tools")]
+use super::structured::{chat_correction, generate_correction, parse_reply};
+use super::{OllamaClient, ResponseIter};
 
 /// Sync API operations trait
 ///
@@ -291,6 +306,48 @@ pub trait OllamaApiSync: Send + Sync {
     /// ```
     fn generate_blocking(&self, request: &GenerateRequest) -> Result<GenerateResponse>;
 
+    /// Generate text completion (blocking, streaming)
+    ///
+    /// Generates a text completion for the provided prompt, returning an
+    /// iterator that blocks until each chunk arrives. The request is sent
+    /// with `stream: true` regardless of its `stream` field.
+    ///
+    /// The final chunk has `done: true` and holds the timing and token
+    /// counts; iteration ends after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Generate request containing model, prompt, and options
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Model doesn't exist (404)
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// Errors that occur after streaming has started, including an
+    /// `{"error": ...}` object sent by the server, are yielded as iterator items.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync, GenerateRequest};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = GenerateRequest::new("qwen3:0.6b", "Tell me a joke.");
+    /// for chunk in client.generate_stream_blocking(&request)? {
+    ///     print!("{}", chunk?.text().unwrap_or(""));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn generate_stream_blocking(
+        &self,
+        request: &GenerateRequest,
+    ) -> Result<ResponseIter<GenerateResponse>>;
+
     /// Chat completion (blocking, non-streaming)
     ///
     /// Generates the next message in a chat conversation.
@@ -324,6 +381,111 @@ pub trait OllamaApiSync: Send + Sync {
     /// ```
     fn chat_blocking(&self, request: &ChatRequest) -> Result<ChatResponse>;
 
+    /// Send a chat request and parse the reply into `T` (blocking)
+    ///
+    /// The request's `format` is replaced by the JSON schema of `T`, derived
+    /// with `schemars`, so the model is constrained to produce matching JSON.
+    /// The reply is then deserialized into `T`.
+    ///
+    /// When parsing fails and `max_corrections` is not yet used up,
+    /// the failed reply and a user message describing the
+    /// parse error are appended to the conversation and the request is sent
+    /// again.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Chat request; `format` is overwritten
+    /// * `max_corrections` - How many corrective follow-ups to send (0 = none)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The reply still cannot be parsed into `T`, as
+    ///   [`Error::StructuredOutputError`](crate::Error::StructuredOutputError)
+    ///   holding the raw reply text
+    /// - Any error returned by `chat_blocking`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{ChatMessage, ChatRequest, OllamaClient, OllamaApiSync};
+    /// use schemars::JsonSchema;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, JsonSchema)]
+    /// struct Capital {
+    ///     country: String,
+    ///     city: String,
+    /// }
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("What is the capital of France?")]);
+    /// let capital: Capital = client.chat_structured_blocking(&request, 2)?;
+    /// println!("{} -> {}", capital.country, capital.city);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tools")]
+    fn chat_structured_blocking<T>(&self, request: &ChatRequest, max_corrections: u32) -> Result<T>
+    where
+        Self: Sized,
+        T: JsonSchema + DeserializeOwned + Send;
+
+    /// Send a generate request and parse the reply into `T` (blocking)
+    ///
+    /// The request's `format` is replaced by the JSON schema of `T`, derived
+    /// with `schemars`, so the model is constrained to produce matching JSON.
+    /// The reply is then deserialized into `T`.
+    ///
+    /// When parsing fails and `max_corrections` is not yet used up,
+    /// the failed reply and a note describing the parse error
+    /// are appended to the prompt and the request is sent again.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Generate request; `format` is overwritten
+    /// * `max_corrections` - How many corrective follow-ups to send (0 = none)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The reply still cannot be parsed into `T`, as
+    ///   [`Error::StructuredOutputError`](crate::Error::StructuredOutputError)
+    ///   holding the raw reply text
+    /// - Any error returned by `generate_blocking`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{GenerateRequest, OllamaClient, OllamaApiSync};
+    /// use schemars::JsonSchema;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, JsonSchema)]
+    /// struct Capital {
+    ///     country: String,
+    ///     city: String,
+    /// }
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = GenerateRequest::new("qwen3:0.6b", "What is the capital of France?");
+    /// let capital: Capital = client.generate_structured_blocking(&request, 2)?;
+    /// println!("{} -> {}", capital.country, capital.city);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "tools")]
+    fn generate_structured_blocking<T>(
+        &self,
+        request: &GenerateRequest,
+        max_corrections: u32,
+    ) -> Result<T>
+    where
+        Self: Sized,
+        T: JsonSchema + DeserializeOwned + Send;
+
     /// Create a custom model (blocking, non-streaming)
     ///
     /// Creates a new model from an existing model with custom configuration.
@@ -358,6 +520,164 @@ pub trait OllamaApiSync: Send + Sync {
     #[cfg(feature = "model")]
     fn create_model_blocking(&self, request: &CreateRequest) -> Result<CreateResponse>;
 
+    /// Create a custom model (blocking, streaming)
+    ///
+    /// Creates a new model like `create_model_blocking`, yielding a `CreateResponse` for
+    /// each step the server reports, such as "reading model metadata",
+    /// "quantizing" or "writing manifest". The request is sent with
+    /// `stream: true` regardless of its `stream` field.
+    ///
+    /// The last status is "success" and iteration ends after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Create request containing model name, base model, and options
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Base model doesn't exist (404)
+    /// - Model name is invalid
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// Errors that occur after streaming has started, including an
+    /// `{"error": ...}` object sent by the server, are yielded as iterator items.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync, CreateRequest};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = CreateRequest::from_model("qwen-q4", "qwen3:0.6b")
+    ///     .with_quantize("q4_K_M");
+    /// for event in client.create_model_stream_blocking(&request)? {
+    ///     println!("{}", event?.status().unwrap_or(""));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    fn create_model_stream_blocking(
+        &self,
+        request: &CreateRequest,
+    ) -> Result<ResponseIter<CreateResponse>>;
+
+    /// Check whether a blob exists on the server (blocking)
+    ///
+    /// Sends `HEAD /api/blobs/:digest`. Blobs are the files a model is
+    /// created from, such as GGUF weights or LoRA adapters.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Blob digest in the form `sha256:<hex>`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// A missing blob is not an error; `Ok(false)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let digest = "sha256:29fdb92e57cf0827ded04ae6461b5931d01fa595843f55d36f5b275a52087dd2";
+    /// if !client.blob_exists_blocking(digest)? {
+    ///     println!("blob must be uploaded first");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    fn blob_exists_blocking(&self, digest: &str) -> Result<bool>;
+
+    /// Upload a file as a blob (blocking)
+    ///
+    /// Sends `POST /api/blobs/:digest`, streaming the file from disk so
+    /// large model files are never loaded into memory. The server checks
+    /// the content against `digest`.
+    ///
+    /// # Arguments
+    ///
+    /// * `digest` - Blob digest in the form `sha256:<hex>`
+    /// * `path` - File to upload
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file cannot be read
+    /// - The digest does not match the file (400)
+    /// - Network request fails
+    /// - Maximum retry attempts exceeded
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync};
+    /// use std::path::Path;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let digest = "sha256:29fdb92e57cf0827ded04ae6461b5931d01fa595843f55d36f5b275a52087dd2";
+    /// client.push_blob_blocking(digest, Path::new("model.gguf"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    fn push_blob_blocking(&self, digest: &str, path: &Path) -> Result<()>;
+
+    /// Create a model from a local GGUF file (blocking)
+    ///
+    /// Computes the SHA-256 digest of the model file and of each adapter,
+    /// uploads the ones the server does not have yet, and then calls
+    /// `create_model_blocking` with `files` and `adapters` mapping each file name
+    /// to its digest. Other fields of `request`, such as `system` or
+    /// `quantize`, are sent unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - Create request with the model name and options
+    /// * `path` - GGUF model file
+    /// * `adapters` - LoRA adapter files, may be empty
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A file cannot be read
+    /// - An upload fails
+    /// - The server rejects the model
+    /// - Network request fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync, CreateRequest};
+    /// use std::path::Path;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = CreateRequest::new("my-model").with_quantize("q4_K_M");
+    /// let response = client.create_from_gguf_blocking(&request, Path::new("model.gguf"), &[])?;
+    /// println!("Status: {:?}", response.status());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    fn create_from_gguf_blocking(
+        &self,
+        request: &CreateRequest,
+        path: &Path,
+        adapters: &[&Path],
+    ) -> Result<CreateResponse>;
+
     /// Pull (download) a model from the Ollama registry (blocking).
     ///
     /// Downloads the specified model from the remote registry to the local
@@ -374,7 +694,10 @@ pub trait OllamaApiSync: Send + Sync {
     ///
     /// # Errors
     ///
-    /// * `HttpStatusError(404)` - Model not found in registry
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found in registry; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `HttpStatusError` - Non-success status without a parseable error body
     /// * `HttpError` - Network or HTTP errors
     /// * `MaxRetriesExceededError` - Server errors after all retries
     ///
@@ -394,6 +717,56 @@ pub trait OllamaApiSync: Send + Sync {
     #[cfg(feature = "model")]
     fn pull_model_blocking(&self, request: &PullRequest) -> Result<PullResponse>;
 
+    /// Pull (download) a model from the Ollama registry, streaming progress (blocking).
+    ///
+    /// Yields a [`ProgressResponse`](crate::ProgressResponse) for every status
+    /// change and download tick reported by the server, so long downloads
+    /// can be shown to the user as they happen.
+    ///
+    /// The last event has the status `"success"` and iteration ends after it.
+    /// Combine events with [`TransferProgress`](crate::TransferProgress) to
+    /// get overall bytes done and a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The pull request containing the model name and options
+    ///
+    /// # Errors
+    ///
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found in registry; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `HttpStatusError` - Non-success status without a parseable error body
+    /// * `HttpError` - Network or HTTP errors
+    /// * `MaxRetriesExceededError` - Server errors after all retries
+    ///
+    /// Errors that occur after streaming has started are yielded as iterator items.
+    /// An `{"error": ...}` object sent by the server mid-stream, such as a
+    /// failed transfer, arrives as an `Err(Error::ApiError { .. })` item,
+    /// even though the call itself returned `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync, PullRequest, TransferProgress};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = PullRequest::new("all-minilm:33m");
+    /// let mut progress = TransferProgress::new();
+    /// for event in client.pull_model_stream_blocking(&request)? {
+    ///     progress.update(&event?);
+    ///     println!("{:?} {:?}", progress.status(), progress.percentage());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    fn pull_model_stream_blocking(
+        &self,
+        request: &PullRequest,
+    ) -> Result<ResponseIter<ProgressResponse>>;
+
     /// Push (upload) a model to the Ollama registry (blocking).
     ///
     /// Uploads the specified model to a remote registry. Requires proper
@@ -409,8 +782,12 @@ pub trait OllamaApiSync: Send + Sync {
     ///
     /// # Errors
     ///
-    /// * `HttpStatusError(404)` - Model not found locally
-    /// * `HttpStatusError(401)` - Unauthorized (invalid credentials)
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found locally; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `ApiError` with [`ApiErrorKind::Unauthorized`](crate::ApiErrorKind::Unauthorized) -
+    ///   Invalid credentials for the registry
+    /// * `HttpStatusError` - Non-success status without a parseable error body
     /// * `HttpError` - Network or HTTP errors
     /// * `MaxRetriesExceededError` - Server errors after all retries
     ///
@@ -429,74 +806,238 @@ pub trait OllamaApiSync: Send + Sync {
     /// ```
     #[cfg(feature = "model")]
     fn push_model_blocking(&self, request: &PushRequest) -> Result<PushResponse>;
+
+    /// Push (upload) a model to the Ollama registry, streaming progress (blocking).
+    ///
+    /// Yields a [`ProgressResponse`](crate::ProgressResponse) for every status
+    /// change and upload tick reported by the server. Requires proper
+    /// authentication and namespace permissions.
+    ///
+    /// The last event has the status `"success"` and iteration ends after it.
+    /// Combine events with [`TransferProgress`](crate::TransferProgress) to
+    /// get overall bytes done and a percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The push request containing the model name and options
+    ///
+    /// # Errors
+    ///
+    /// * `ApiError` with [`ApiErrorKind::ModelNotFound`](crate::ApiErrorKind::ModelNotFound) -
+    ///   Model not found locally; check with
+    ///   [`Error::is_model_not_found`](crate::Error::is_model_not_found)
+    /// * `ApiError` with [`ApiErrorKind::Unauthorized`](crate::ApiErrorKind::Unauthorized) -
+    ///   Invalid credentials for the registry
+    /// * `HttpStatusError` - Non-success status without a parseable error body
+    /// * `HttpError` - Network or HTTP errors
+    /// * `MaxRetriesExceededError` - Server errors after all retries
+    ///
+    /// Errors that occur after streaming has started are yielded as iterator items.
+    /// An `{"error": ...}` object sent by the server mid-stream, such as a
+    /// failed transfer, arrives as an `Err(Error::ApiError { .. })` item,
+    /// even though the call itself returned `Ok`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{OllamaClient, OllamaApiSync, PushRequest, TransferProgress};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OllamaClient::default()?;
+    /// let request = PushRequest::new("myuser/mymodel:latest");
+    /// let mut progress = TransferProgress::new();
+    /// for event in client.push_model_stream_blocking(&request)? {
+    ///     progress.update(&event?);
+    ///     println!("{:?} {:?}", progress.status(), progress.percentage());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "model")]
+    fn push_model_stream_blocking(
+        &self,
+        request: &PushRequest,
+    ) -> Result<ResponseIter<ProgressResponse>>;
 }
 
 impl OllamaApiSync for OllamaClient {
     fn version_blocking(&self) -> Result<VersionResponse> {
-        let url = self.config.url(Endpoints::VERSION);
-        self.get_blocking_with_retry(&url)
+        self.get_blocking_with_retry(Endpoints::VERSION)
     }
 
     #[cfg(feature = "model")]
     fn list_models_blocking(&self) -> Result<ListResponse> {
-        let url = self.config.url(Endpoints::TAGS);
-        self.get_blocking_with_retry(&url)
+        self.get_blocking_with_retry(Endpoints::TAGS)
     }
 
     #[cfg(feature = "model")]
     fn copy_model_blocking(&self, request: &CopyRequest) -> Result<()> {
-        let url = self.config.url(Endpoints::COPY);
-        self.post_empty_blocking_with_retry(&url, request)
+        self.post_empty_blocking_with_retry(Endpoints::COPY, request)
     }
 
     #[cfg(feature = "model")]
     fn list_running_models_blocking(&self) -> Result<PsResponse> {
-        let url = self.config.url(Endpoints::PS);
-        self.get_blocking_with_retry(&url)
+        self.get_blocking_with_retry(Endpoints::PS)
     }
 
     #[cfg(feature = "model")]
     fn delete_model_blocking(&self, request: &DeleteRequest) -> Result<()> {
-        let url = self.config.url(Endpoints::DELETE);
-        self.delete_empty_blocking_with_retry(&url, request)
+        self.delete_empty_blocking_with_retry(Endpoints::DELETE, request)
     }
 
     #[cfg(feature = "model")]
     fn show_model_blocking(&self, request: &ShowRequest) -> Result<ShowResponse> {
-        let url = self.config.url(Endpoints::SHOW);
-        self.post_blocking_with_retry(&url, request)
+        self.post_blocking_with_retry(Endpoints::SHOW, request)
     }
 
     fn embed_blocking(&self, request: &EmbedRequest) -> Result<EmbedResponse> {
-        let url = self.config.url(Endpoints::EMBED);
-        self.post_blocking_with_retry(&url, request)
+        validate_options(request.options.as_ref())?;
+        self.post_blocking_with_retry(Endpoints::EMBED, request)
     }
 
     fn generate_blocking(&self, request: &GenerateRequest) -> Result<GenerateResponse> {
-        let url = self.config.url(Endpoints::GENERATE);
-        self.post_blocking_with_retry(&url, request)
+        validate_options(request.options.as_ref())?;
+        self.post_blocking_with_retry(Endpoints::GENERATE, request)
+    }
+
+    fn generate_stream_blocking(
+        &self,
+        request: &GenerateRequest,
+    ) -> Result<ResponseIter<GenerateResponse>> {
+        validate_options(request.options.as_ref())?;
+        let request = GenerateRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self.post_stream_blocking_with_retry(Endpoints::GENERATE, &request)?;
+        Ok(ResponseIter::new(response, GenerateResponse::is_done))
     }
 
     fn chat_blocking(&self, request: &ChatRequest) -> Result<ChatResponse> {
-        let url = self.config.url(Endpoints::CHAT);
-        self.post_blocking_with_retry(&url, request)
+        validate_options(request.options.as_ref())?;
+        self.post_blocking_with_retry(Endpoints::CHAT, request)
+    }
+
+    #[cfg(feature = "tools")]
+    fn chat_structured_blocking<T>(&self, request: &ChatRequest, max_corrections: u32) -> Result<T>
+    where
+        T: JsonSchema + DeserializeOwned + Send,
+    {
+        let mut request = request
+            .clone()
+            .with_format(FormatSetting::schema_for::<T>());
+        for _ in 0..max_corrections {
+            let response = self.chat_blocking(&request)?;
+            match parse_reply(response.content()) {
+                Err(e) => chat_correction(&mut request, &e),
+                result => return result,
+            }
+        }
+        parse_reply(self.chat_blocking(&request)?.content())
+    }
+
+    #[cfg(feature = "tools")]
+    fn generate_structured_blocking<T>(
+        &self,
+        request: &GenerateRequest,
+        max_corrections: u32,
+    ) -> Result<T>
+    where
+        T: JsonSchema + DeserializeOwned + Send,
+    {
+        let original = request
+            .clone()
+            .with_format(FormatSetting::schema_for::<T>());
+        let mut request = original.clone();
+        for _ in 0..max_corrections {
+            let response = self.generate_blocking(&request)?;
+            match parse_reply(response.text()) {
+                Err(e) => request = generate_correction(&original, &e),
+                result => return result,
+            }
+        }
+        parse_reply(self.generate_blocking(&request)?.text())
     }
 
     #[cfg(feature = "model")]
     fn create_model_blocking(&self, request: &CreateRequest) -> Result<CreateResponse> {
-        let url = self.config.url(Endpoints::CREATE);
-        self.post_blocking_with_retry(&url, request)
+        self.post_blocking_with_retry(Endpoints::CREATE, request)
+    }
+
+    #[cfg(feature = "model")]
+    fn create_model_stream_blocking(
+        &self,
+        request: &CreateRequest,
+    ) -> Result<ResponseIter<CreateResponse>> {
+        let request = CreateRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self.post_stream_blocking_with_retry(Endpoints::CREATE, &request)?;
+        Ok(ResponseIter::new(response, CreateResponse::is_success))
+    }
+
+    #[cfg(feature = "model")]
+    fn blob_exists_blocking(&self, digest: &str) -> Result<bool> {
+        self.blob_exists_blocking_with_retry(digest)
+    }
+
+    #[cfg(feature = "model")]
+    fn push_blob_blocking(&self, digest: &str, path: &Path) -> Result<()> {
+        self.post_file_blocking_with_retry(digest, path)
+    }
+
+    #[cfg(feature = "model")]
+    fn create_from_gguf_blocking(
+        &self,
+        request: &CreateRequest,
+        path: &Path,
+        adapters: &[&Path],
+    ) -> Result<CreateResponse> {
+        let mut request = request.clone().with_file(
+            file_name(path)?,
+            self.upload_blob_if_missing_blocking(path)?,
+        );
+        for adapter in adapters {
+            let digest = self.upload_blob_if_missing_blocking(adapter)?;
+            request = request.with_adapter(file_name(adapter)?, digest);
+        }
+        self.create_model_blocking(&request)
     }
 
     #[cfg(feature = "model")]
     fn pull_model_blocking(&self, request: &PullRequest) -> Result<PullResponse> {
-        let url = self.config.url(Endpoints::PULL);
-        self.post_blocking_with_retry(&url, request)
+        self.post_blocking_with_retry(Endpoints::PULL, request)
+    }
+
+    #[cfg(feature = "model")]
+    fn pull_model_stream_blocking(
+        &self,
+        request: &PullRequest,
+    ) -> Result<ResponseIter<ProgressResponse>> {
+        let request = PullRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self.post_stream_blocking_with_retry(Endpoints::PULL, &request)?;
+        Ok(ResponseIter::new(response, ProgressResponse::is_success))
     }
 
     #[cfg(feature = "model")]
     fn push_model_blocking(&self, request: &PushRequest) -> Result<PushResponse> {
-        let url = self.config.url(Endpoints::PUSH);
-        self.post_blocking_with_retry(&url, request)
+        self.post_blocking_with_retry(Endpoints::PUSH, request)
+    }
+
+    #[cfg(feature = "model")]
+    fn push_model_stream_blocking(
+        &self,
+        request: &PushRequest,
+    ) -> Result<ResponseIter<ProgressResponse>> {
+        let request = PushRequest {
+            stream: Some(true),
+            ..request.clone()
+        };
+        let response = self.post_stream_blocking_with_retry(Endpoints::PUSH, &request)?;
+        Ok(ResponseIter::new(response, ProgressResponse::is_success))
     }
 }
@@ -0,0 +1,204 @@
+//! Authentication for requests sent through a proxy or hosted API
+
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::header::HeaderValue;
+
+use crate::{Error, Result};
+
+/// Supplies a bearer token before each request
+///
+/// Use a provider when tokens expire and must be refreshed, for example
+/// when Ollama runs behind an authenticating reverse proxy. The provider
+/// is called before every request attempt, including retries, so it
+/// should cache the token and only refresh it when needed.
+///
+/// The provider may block, e.g. to read a token file: the async API calls
+/// it on Tokio's blocking thread pool.
+///
+/// Closures returning `Result<String>` implement this trait.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{Auth, ClientConfig};
+///
+/// let config = ClientConfig {
+///     auth: Some(Auth::provider(|| Ok(std::env::var("PROXY_TOKEN").unwrap_or_default()))),
+///     ..Default::default()
+/// };
+/// ```
+pub trait CredentialProvider: Send + Sync {
+    /// Get the bearer token for the next request
+    ///
+    /// # Errors
+    ///
+    /// Returning an error aborts the request with that error. Use
+    /// [`Error::AuthError`] for failures to obtain a token.
+    fn token(&self) -> Result<String>;
+}
+
+impl<F> CredentialProvider for F
+where
+    F: Fn() -> Result<String> + Send + Sync,
+{
+    fn token(&self) -> Result<String> {
+        self()
+    }
+}
+
+/// Credentials sent in the `Authorization` header of every request
+///
+/// API keys for hosted Ollama services are sent as bearer tokens, so use
+/// [`Auth::bearer`] for them. Keys expected in a custom header belong in
+/// [`ClientConfig::headers`](super::ClientConfig::headers) instead.
+///
+/// The `Debug` output never contains tokens or passwords.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{Auth, ClientConfig, OllamaClient};
+///
+/// let config = ClientConfig {
+///     base_url: "https://ollama.example.com".to_string(),
+///     auth: Some(Auth::bearer("my-api-key")),
+///     ..Default::default()
+/// };
+/// let client = OllamaClient::new(config)?;
+/// # Ok::<(), ollama_oxide::Error>(())
+/// ```
+#[derive(Clone)]
+pub enum Auth {
+    /// `Authorization: Bearer <token>`
+    Bearer(String),
+
+    /// `Authorization: Basic <base64(username:password)>`
+    Basic {
+        /// User name
+        username: String,
+        /// Password, if any
+        password: Option<String>,
+    },
+
+    /// Bearer token fetched from a provider before each request
+    Provider(Arc<dyn CredentialProvider>),
+}
+
+impl Auth {
+    /// Create bearer token authentication
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Bearer token or API key
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self::Bearer(token.into())
+    }
+
+    /// Create HTTP basic authentication
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - User name
+    /// * `password` - Password, if any
+    pub fn basic(username: impl Into<String>, password: Option<impl Into<String>>) -> Self {
+        Self::Basic {
+            username: username.into(),
+            password: password.map(Into::into),
+        }
+    }
+
+    /// Create authentication backed by a credential provider
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - Provider called for a bearer token before each request
+    pub fn provider(provider: impl CredentialProvider + 'static) -> Self {
+        Self::Provider(Arc::new(provider))
+    }
+
+    /// Check that static credentials can be sent in a header
+    ///
+    /// Provider tokens are checked when they are fetched.
+    pub(super) fn validate(&self) -> Result<()> {
+        match self {
+            Self::Bearer(token) => bearer_header(token).map(|_| ()),
+            Self::Basic { username, .. } if username.contains(':') => Err(Error::AuthError(
+                "basic auth username must not contain ':'".to_string(),
+            )),
+            Self::Basic { .. } | Self::Provider(_) => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bearer(_) => f.write_str("Bearer(<redacted>)"),
+            Self::Basic { username, .. } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            Self::Provider(_) => f.write_str("Provider(..)"),
+        }
+    }
+}
+
+/// Build a sensitive `Authorization` header value for a bearer token
+pub(super) fn bearer_header(token: &str) -> Result<HeaderValue> {
+    let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+        .map_err(|_| Error::AuthError("token contains invalid header characters".to_string()))?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bearer_header_is_sensitive() {
+        let value = bearer_header("abc123").unwrap();
+        assert_eq!(value.to_str().unwrap(), "Bearer abc123");
+        assert!(value.is_sensitive());
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_token() {
+        assert!(Auth::bearer("abc123").validate().is_ok());
+        assert!(matches!(
+            Auth::bearer("line\nbreak").validate(),
+            Err(Error::AuthError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rejects_colon_in_username() {
+        assert!(Auth::basic("user", Some("p:ss")).validate().is_ok());
+        assert!(matches!(
+            Auth::basic("us:er", None::<String>).validate(),
+            Err(Error::AuthError(_))
+        ));
+    }
+
+    #[test]
+    fn test_provider_from_closure() {
+        let auth = Auth::provider(|| Ok("fresh".to_string()));
+        let Auth::Provider(provider) = auth else {
+            panic!("expected provider");
+        };
+        assert_eq!(provider.token().unwrap(), "fresh");
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let bearer = format!("{:?}", Auth::bearer("secret-token"));
+        let basic = format!("{:?}", Auth::basic("alice", Some("hunter2")));
+
+        assert!(!bearer.contains("secret-token"));
+        assert!(basic.contains("alice"));
+        assert!(!basic.contains("hunter2"));
+    }
+}
@@ -0,0 +1,94 @@
+//! Blob digests and file names for `/api/blobs`
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use super::endpoints::Endpoints;
+use crate::{Error, Result};
+use sha2::{Digest, Sha256};
+
+/// Size of each chunk read from a file while hashing
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Endpoint path for a blob
+pub(super) fn blob_endpoint(digest: &str) -> String {
+    format!("{}/{}", Endpoints::BLOBS, digest)
+}
+
+/// Compute the `sha256:<hex>` digest of a file without loading it into memory
+pub(super) fn file_digest(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    loop {
+        match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => hasher.update(&buf[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let hex: String = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    Ok(format!("sha256:{}", hex))
+}
+
+/// Compute a file digest on a blocking thread
+pub(super) async fn file_digest_async(path: &Path) -> Result<String> {
+    let path = path.to_path_buf();
+    tokio::task::spawn_blocking(move || file_digest(&path))
+        .await
+        .map_err(|e| Error::IoError(e.to_string()))?
+}
+
+/// Get the file name used as key in `CreateRequest::files` and `adapters`
+pub(super) fn file_name(path: &Path) -> Result<String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(str::to_string)
+        .ok_or_else(|| Error::IoError(format!("{} has no valid file name", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blob_endpoint() {
+        assert_eq!(blob_endpoint("sha256:abc"), "/api/blobs/sha256:abc");
+    }
+
+    #[test]
+    fn test_file_digest() {
+        let path = std::env::temp_dir().join(format!("ollama-oxide-digest-{}", std::process::id()));
+        std::fs::write(&path, b"hello world").unwrap();
+
+        let digest = file_digest(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            digest,
+            "sha256:b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+
+    #[test]
+    fn test_file_digest_missing_file() {
+        let result = file_digest(Path::new("/nonexistent/model.gguf"));
+        assert!(matches!(result, Err(Error::IoError(_))));
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(
+            file_name(Path::new("/models/llama.gguf")).unwrap(),
+            "llama.gguf"
+        );
+        assert!(file_name(Path::new("/")).is_err());
+    }
+}
@@ -1,17 +1,43 @@
 //! Ollama HTTP client implementation
 
-use crate::{Error, Result};
-use reqwest::Client;
+use crate::{Error, ModelOptions, Result};
+use reqwest::Method;
+#[cfg(feature = "model")]
+use std::path::Path;
 use std::sync::Arc;
-use std::time::Duration;
-use url::Url;
-
-use super::ClientConfig;
+use std::time::Instant;
+
+#[cfg(feature = "model")]
+use super::blob::{blob_endpoint, file_digest, file_digest_async};
+#[cfg(feature = "model")]
+use super::endpoints::Endpoints;
+use super::middleware::{Before, Middleware, MiddlewareChain};
+use super::retry_policy::retry_after;
+use super::transport::{RequestBody, Transport, TransportRequest, TransportResponse};
+use super::{ClientConfig, ReqwestTransport};
+#[cfg(feature = "testing")]
+use crate::testing::cassette_transport::CassetteTransport;
+
+/// Validate request options before they are sent
+///
+/// # Errors
+///
+/// Returns [`Error::ValidationError`] if any option is out of range
+pub(super) fn validate_options(options: Option<&ModelOptions>) -> Result<()> {
+    options.map_or(Ok(()), ModelOptions::validate)
+}
 
 /// HTTP client for Ollama API
 ///
 /// This client is cloneable and can be safely shared across threads.
-/// The internal HTTP client is wrapped in Arc for efficient cloning.
+/// Requests go through a [`Transport`], wrapped in Arc for efficient
+/// cloning, so clones share the same connection pools.
+///
+/// [`new`](Self::new) sends requests over HTTP with a
+/// [`ReqwestTransport`]; [`with_transport`](Self::with_transport) plugs in
+/// any other transport. Retries and response decoding happen in the
+/// client, on top of the transport, and [`Middleware`] registered with
+/// [`with_middleware`](Self::with_middleware) runs around each attempt.
 ///
 /// # Thread Safety
 ///
@@ -32,6 +58,7 @@ use super::ClientConfig;
 ///     base_url: "http://localhost:11434".to_string(),
 ///     timeout: Duration::from_secs(30),
 ///     max_retries: 3,
+///     ..Default::default()
 /// };
 /// let client = OllamaClient::new(config).unwrap();
 ///
@@ -41,7 +68,10 @@ use super::ClientConfig;
 #[derive(Clone, Debug)]
 pub struct OllamaClient {
     pub(super) config: ClientConfig,
-    pub(super) client: Arc<Client>,
+    /// Transport carrying every request
+    pub(super) transport: Arc<dyn Transport>,
+    /// Hooks run around every attempt
+    middlewares: MiddlewareChain,
 }
 
 impl OllamaClient {
@@ -55,7 +85,9 @@ impl OllamaClient {
     ///
     /// Returns an error if:
     /// - The base URL is invalid or malformed
-    /// - The URL scheme is not http or https
+    /// - The URL scheme is not http, https or (on Unix) unix
+    /// - The configured credentials cannot be sent in a header
+    /// - A proxy URL, root certificate or client identity is invalid
     /// - The HTTP client cannot be built
     ///
     /// # Examples
@@ -68,35 +100,104 @@ impl OllamaClient {
     ///     base_url: "http://localhost:11434".to_string(),
     ///     timeout: Duration::from_secs(30),
     ///     max_retries: 3,
+    ///     ..Default::default()
     /// };
     ///
     /// let client = OllamaClient::new(config)?;
     /// # Ok::<(), ollama_oxide::Error>(())
     /// ```
     pub fn new(config: ClientConfig) -> Result<Self> {
-        // Validate base URL
-        let url = Url::parse(&config.base_url)?;
-
-        // Ensure URL has a scheme (http or https)
-        if url.scheme() != "http" && url.scheme() != "https" {
-            return Err(Error::InvalidUrlError(
-                url::ParseError::RelativeUrlWithoutBase,
-            ));
-        }
+        let transport = ReqwestTransport::new(&config)?;
+        Self::with_transport(config, transport)
+    }
 
-        let client = Client::builder().timeout(config.timeout).build()?;
+    /// Create client sending its requests through a custom transport
+    ///
+    /// The retry settings of `config` still apply. Its HTTP settings, such
+    /// as the base URL, timeouts and credentials, are only used by a
+    /// [`ReqwestTransport`].
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Client configuration
+    /// * `transport` - Transport that sends every request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configured cassette cannot be opened
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{ClientConfig, OllamaClient, ReqwestTransport};
+    ///
+    /// let config = ClientConfig::default();
+    /// let transport = ReqwestTransport::new(&config)?;
+    /// let client = OllamaClient::with_transport(config, transport)?;
+    /// # Ok::<(), ollama_oxide::Error>(())
+    /// ```
+    pub fn with_transport(
+        config: ClientConfig,
+        transport: impl Transport + 'static,
+    ) -> Result<Self> {
+        let transport: Arc<dyn Transport> = Arc::new(transport);
+
+        #[cfg(feature = "testing")]
+        let transport: Arc<dyn Transport> = match &config.cassette {
+            Some(mode) => Arc::new(CassetteTransport::open(mode, transport)?),
+            None => transport,
+        };
 
         Ok(Self {
             config,
-            client: Arc::new(client),
+            transport,
+            middlewares: MiddlewareChain::default(),
         })
     }
 
+    /// Register a middleware to run around every request
+    ///
+    /// Middlewares run in registration order before a request is sent,
+    /// and in reverse order once its response or error is known. See
+    /// [`Middleware`] for what each hook can do.
+    ///
+    /// # Arguments
+    ///
+    /// * `middleware` - Hooks to run for requests of this client and its clones
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::{Middleware, OllamaClient, TransportRequest, TransportResponse};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Debug)]
+    /// struct Latency;
+    ///
+    /// impl Middleware for Latency {
+    ///     fn after_response(
+    ///         &self,
+    ///         request: &TransportRequest,
+    ///         response: &TransportResponse,
+    ///         elapsed: Duration,
+    ///     ) {
+    ///         println!("{} {} -> {} in {:?}", request.method, request.path, response.status, elapsed);
+    ///     }
+    /// }
+    ///
+    /// let client = OllamaClient::default()?.with_middleware(Latency);
+    /// # Ok::<(), ollama_oxide::Error>(())
+    /// ```
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
     /// Create client with custom base URL and default timeout/retry
     ///
     /// # Arguments
     ///
-    /// * `base_url` - Base URL for the Ollama API (must include http:// or https://)
+    /// * `base_url` - Base URL for the Ollama API (http://, https:// or unix://)
     ///
     /// # Errors
     ///
@@ -118,6 +219,29 @@ impl OllamaClient {
         Self::new(config)
     }
 
+    /// Create client configured from environment variables
+    ///
+    /// Honors `OLLAMA_HOST` like the `ollama` CLI, plus `OLLAMA_TIMEOUT`,
+    /// `OLLAMA_CONNECT_TIMEOUT`, `OLLAMA_MAX_RETRIES` and `OLLAMA_API_KEY`.
+    /// See [`ClientConfig::from_env`] for the accepted formats.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable is malformed or the client cannot
+    /// be built
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::OllamaClient;
+    ///
+    /// let client = OllamaClient::from_env()?;
+    /// # Ok::<(), ollama_oxide::Error>(())
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        Self::new(ClientConfig::from_env()?)
+    }
+
     /// Create client with default configuration (http://localhost:11434)
     ///
     /// # Errors
@@ -137,47 +261,55 @@ impl OllamaClient {
         Self::new(ClientConfig::default())
     }
 
-    /// Execute async HTTP GET request with retry logic
-    ///
-    /// This helper handles exponential backoff and automatic retries for:
-    /// - Network errors
-    /// - Server errors (5xx status codes)
+    /// Send an async request, retrying according to the retry policy
     ///
-    /// # Type Parameters
-    ///
-    /// * `T` - Response type that implements `DeserializeOwned`
+    /// This is the single retry loop behind every async helper. The
+    /// request is handed to the transport once per attempt. Whether a
+    /// failed attempt is retried, and how long to wait first, is decided
+    /// by [`RetryPolicy`](super::RetryPolicy).
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path, used to check if `POST` retries are allowed
+    /// * `request` - Request to send
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Maximum retry attempts exceeded
-    /// - Response cannot be deserialized
-    /// - Client errors (4xx) occur (no retry)
-    pub(super) async fn get_with_retry<T>(&self, url: &str) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned,
-    {
+    /// - The response status is not successful and cannot be retried
+    /// - Maximum retry attempts exceeded on network errors
+    /// - A network error occurs on a request that cannot be retried
+    async fn send_with_retry(
+        &self,
+        endpoint: &str,
+        request: TransportRequest,
+    ) -> Result<TransportResponse> {
+        let policy = &self.config.retry_policy;
+        let retry_allowed = policy.allows_retry(&request.method, endpoint);
+
         for attempt in 0..=self.config.max_retries {
-            match self.client.get(url).send().await {
+            let can_retry = attempt < self.config.max_retries;
+
+            match self.send_attempt(&request).await {
                 Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
+                    if response.is_success() {
+                        return Ok(response);
+                    }
+
+                    if can_retry && retry_allowed && policy.is_retryable_status(response.status) {
+                        let delay = policy.delay(attempt, retry_after(&response.headers));
+                        tokio::time::sleep(delay).await;
                         continue;
                     }
 
-                    // Deserialize and return
-                    let result = response.json::<T>().await?;
-                    return Ok(result);
+                    return Err(Self::error_from_response(response).await);
                 }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
+                Err(e) => {
+                    if !is_retryable_error(&e, retry_allowed) {
+                        return Err(e);
+                    }
+                    if can_retry {
+                        tokio::time::sleep(policy.delay(attempt, None)).await;
                     }
                 }
             }
@@ -186,52 +318,53 @@ impl OllamaClient {
         Err(Error::MaxRetriesExceededError(self.config.max_retries))
     }
 
-    /// Execute blocking HTTP GET request with retry logic
-    ///
-    /// This helper handles exponential backoff and automatic retries for:
-    /// - Network errors
-    /// - Server errors (5xx status codes)
+    /// Send a blocking request, retrying according to the retry policy
     ///
-    /// # Type Parameters
-    ///
-    /// * `T` - Response type that implements `DeserializeOwned`
+    /// Blocking counterpart of [`send_with_retry`](Self::send_with_retry)
+    /// with the same retry rules.
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path, used to check if `POST` retries are allowed
+    /// * `request` - Request to send
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Maximum retry attempts exceeded
-    /// - Response cannot be deserialized
-    /// - Client errors (4xx) occur (no retry)
-    pub(super) fn get_blocking_with_retry<T>(&self, url: &str) -> Result<T>
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        // Create blocking client
-        let blocking_client = reqwest::blocking::Client::builder()
-            .timeout(self.config.timeout)
-            .build()?;
+    /// - The response status is not successful and cannot be retried
+    /// - Maximum retry attempts exceeded on network errors
+    /// - A network error occurs on a request that cannot be retried
+    fn send_blocking_with_retry(
+        &self,
+        endpoint: &str,
+        request: TransportRequest,
+    ) -> Result<TransportResponse> {
+        let policy = &self.config.retry_policy;
+        let retry_allowed = policy.allows_retry(&request.method, endpoint);
 
         for attempt in 0..=self.config.max_retries {
-            match blocking_client.get(url).send() {
+            let can_retry = attempt < self.config.max_retries;
+
+            match self.send_attempt_blocking(&request) {
                 Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
+                    if response.is_success() {
+                        return Ok(response);
+                    }
+
+                    if can_retry && retry_allowed && policy.is_retryable_status(response.status) {
+                        let delay = policy.delay(attempt, retry_after(&response.headers));
+                        std::thread::sleep(delay);
                         continue;
                     }
 
-                    // Deserialize and return
-                    let result = response.json::<T>()?;
-                    return Ok(result);
+                    return Err(Self::error_from_blocking_response(response));
                 }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
+                Err(e) => {
+                    if !is_retryable_error(&e, retry_allowed) {
+                        return Err(e);
+                    }
+                    if can_retry {
+                        std::thread::sleep(policy.delay(attempt, None));
                     }
                 }
             }
@@ -240,6 +373,117 @@ impl OllamaClient {
         Err(Error::MaxRetriesExceededError(self.config.max_retries))
     }
 
+    /// Send one async attempt through the middlewares and the transport
+    ///
+    /// The middlewares work on their own copy of `request`, so changes
+    /// they make do not carry over to the next attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a middleware rejects the request, or if the
+    /// transport or reading a non-streaming body fails
+    async fn send_attempt(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        if self.middlewares.is_empty() {
+            return self.transport.send(request.clone()).await;
+        }
+
+        let started = Instant::now();
+        let mut request = request.clone();
+        let (entered, result) = match self.middlewares.before(&mut request) {
+            Before::Send => {
+                let result = match self.transport.send(request.clone()).await {
+                    Ok(response) if !request.streaming => response.buffered().await,
+                    result => result,
+                };
+                (self.middlewares.len(), result)
+            }
+            Before::Stop { entered, result } => (entered, result),
+        };
+        self.middlewares
+            .after(entered, &request, &result, started.elapsed());
+        result
+    }
+
+    /// Send one blocking attempt through the middlewares and the transport
+    ///
+    /// Blocking counterpart of [`send_attempt`](Self::send_attempt).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a middleware rejects the request, or if the
+    /// transport or reading a non-streaming body fails
+    fn send_attempt_blocking(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        if self.middlewares.is_empty() {
+            return self.transport.send_blocking(request.clone());
+        }
+
+        let started = Instant::now();
+        let mut request = request.clone();
+        let (entered, result) = match self.middlewares.before(&mut request) {
+            Before::Send => {
+                let result = match self.transport.send_blocking(request.clone()) {
+                    Ok(response) if !request.streaming => response.buffered_blocking(),
+                    result => result,
+                };
+                (self.middlewares.len(), result)
+            }
+            Before::Stop { entered, result } => (entered, result),
+        };
+        self.middlewares
+            .after(entered, &request, &result, started.elapsed());
+        result
+    }
+
+    /// Execute async HTTP GET request with retry logic
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - Response type that implements `DeserializeOwned`
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path to request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Maximum retry attempts exceeded
+    /// - Response cannot be deserialized
+    /// - Non-retryable error status returned
+    pub(super) async fn get_with_retry<T>(&self, endpoint: &str) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let request = TransportRequest::new(Method::GET, endpoint);
+        let response = self.send_with_retry(endpoint, request).await?;
+        Ok(serde_json::from_slice(&response.body.bytes().await?)?)
+    }
+
+    /// Execute blocking HTTP GET request with retry logic
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - Response type that implements `DeserializeOwned`
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path to request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Maximum retry attempts exceeded
+    /// - Response cannot be deserialized
+    /// - Non-retryable error status returned
+    pub(super) fn get_blocking_with_retry<T>(&self, endpoint: &str) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let request = TransportRequest::new(Method::GET, endpoint);
+        let response = self.send_blocking_with_retry(endpoint, request)?;
+        Ok(serde_json::from_slice(&response.body.bytes_blocking()?)?)
+    }
+
     /// Execute async HTTP POST request with retry logic (with JSON response)
     ///
     /// For endpoints that accept a request body and return a JSON response.
@@ -251,7 +495,7 @@ impl OllamaClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path to request
     /// * `body` - Request body to serialize as JSON
     ///
     /// # Errors
@@ -259,40 +503,79 @@ impl OllamaClient {
     /// Returns an error if:
     /// - Maximum retry attempts exceeded
     /// - Response cannot be deserialized
-    /// - Client errors (4xx) occur (no retry)
-    pub(super) async fn post_with_retry<R, T>(&self, url: &str, body: &R) -> Result<T>
+    /// - Non-retryable error status returned
+    pub(super) async fn post_with_retry<R, T>(&self, endpoint: &str, body: &R) -> Result<T>
     where
         R: serde::Serialize,
         T: serde::de::DeserializeOwned,
     {
-        for attempt in 0..=self.config.max_retries {
-            match self.client.post(url).json(body).send().await {
-                Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
-                        continue;
-                    }
-
-                    // Check for client errors (no retry)
-                    if response.status().is_client_error() {
-                        return Err(Error::HttpStatusError(response.status().as_u16()));
-                    }
+        let request = json_request(Method::POST, endpoint, body)?;
+        let response = self.send_with_retry(endpoint, request).await?;
+        Ok(serde_json::from_slice(&response.body.bytes().await?)?)
+    }
 
-                    // Deserialize and return
-                    let result = response.json::<T>().await?;
-                    return Ok(result);
-                }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
-                    }
-                }
-            }
-        }
+    /// Execute async HTTP POST request with retry logic (streaming response)
+    ///
+    /// For endpoints that stream newline-delimited JSON. Only establishing the
+    /// connection is retried; once a successful response is returned its body
+    /// is handed to the caller unread.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `R` - Request type that implements `Serialize`
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path to request
+    /// * `body` - Request body to serialize as JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Maximum retry attempts exceeded
+    /// - Non-retryable error status returned
+    pub(super) async fn post_stream_with_retry<R>(
+        &self,
+        endpoint: &str,
+        body: &R,
+    ) -> Result<TransportResponse>
+    where
+        R: serde::Serialize,
+    {
+        let request = json_request(Method::POST, endpoint, body)?.with_streaming(true);
+        self.send_with_retry(endpoint, request).await
+    }
 
-        Err(Error::MaxRetriesExceededError(self.config.max_retries))
+    /// Execute blocking HTTP POST request with retry logic (streaming response)
+    ///
+    /// For endpoints that stream newline-delimited JSON. Only establishing the
+    /// connection is retried; once a successful response is returned its body
+    /// is handed to the caller unread. The timeout applies to each read.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `R` - Request type that implements `Serialize`
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path to request
+    /// * `body` - Request body to serialize as JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Maximum retry attempts exceeded
+    /// - Non-retryable error status returned
+    pub(super) fn post_stream_blocking_with_retry<R>(
+        &self,
+        endpoint: &str,
+        body: &R,
+    ) -> Result<TransportResponse>
+    where
+        R: serde::Serialize,
+    {
+        let request = json_request(Method::POST, endpoint, body)?.with_streaming(true);
+        self.send_blocking_with_retry(endpoint, request)
     }
 
     /// Execute blocking HTTP POST request with retry logic (with JSON response)
@@ -306,7 +589,7 @@ impl OllamaClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path to request
     /// * `body` - Request body to serialize as JSON
     ///
     /// # Errors
@@ -314,44 +597,15 @@ impl OllamaClient {
     /// Returns an error if:
     /// - Maximum retry attempts exceeded
     /// - Response cannot be deserialized
-    /// - Client errors (4xx) occur (no retry)
-    pub(super) fn post_blocking_with_retry<R, T>(&self, url: &str, body: &R) -> Result<T>
+    /// - Non-retryable error status returned
+    pub(super) fn post_blocking_with_retry<R, T>(&self, endpoint: &str, body: &R) -> Result<T>
     where
         R: serde::Serialize,
         T: serde::de::DeserializeOwned,
     {
-        let blocking_client = reqwest::blocking::Client::builder()
-            .timeout(self.config.timeout)
-            .build()?;
-
-        for attempt in 0..=self.config.max_retries {
-            match blocking_client.post(url).json(body).send() {
-                Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
-                        continue;
-                    }
-
-                    // Check for client errors (no retry)
-                    if response.status().is_client_error() {
-                        return Err(Error::HttpStatusError(response.status().as_u16()));
-                    }
-
-                    // Deserialize and return
-                    let result = response.json::<T>()?;
-                    return Ok(result);
-                }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
-                    }
-                }
-            }
-        }
-
-        Err(Error::MaxRetriesExceededError(self.config.max_retries))
+        let request = json_request(Method::POST, endpoint, body)?;
+        let response = self.send_blocking_with_retry(endpoint, request)?;
+        Ok(serde_json::from_slice(&response.body.bytes_blocking()?)?)
     }
 
     /// Execute async HTTP POST request with retry logic (no response body)
@@ -364,46 +618,22 @@ impl OllamaClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path to request
     /// * `body` - Request body to serialize as JSON
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Maximum retry attempts exceeded
-    /// - Client errors (4xx) occur (no retry)
+    /// - Non-retryable error status returned
     #[cfg(feature = "model")]
-    pub(super) async fn post_empty_with_retry<R>(&self, url: &str, body: &R) -> Result<()>
+    pub(super) async fn post_empty_with_retry<R>(&self, endpoint: &str, body: &R) -> Result<()>
     where
         R: serde::Serialize,
     {
-        for attempt in 0..=self.config.max_retries {
-            match self.client.post(url).json(body).send().await {
-                Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
-                        continue;
-                    }
-
-                    // Check for success status
-                    if response.status().is_success() {
-                        return Ok(());
-                    }
-
-                    // Client error - no retry
-                    return Err(Error::HttpStatusError(response.status().as_u16()));
-                }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
-                    }
-                }
-            }
-        }
-
-        Err(Error::MaxRetriesExceededError(self.config.max_retries))
+        let request = json_request(Method::POST, endpoint, body)?;
+        self.send_with_retry(endpoint, request).await?;
+        Ok(())
     }
 
     /// Execute blocking HTTP POST request with retry logic (no response body)
@@ -416,50 +646,22 @@ impl OllamaClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path to request
     /// * `body` - Request body to serialize as JSON
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Maximum retry attempts exceeded
-    /// - Client errors (4xx) occur (no retry)
+    /// - Non-retryable error status returned
     #[cfg(feature = "model")]
-    pub(super) fn post_empty_blocking_with_retry<R>(&self, url: &str, body: &R) -> Result<()>
+    pub(super) fn post_empty_blocking_with_retry<R>(&self, endpoint: &str, body: &R) -> Result<()>
     where
         R: serde::Serialize,
     {
-        let blocking_client = reqwest::blocking::Client::builder()
-            .timeout(self.config.timeout)
-            .build()?;
-
-        for attempt in 0..=self.config.max_retries {
-            match blocking_client.post(url).json(body).send() {
-                Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
-                        continue;
-                    }
-
-                    // Check for success status
-                    if response.status().is_success() {
-                        return Ok(());
-                    }
-
-                    // Client error - no retry
-                    return Err(Error::HttpStatusError(response.status().as_u16()));
-                }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
-                    }
-                }
-            }
-        }
-
-        Err(Error::MaxRetriesExceededError(self.config.max_retries))
+        let request = json_request(Method::POST, endpoint, body)?;
+        self.send_blocking_with_retry(endpoint, request)?;
+        Ok(())
     }
 
     /// Execute async HTTP DELETE request with retry logic (no response body)
@@ -472,46 +674,22 @@ impl OllamaClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path to request
     /// * `body` - Request body to serialize as JSON
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Maximum retry attempts exceeded
-    /// - Client errors (4xx) occur (no retry)
+    /// - Non-retryable error status returned
     #[cfg(feature = "model")]
-    pub(super) async fn delete_empty_with_retry<R>(&self, url: &str, body: &R) -> Result<()>
+    pub(super) async fn delete_empty_with_retry<R>(&self, endpoint: &str, body: &R) -> Result<()>
     where
         R: serde::Serialize,
     {
-        for attempt in 0..=self.config.max_retries {
-            match self.client.delete(url).json(body).send().await {
-                Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
-                        continue;
-                    }
-
-                    // Check for success status
-                    if response.status().is_success() {
-                        return Ok(());
-                    }
-
-                    // Client error - no retry
-                    return Err(Error::HttpStatusError(response.status().as_u16()));
-                }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        tokio::time::sleep(Duration::from_millis(100 * (attempt as u64 + 1))).await;
-                    }
-                }
-            }
-        }
-
-        Err(Error::MaxRetriesExceededError(self.config.max_retries))
+        let request = json_request(Method::DELETE, endpoint, body)?;
+        self.send_with_retry(endpoint, request).await?;
+        Ok(())
     }
 
     /// Execute blocking HTTP DELETE request with retry logic (no response body)
@@ -524,49 +702,186 @@ impl OllamaClient {
     ///
     /// # Arguments
     ///
-    /// * `url` - Full URL to request
+    /// * `endpoint` - Endpoint path to request
     /// * `body` - Request body to serialize as JSON
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Maximum retry attempts exceeded
-    /// - Client errors (4xx) occur (no retry)
+    /// - Non-retryable error status returned
     #[cfg(feature = "model")]
-    pub(super) fn delete_empty_blocking_with_retry<R>(&self, url: &str, body: &R) -> Result<()>
+    pub(super) fn delete_empty_blocking_with_retry<R>(&self, endpoint: &str, body: &R) -> Result<()>
     where
         R: serde::Serialize,
     {
-        let blocking_client = reqwest::blocking::Client::builder()
-            .timeout(self.config.timeout)
-            .build()?;
+        let request = json_request(Method::DELETE, endpoint, body)?;
+        self.send_blocking_with_retry(endpoint, request)?;
+        Ok(())
+    }
 
-        for attempt in 0..=self.config.max_retries {
-            match blocking_client.delete(url).json(body).send() {
-                Ok(response) => {
-                    // Retry on server errors (5xx)
-                    if response.status().is_server_error() && attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
-                        continue;
-                    }
+    /// Check whether the server has a blob
+    ///
+    /// Sends `HEAD /api/blobs/:digest`; a 404 response means the blob is
+    /// missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Maximum retry attempts exceeded
+    /// - Non-retryable error status other than 404 returned
+    #[cfg(feature = "model")]
+    pub(super) async fn blob_exists_with_retry(&self, digest: &str) -> Result<bool> {
+        let request = TransportRequest::new(Method::HEAD, blob_endpoint(digest));
+        match self.send_with_retry(Endpoints::BLOBS, request).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.status_code() == Some(404) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
 
-                    // Check for success status
-                    if response.status().is_success() {
-                        return Ok(());
-                    }
+    /// Check whether the server has a blob (blocking)
+    ///
+    /// Blocking counterpart of [`blob_exists_with_retry`](Self::blob_exists_with_retry).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Maximum retry attempts exceeded
+    /// - Non-retryable error status other than 404 returned
+    #[cfg(feature = "model")]
+    pub(super) fn blob_exists_blocking_with_retry(&self, digest: &str) -> Result<bool> {
+        let request = TransportRequest::new(Method::HEAD, blob_endpoint(digest));
+        match self.send_blocking_with_retry(Endpoints::BLOBS, request) {
+            Ok(_) => Ok(true),
+            Err(e) if e.status_code() == Some(404) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
 
-                    // Client error - no retry
-                    return Err(Error::HttpStatusError(response.status().as_u16()));
-                }
-                Err(_e) => {
-                    // Retry on network errors
-                    if attempt < self.config.max_retries {
-                        std::thread::sleep(Duration::from_millis(100 * (attempt as u64 + 1)));
-                    }
-                }
-            }
+    /// Upload a file as a blob, streaming it from disk
+    ///
+    /// Sent as a streaming request, so the total request timeout does not
+    /// limit uploads of large files. Each attempt reopens the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file cannot be read
+    /// - Maximum retry attempts exceeded
+    /// - Non-retryable error status returned, e.g. on a digest mismatch
+    #[cfg(feature = "model")]
+    pub(super) async fn post_file_with_retry(&self, digest: &str, path: &Path) -> Result<()> {
+        let len = tokio::fs::metadata(path).await?.len();
+        let request = file_request(digest, path, len);
+        self.send_with_retry(Endpoints::BLOBS, request).await?;
+        Ok(())
+    }
+
+    /// Upload a file as a blob, streaming it from disk (blocking)
+    ///
+    /// Blocking counterpart of [`post_file_with_retry`](Self::post_file_with_retry).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file cannot be read
+    /// - Maximum retry attempts exceeded
+    /// - Non-retryable error status returned, e.g. on a digest mismatch
+    #[cfg(feature = "model")]
+    pub(super) fn post_file_blocking_with_retry(&self, digest: &str, path: &Path) -> Result<()> {
+        let len = std::fs::metadata(path)?.len();
+        let request = file_request(digest, path, len);
+        self.send_blocking_with_retry(Endpoints::BLOBS, request)?;
+        Ok(())
+    }
+
+    /// Upload a file as a blob unless the server already has it
+    ///
+    /// Returns the blob digest. The file is hashed on a blocking thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a request fails
+    #[cfg(feature = "model")]
+    pub(super) async fn upload_blob_if_missing(&self, path: &Path) -> Result<String> {
+        let digest = file_digest_async(path).await?;
+        if !self.blob_exists_with_retry(&digest).await? {
+            self.post_file_with_retry(&digest, path).await?;
         }
+        Ok(digest)
+    }
 
-        Err(Error::MaxRetriesExceededError(self.config.max_retries))
+    /// Upload a file as a blob unless the server already has it (blocking)
+    ///
+    /// Blocking counterpart of [`upload_blob_if_missing`](Self::upload_blob_if_missing).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a request fails
+    #[cfg(feature = "model")]
+    pub(super) fn upload_blob_if_missing_blocking(&self, path: &Path) -> Result<String> {
+        let digest = file_digest(path)?;
+        if !self.blob_exists_blocking_with_retry(&digest)? {
+            self.post_file_blocking_with_retry(&digest, path)?;
+        }
+        Ok(digest)
+    }
+
+    /// Convert a non-success async response into an error
+    ///
+    /// Reads the body so the `{"error": "..."}` message sent by Ollama is
+    /// kept; see [`Error::from_response_body`].
+    async fn error_from_response(response: TransportResponse) -> Error {
+        let body = response.body.bytes().await.unwrap_or_default();
+        Error::from_response_body(response.status, &String::from_utf8_lossy(&body))
+    }
+
+    /// Convert a non-success blocking response into an error
+    ///
+    /// Reads the body so the `{"error": "..."}` message sent by Ollama is
+    /// kept; see [`Error::from_response_body`].
+    fn error_from_blocking_response(response: TransportResponse) -> Error {
+        let body = response.body.bytes_blocking().unwrap_or_default();
+        Error::from_response_body(response.status, &String::from_utf8_lossy(&body))
+    }
+}
+
+/// Build a request with a JSON body
+///
+/// # Errors
+///
+/// Returns an error if `body` cannot be serialized
+fn json_request<R>(method: Method, endpoint: &str, body: &R) -> Result<TransportRequest>
+where
+    R: serde::Serialize,
+{
+    // Going through text keeps `f32` options such as a temperature of 0.7
+    // exact; `serde_json::to_value` would widen them to 0.699999988...
+    let body: serde_json::Value = serde_json::from_str(&serde_json::to_string(body)?)?;
+    Ok(TransportRequest::new(method, endpoint).with_body(RequestBody::Json(body)))
+}
+
+/// Build a blob upload streaming a file from disk
+#[cfg(feature = "model")]
+fn file_request(digest: &str, path: &Path, len: u64) -> TransportRequest {
+    TransportRequest::new(Method::POST, blob_endpoint(digest))
+        .with_body(RequestBody::File {
+            path: path.to_path_buf(),
+            len,
+        })
+        .with_streaming(true)
+}
+
+/// Check whether a failed attempt may be retried
+///
+/// Connection failures never reached the server, so they are safe to
+/// retry for any endpoint. Other network errors are only retried where
+/// the retry policy allows it.
+fn is_retryable_error(error: &Error, retry_allowed: bool) -> bool {
+    match error {
+        Error::ConnectionError(_) => true,
+        Error::HttpError(_) | Error::TimeoutError(_) => retry_allowed,
+        _ => false,
     }
 }
@@ -0,0 +1,104 @@
+//! Client certificate for mutual TLS
+
+use std::fmt;
+
+use crate::{Error, Result};
+
+/// Client certificate and private key presented for mutual TLS
+///
+/// Set it with [`ClientConfig::with_identity`](super::ClientConfig::with_identity)
+/// when the server or a reverse proxy in front of it requires clients to
+/// authenticate with a certificate.
+///
+/// The `Debug` output never contains the private key or password.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{ClientConfig, ClientIdentity};
+///
+/// let identity = ClientIdentity::pem(
+///     std::fs::read("client.pem")?,
+///     std::fs::read("client.key")?,
+/// );
+/// let config = ClientConfig::default()
+///     .with_base_url("https://ollama.internal:11434")
+///     .with_root_certificate(std::fs::read("ca.pem")?)
+///     .with_identity(identity);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone)]
+pub enum ClientIdentity {
+    /// PEM encoded certificate chain and PKCS#8 PEM encoded private key
+    Pem {
+        /// Certificate chain, leaf certificate first
+        certificate: Vec<u8>,
+        /// Unencrypted PKCS#8 private key (`BEGIN PRIVATE KEY`)
+        key: Vec<u8>,
+    },
+
+    /// DER encoded PKCS#12 archive holding both certificate and key
+    Pkcs12 {
+        /// PKCS#12 archive (`.p12` / `.pfx`)
+        der: Vec<u8>,
+        /// Password protecting the archive
+        password: String,
+    },
+}
+
+impl ClientIdentity {
+    /// Create an identity from a PEM certificate chain and PKCS#8 PEM key
+    ///
+    /// # Arguments
+    ///
+    /// * `certificate` - PEM encoded certificate chain
+    /// * `key` - PEM encoded, unencrypted PKCS#8 private key
+    pub fn pem(certificate: impl Into<Vec<u8>>, key: impl Into<Vec<u8>>) -> Self {
+        Self::Pem {
+            certificate: certificate.into(),
+            key: key.into(),
+        }
+    }
+
+    /// Create an identity from a DER encoded PKCS#12 archive
+    ///
+    /// # Arguments
+    ///
+    /// * `der` - PKCS#12 archive contents
+    /// * `password` - Password protecting the archive
+    pub fn pkcs12(der: impl Into<Vec<u8>>, password: impl Into<String>) -> Self {
+        Self::Pkcs12 {
+            der: der.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Parse into the identity type used by the HTTP client
+    pub(super) fn to_reqwest(&self) -> Result<reqwest::Identity> {
+        match self {
+            Self::Pem { certificate, key } => reqwest::Identity::from_pkcs8_pem(certificate, key),
+            Self::Pkcs12 { der, password } => reqwest::Identity::from_pkcs12_der(der, password),
+        }
+        .map_err(|e| Error::ConfigError(format!("invalid client identity: {}", e)))
+    }
+}
+
+impl fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pem { certificate, .. } => f
+                .debug_struct("Pem")
+                .field(
+                    "certificate",
+                    &format_args!("<{} bytes>", certificate.len()),
+                )
+                .field("key", &"<redacted>")
+                .finish(),
+            Self::Pkcs12 { der, .. } => f
+                .debug_struct("Pkcs12")
+                .field("der", &format_args!("<{} bytes>", der.len()))
+                .field("password", &"<redacted>")
+                .finish(),
+        }
+    }
+}
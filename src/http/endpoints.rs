@@ -43,6 +43,9 @@ impl Endpoints {
 
     /// DELETE /api/delete - Delete a model
     pub const DELETE: &'static str = "/api/delete";
+
+    /// HEAD/POST /api/blobs/:digest - Check or upload a blob
+    pub const BLOBS: &'static str = "/api/blobs";
 }
 
 #[cfg(test)]
@@ -63,6 +66,7 @@ mod tests {
         assert!(Endpoints::PULL.starts_with("/api/"));
         assert!(Endpoints::PUSH.starts_with("/api/"));
         assert!(Endpoints::DELETE.starts_with("/api/"));
+        assert!(Endpoints::BLOBS.starts_with("/api/"));
     }
 
     #[test]
@@ -79,5 +83,6 @@ mod tests {
         assert_eq!(Endpoints::PULL, "/api/pull");
         assert_eq!(Endpoints::PUSH, "/api/push");
         assert_eq!(Endpoints::DELETE, "/api/delete");
+        assert_eq!(Endpoints::BLOBS, "/api/blobs");
     }
 }
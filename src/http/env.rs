@@ -0,0 +1,283 @@
+//! Client configuration from environment variables
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use super::{Auth, ClientConfig};
+use crate::{Error, Result};
+
+/// Ollama server address, as used by the `ollama` CLI
+const OLLAMA_HOST: &str = "OLLAMA_HOST";
+
+/// Request timeout, e.g. `30`, `1.5`, `500ms`, `2m`
+const OLLAMA_TIMEOUT: &str = "OLLAMA_TIMEOUT";
+
+/// Connect timeout, same format as `OLLAMA_TIMEOUT`
+const OLLAMA_CONNECT_TIMEOUT: &str = "OLLAMA_CONNECT_TIMEOUT";
+
+/// Maximum retry attempts
+const OLLAMA_MAX_RETRIES: &str = "OLLAMA_MAX_RETRIES";
+
+/// Bearer token sent in the `Authorization` header
+const OLLAMA_API_KEY: &str = "OLLAMA_API_KEY";
+
+/// Build a configuration from variables returned by `lookup`
+///
+/// Unset and empty variables keep the defaults of [`ClientConfig`].
+pub(super) fn config_from_lookup<F>(lookup: F) -> Result<ClientConfig>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    // Like the CLI, ignore surrounding whitespace and quotes
+    let var = |name: &str| {
+        lookup(name)
+            .map(|value| value.trim().trim_matches(['"', '\'']).to_string())
+            .filter(|value| !value.is_empty())
+    };
+
+    let mut config = ClientConfig::default();
+
+    if let Some(value) = var(OLLAMA_HOST) {
+        config.base_url = parse_host(&value)?;
+    }
+    if let Some(value) = var(OLLAMA_TIMEOUT) {
+        config.timeout = parse_duration(OLLAMA_TIMEOUT, &value)?;
+    }
+    if let Some(value) = var(OLLAMA_CONNECT_TIMEOUT) {
+        config.connect_timeout = Some(parse_duration(OLLAMA_CONNECT_TIMEOUT, &value)?);
+    }
+    if let Some(value) = var(OLLAMA_MAX_RETRIES) {
+        config.max_retries = value.parse().map_err(|_| {
+            Error::ConfigError(format!(
+                "{}: expected a non-negative integer, got {:?}",
+                OLLAMA_MAX_RETRIES, value
+            ))
+        })?;
+    }
+    if let Some(value) = var(OLLAMA_API_KEY) {
+        config.auth = Some(Auth::bearer(value));
+    }
+
+    Ok(config)
+}
+
+/// Turn an `OLLAMA_HOST` value into a base URL
+///
+/// Follows the `ollama` CLI:
+/// - Without a scheme, `http` and port 11434 are assumed
+/// - With `http://` or `https://` but no port, 80 or 443 is used
+/// - A bare `ollama.com` means `https://ollama.com:443`
+/// - A path after the host is kept, for servers behind a path prefix
+///
+/// Unlike the CLI, which connects to whatever address it is given, the
+/// unspecified addresses `0.0.0.0` and `::` are replaced by loopback:
+/// they are what the server listens on, not an address to connect to.
+/// Malformed ports are reported as errors instead of being replaced.
+fn parse_host(value: &str) -> Result<String> {
+    let invalid =
+        |reason: String| Error::ConfigError(format!("{}: {} in {:?}", OLLAMA_HOST, reason, value));
+
+    let (scheme, rest, default_port) = match value.split_once("://") {
+        None if value == "ollama.com" => ("https", "ollama.com:443", "443"),
+        None => ("http", value, "11434"),
+        Some(("http", rest)) => ("http", rest, "80"),
+        Some(("https", rest)) => ("https", rest, "443"),
+        Some((scheme, _)) => {
+            return Err(invalid(format!(
+                "unsupported scheme {:?}, expected http or https",
+                scheme
+            )));
+        }
+    };
+
+    let (hostport, path) = match rest.split_once('/') {
+        Some((hostport, path)) => (hostport, path.trim_end_matches('/')),
+        None => (rest, ""),
+    };
+
+    let (host, port) = match split_host_port(hostport) {
+        Some((host, port)) => (host, port),
+        None => (hostport.trim_start_matches('[').trim_end_matches(']'), ""),
+    };
+    let host = if host.is_empty() { "127.0.0.1" } else { host };
+
+    let port = if port.is_empty() { default_port } else { port };
+    if port.parse::<u16>().is_err() {
+        return Err(invalid(format!("invalid port {:?}", port)));
+    }
+
+    let host = match host.parse::<IpAddr>() {
+        Ok(ip) if ip.is_unspecified() && ip.is_ipv4() => "127.0.0.1".to_string(),
+        Ok(ip) if ip.is_unspecified() => "[::1]".to_string(),
+        Ok(IpAddr::V6(ip)) => format!("[{}]", ip),
+        Ok(IpAddr::V4(ip)) => ip.to_string(),
+        Err(_) => host.to_string(),
+    };
+
+    let base_url = if path.is_empty() {
+        format!("{}://{}:{}", scheme, host, port)
+    } else {
+        format!("{}://{}:{}/{}", scheme, host, port, path)
+    };
+
+    url::Url::parse(&base_url).map_err(|e| invalid(e.to_string()))?;
+    Ok(base_url)
+}
+
+/// Split `host:port` or `[ipv6]:port`
+///
+/// Returns None when there is no port separator, like Go's
+/// `net.SplitHostPort` failing, so the caller can apply a default port.
+fn split_host_port(hostport: &str) -> Option<(&str, &str)> {
+    if let Some(rest) = hostport.strip_prefix('[') {
+        let (host, after) = rest.split_once(']')?;
+        return Some((host, after.strip_prefix(':')?));
+    }
+
+    let (host, port) = hostport.rsplit_once(':')?;
+    // A bare IPv6 address has several colons and no port
+    if host.contains(':') {
+        return None;
+    }
+    Some((host, port))
+}
+
+/// Parse a duration given in seconds or with a unit suffix
+///
+/// Accepts `30`, `1.5`, `500ms`, `30s`, `5m` and `1h`.
+fn parse_duration(name: &str, value: &str) -> Result<Duration> {
+    let invalid = || {
+        Error::ConfigError(format!(
+            "{}: invalid duration {:?}, expected seconds or a value like 500ms, 30s, 5m",
+            name, value
+        ))
+    };
+
+    let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else if let Some(number) = value.strip_suffix('h') {
+        (number, 3600.0)
+    } else {
+        (value, 1.0)
+    };
+
+    let number: f64 = number.trim().parse().map_err(|_| invalid())?;
+    Duration::try_from_secs_f64(number * scale).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn config_from(vars: &[(&str, &str)]) -> Result<ClientConfig> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        config_from_lookup(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_parse_host_like_cli() {
+        let cases = [
+            ("localhost", "http://localhost:11434"),
+            ("example.com:8080", "http://example.com:8080"),
+            ("10.0.0.5", "http://10.0.0.5:11434"),
+            ("0.0.0.0", "http://127.0.0.1:11434"),
+            ("0.0.0.0:11435", "http://127.0.0.1:11435"),
+            (":11434", "http://127.0.0.1:11434"),
+            ("http://example.com", "http://example.com:80"),
+            ("https://example.com", "https://example.com:443"),
+            ("https://example.com:8443", "https://example.com:8443"),
+            ("ollama.com", "https://ollama.com:443"),
+            ("example.com:8080/ollama/", "http://example.com:8080/ollama"),
+            ("[::1]:11434", "http://[::1]:11434"),
+            ("::1", "http://[::1]:11434"),
+            ("[::]", "http://[::1]:11434"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(parse_host(input).unwrap(), expected, "input {:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_host_rejects_malformed_values() {
+        for input in ["localhost:abc", "localhost:70000", "ftp://example.com"] {
+            let err = parse_host(input).unwrap_err();
+            assert!(matches!(err, Error::ConfigError(_)), "input {:?}", input);
+            assert!(err.to_string().contains(OLLAMA_HOST));
+            assert!(err.to_string().contains(input));
+        }
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let parse = |value| parse_duration(OLLAMA_TIMEOUT, value).unwrap();
+
+        assert_eq!(parse("30"), Duration::from_secs(30));
+        assert_eq!(parse("1.5"), Duration::from_millis(1500));
+        assert_eq!(parse("500ms"), Duration::from_millis(500));
+        assert_eq!(parse("45s"), Duration::from_secs(45));
+        assert_eq!(parse("2m"), Duration::from_secs(120));
+        assert_eq!(parse("1h"), Duration::from_secs(3600));
+
+        for value in ["abc", "-5", "10x", "ms"] {
+            assert!(matches!(
+                parse_duration(OLLAMA_TIMEOUT, value),
+                Err(Error::ConfigError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_config_from_empty_environment() {
+        let config = config_from(&[]).unwrap();
+        let default = ClientConfig::default();
+
+        assert_eq!(config.base_url, default.base_url);
+        assert_eq!(config.timeout, default.timeout);
+        assert_eq!(config.max_retries, default.max_retries);
+        assert!(config.auth.is_none());
+    }
+
+    #[test]
+    fn test_config_from_all_variables() {
+        let config = config_from(&[
+            (OLLAMA_HOST, "\"gpu-box:11500\""),
+            (OLLAMA_TIMEOUT, "2m"),
+            (OLLAMA_CONNECT_TIMEOUT, "5"),
+            (OLLAMA_MAX_RETRIES, " 7 "),
+            (OLLAMA_API_KEY, "secret"),
+        ])
+        .unwrap();
+
+        assert_eq!(config.base_url, "http://gpu-box:11500");
+        assert_eq!(config.timeout, Duration::from_secs(120));
+        assert_eq!(config.connect_timeout, Some(Duration::from_secs(5)));
+        assert_eq!(config.max_retries, 7);
+        assert!(matches!(config.auth, Some(Auth::Bearer(token)) if token == "secret"));
+    }
+
+    #[test]
+    fn test_config_ignores_empty_variables() {
+        let config = config_from(&[(OLLAMA_HOST, "  "), (OLLAMA_API_KEY, "")]).unwrap();
+
+        assert_eq!(config.base_url, ClientConfig::default().base_url);
+        assert!(config.auth.is_none());
+    }
+
+    #[test]
+    fn test_config_reports_variable_name() {
+        let err = config_from(&[(OLLAMA_MAX_RETRIES, "many")]).unwrap_err();
+        assert!(err.to_string().contains(OLLAMA_MAX_RETRIES));
+
+        let err = config_from(&[(OLLAMA_TIMEOUT, "soon")]).unwrap_err();
+        assert!(err.to_string().contains(OLLAMA_TIMEOUT));
+    }
+}
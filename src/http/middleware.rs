@@ -0,0 +1,163 @@
+//! Hooks that run around every request sent by the client
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::transport::{TransportRequest, TransportResponse};
+use crate::{Error, Result};
+
+/// Hooks run by [`OllamaClient`](super::OllamaClient) around each request
+///
+/// Register middlewares with [`OllamaClient::with_middleware`](super::OllamaClient::with_middleware).
+/// They run for the async and the blocking API alike, once per attempt,
+/// so a request retried twice passes through them three times.
+///
+/// [`before_request`](Self::before_request) hooks run in registration
+/// order and may change the request, for example to add a trace header.
+/// Once the attempt is over, [`after_response`](Self::after_response) or
+/// [`on_error`](Self::on_error) run in reverse order, so the first
+/// middleware registered sees the request first and the outcome last.
+///
+/// Hooks see the endpoint path, the JSON body (typed through
+/// [`TransportRequest::body_json`]), the status and the time spent. The
+/// body of a non-streaming response is read before the after hooks run,
+/// so [`TransportResponse::body_json`] works there; streaming responses
+/// are handed over unread.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{
+///     ChatRequest, Error, Middleware, OllamaClient, Result, TransportRequest, TransportResponse,
+/// };
+///
+/// /// Rejects chat requests for models outside a fixed list
+/// #[derive(Debug)]
+/// struct ModelAllowlist(Vec<String>);
+///
+/// impl Middleware for ModelAllowlist {
+///     fn before_request(&self, request: &mut TransportRequest) -> Result<Option<TransportResponse>> {
+///         if let Some(chat) = request.body_json::<ChatRequest>().transpose()? {
+///             if !self.0.contains(&chat.model) {
+///                 let message = format!("model '{}' is not allowed", chat.model);
+///                 return Err(Error::ValidationError(message));
+///             }
+///         }
+///         Ok(None)
+///     }
+/// }
+///
+/// let client = OllamaClient::default()?
+///     .with_middleware(ModelAllowlist(vec!["qwen3:0.6b".to_string()]));
+/// # Ok::<(), ollama_oxide::Error>(())
+/// ```
+pub trait Middleware: Send + Sync + fmt::Debug {
+    /// Inspect or change a request before it is sent
+    ///
+    /// Return `Ok(Some(response))` to answer the request without sending
+    /// it, for example from a cache; the response is then handled like
+    /// one from the server and later middlewares are skipped. Return an
+    /// error to fail the attempt without sending it.
+    ///
+    /// # Errors
+    ///
+    /// The error is returned to the caller, and retried only if a
+    /// transport error of the same kind would be
+    fn before_request(&self, request: &mut TransportRequest) -> Result<Option<TransportResponse>> {
+        let _ = request;
+        Ok(None)
+    }
+
+    /// Observe a response, including error statuses
+    ///
+    /// `elapsed` runs from the start of the attempt until the response
+    /// was received; for non-streaming requests that includes reading the
+    /// body.
+    fn after_response(
+        &self,
+        request: &TransportRequest,
+        response: &TransportResponse,
+        elapsed: Duration,
+    ) {
+        let _ = (request, response, elapsed);
+    }
+
+    /// Observe an attempt that failed without a response
+    fn on_error(&self, request: &TransportRequest, error: &Error, elapsed: Duration) {
+        let _ = (request, error, elapsed);
+    }
+}
+
+/// Middlewares registered on a client, in registration order
+#[derive(Clone, Debug, Default)]
+pub(super) struct MiddlewareChain(Vec<Arc<dyn Middleware>>);
+
+/// Outcome of the before-request hooks
+pub(super) enum Before {
+    /// Every hook let the request through
+    Send,
+
+    /// A hook answered or rejected the request
+    Stop {
+        /// Number of middlewares whose hooks ran before the one that stopped
+        entered: usize,
+        /// Response or error to use instead of sending the request
+        result: Result<TransportResponse>,
+    },
+}
+
+impl MiddlewareChain {
+    /// Append a middleware to the chain
+    pub(super) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    /// Check whether no middleware is registered
+    pub(super) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of registered middlewares
+    pub(super) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Run the before-request hooks in registration order
+    pub(super) fn before(&self, request: &mut TransportRequest) -> Before {
+        for (entered, middleware) in self.0.iter().enumerate() {
+            match middleware.before_request(request) {
+                Ok(None) => {}
+                Ok(Some(response)) => {
+                    return Before::Stop {
+                        entered,
+                        result: Ok(response),
+                    };
+                }
+                Err(e) => {
+                    return Before::Stop {
+                        entered,
+                        result: Err(e),
+                    };
+                }
+            }
+        }
+        Before::Send
+    }
+
+    /// Run the after hooks of the first `entered` middlewares, last first
+    pub(super) fn after(
+        &self,
+        entered: usize,
+        request: &TransportRequest,
+        result: &Result<TransportResponse>,
+        elapsed: Duration,
+    ) {
+        for middleware in self.0[..entered].iter().rev() {
+            match result {
+                Ok(response) => middleware.after_response(request, response, elapsed),
+                Err(e) => middleware.on_error(request, e, elapsed),
+            }
+        }
+    }
+}
@@ -6,9 +6,16 @@
 //! # Components
 //!
 //! - [`ClientConfig`] - Configuration for the HTTP client
+//! - [`ClientIdentity`] - Client certificate for mutual TLS
+//! - [`RetryPolicy`] - Backoff and retry rules for failed requests
+//! - [`Auth`] - Bearer, basic or provider-based request authentication
 //! - [`OllamaClient`] - The main HTTP client
 //! - [`OllamaApiAsync`] - Async API trait
 //! - [`OllamaApiSync`] - Sync (blocking) API trait
+//! - [`ResponseStream`] - Async stream of chunks from streaming endpoints
+//! - [`ResponseIter`] - Blocking iterator of chunks from streaming endpoints
+//! - [`Transport`] - Carries requests to the server; [`ReqwestTransport`] by default
+//! - [`Middleware`] - Hooks run by the client around every request
 //!
 //! # Examples
 //!
@@ -41,11 +48,33 @@
 
 mod api_async;
 mod api_sync;
+mod auth;
+#[cfg(feature = "model")]
+mod blob;
 mod client;
+mod client_identity;
 mod config;
 pub(crate) mod endpoints;
+mod env;
+mod middleware;
+mod ndjson_decoder;
+mod reqwest_transport;
+mod response_iter;
+mod response_stream;
+mod retry_policy;
+#[cfg(feature = "tools")]
+mod structured;
+mod transport;
 
 pub use api_async::OllamaApiAsync;
 pub use api_sync::OllamaApiSync;
+pub use auth::{Auth, CredentialProvider};
 pub use client::OllamaClient;
+pub use client_identity::ClientIdentity;
 pub use config::ClientConfig;
+pub use middleware::Middleware;
+pub use reqwest_transport::ReqwestTransport;
+pub use response_iter::ResponseIter;
+pub use response_stream::ResponseStream;
+pub use retry_policy::RetryPolicy;
+pub use transport::{RequestBody, ResponseBody, Transport, TransportRequest, TransportResponse};
@@ -0,0 +1,164 @@
+//! Newline-delimited JSON decoder for streaming responses
+//!
+//! Ollama streams responses as one JSON object per line. Network reads do
+//! not respect line boundaries, so bytes are buffered until a full line is
+//! available before being deserialized.
+
+use crate::{Error, Result};
+
+/// Incremental decoder for newline-delimited JSON (NDJSON) bodies
+///
+/// Bytes are appended with [`push`](NdjsonDecoder::push) as they arrive and
+/// complete objects are pulled out with [`next`](NdjsonDecoder::next).
+/// Blank lines are skipped, and an `{"error": "..."}` object sent by the
+/// server mid-stream is returned as [`Error::ApiError`].
+#[derive(Debug)]
+pub(super) struct NdjsonDecoder {
+    buffer: Vec<u8>,
+    /// Status code of the response, reported with mid-stream errors
+    status: u16,
+}
+
+impl NdjsonDecoder {
+    /// Create an empty decoder for a response with the given status code
+    pub(super) fn new(status: u16) -> Self {
+        Self {
+            buffer: Vec::new(),
+            status,
+        }
+    }
+
+    /// Append raw bytes received from the network
+    pub(super) fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Decode the next complete line, if one is buffered
+    ///
+    /// Returns `None` when no full line is available yet.
+    pub(super) fn next<T>(&mut self) -> Option<Result<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        loop {
+            let pos = self.buffer.iter().position(|b| *b == b'\n')?;
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            if let Some(item) = self.decode_line(&line) {
+                return Some(item);
+            }
+        }
+    }
+
+    /// Decode whatever remains in the buffer once the body has ended
+    ///
+    /// The final object of a stream is not always followed by a newline.
+    pub(super) fn finish<T>(&mut self) -> Option<Result<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let line = std::mem::take(&mut self.buffer);
+        self.decode_line(&line)
+    }
+
+    fn decode_line<T>(&self, line: &[u8]) -> Option<Result<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let line = line.trim_ascii();
+        if line.is_empty() {
+            return None;
+        }
+        Some(self.decode_value(line))
+    }
+
+    fn decode_value<T>(&self, line: &[u8]) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let value: serde_json::Value = serde_json::from_slice(line)?;
+        if let Some(message) = value.get("error").and_then(|e| e.as_str()) {
+            return Err(Error::api(self.status, message));
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Chunk {
+        n: i32,
+    }
+
+    #[test]
+    fn test_decodes_complete_lines() {
+        let mut decoder = NdjsonDecoder::new(200);
+        decoder.push(b"{\"n\":1}\n{\"n\":2}\n");
+
+        assert_eq!(decoder.next::<Chunk>().unwrap().unwrap(), Chunk { n: 1 });
+        assert_eq!(decoder.next::<Chunk>().unwrap().unwrap(), Chunk { n: 2 });
+        assert!(decoder.next::<Chunk>().is_none());
+    }
+
+    #[test]
+    fn test_buffers_partial_lines() {
+        let mut decoder = NdjsonDecoder::new(200);
+        decoder.push(b"{\"n\":");
+        assert!(decoder.next::<Chunk>().is_none());
+
+        decoder.push(b"42}\n{\"n\"");
+        assert_eq!(decoder.next::<Chunk>().unwrap().unwrap(), Chunk { n: 42 });
+        assert!(decoder.next::<Chunk>().is_none());
+
+        decoder.push(b":7}\n");
+        assert_eq!(decoder.next::<Chunk>().unwrap().unwrap(), Chunk { n: 7 });
+    }
+
+    #[test]
+    fn test_skips_blank_lines() {
+        let mut decoder = NdjsonDecoder::new(200);
+        decoder.push(b"\n\r\n{\"n\":1}\r\n\n");
+
+        assert_eq!(decoder.next::<Chunk>().unwrap().unwrap(), Chunk { n: 1 });
+        assert!(decoder.next::<Chunk>().is_none());
+    }
+
+    #[test]
+    fn test_finish_decodes_trailing_line() {
+        let mut decoder = NdjsonDecoder::new(200);
+        decoder.push(b"{\"n\":3}");
+
+        assert!(decoder.next::<Chunk>().is_none());
+        assert_eq!(decoder.finish::<Chunk>().unwrap().unwrap(), Chunk { n: 3 });
+        assert!(decoder.finish::<Chunk>().is_none());
+    }
+
+    #[test]
+    fn test_invalid_line_returns_error() {
+        let mut decoder = NdjsonDecoder::new(200);
+        decoder.push(b"not json\n");
+
+        let result = decoder.next::<Chunk>().unwrap();
+        assert!(matches!(result, Err(Error::SerializationError(_))));
+    }
+
+    #[test]
+    fn test_error_object_returns_api_error() {
+        let mut decoder = NdjsonDecoder::new(200);
+        decoder.push(b"{\"n\":1}\n{\"error\":\"out of memory\"}\n");
+
+        assert_eq!(decoder.next::<Chunk>().unwrap().unwrap(), Chunk { n: 1 });
+        match decoder.next::<Chunk>().unwrap() {
+            Err(Error::ApiError {
+                status, message, ..
+            }) => {
+                assert_eq!(status, 200);
+                assert_eq!(message, "out of memory");
+            }
+            other => panic!("expected ApiError, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,357 @@
+//! Default transport sending requests over HTTP with `reqwest`
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use reqwest::Client;
+use reqwest::header::{AUTHORIZATION, CONTENT_LENGTH};
+use tokio::io::AsyncReadExt;
+use url::Url;
+
+use super::auth::bearer_header;
+use super::transport::{RequestBody, ResponseBody, Transport, TransportRequest, TransportResponse};
+use super::{Auth, ClientConfig};
+use crate::{Error, Result};
+
+/// Size of each chunk read from a file while uploading
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Request URL base for a Unix socket, which replaces the host
+///
+/// Ollama accepts `localhost` in the `Host` header.
+const UNIX_SOCKET_BASE_URL: &str = "http://localhost";
+
+/// Transport sending requests to the configured base URL with `reqwest`
+///
+/// Applies the HTTP settings of a [`ClientConfig`]: base URL, timeouts,
+/// headers, credentials, proxies, TLS and connection pooling. A
+/// `unix:///path/to/ollama.sock` base URL sends every request over that
+/// Unix domain socket.
+/// [`OllamaClient::new`](super::OllamaClient::new) uses it, and a custom
+/// [`Transport`] can wrap it to add behavior around real HTTP requests.
+///
+/// The blocking HTTP client is built on the first blocking request and
+/// reused afterwards, so creating a transport inside an async runtime
+/// costs nothing for callers that only use the async API.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{ClientConfig, OllamaClient, ReqwestTransport};
+///
+/// let config = ClientConfig::default();
+/// let transport = ReqwestTransport::new(&config)?;
+/// let client = OllamaClient::with_transport(config, transport)?;
+/// # Ok::<(), ollama_oxide::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ReqwestTransport {
+    /// URL that endpoint paths are appended to
+    base_url: String,
+    config: ClientConfig,
+    client: Client,
+    /// Client for streaming requests, where `timeout` bounds each read
+    /// instead of the whole response
+    stream_client: Client,
+    /// Blocking client, built on first use
+    blocking_client: OnceLock<reqwest::blocking::Client>,
+}
+
+impl ReqwestTransport {
+    /// Create a transport for a client configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The base URL is invalid or malformed
+    /// - The URL scheme is not http, https or (on Unix) unix
+    /// - The configured credentials cannot be sent in a header
+    /// - A proxy URL, root certificate or client identity is invalid
+    /// - The HTTP client cannot be built
+    pub fn new(config: &ClientConfig) -> Result<Self> {
+        let base_url = match config.unix_socket()? {
+            Some(_) if cfg!(unix) => UNIX_SOCKET_BASE_URL.to_string(),
+            Some(_) => {
+                return Err(Error::ConfigError(
+                    "unix socket base URLs are only supported on Unix".to_string(),
+                ));
+            }
+            None => {
+                // Validate base URL
+                let url = Url::parse(&config.base_url)?;
+
+                // Ensure URL has a scheme (http or https)
+                if url.scheme() != "http" && url.scheme() != "https" {
+                    return Err(Error::InvalidUrlError(
+                        url::ParseError::RelativeUrlWithoutBase,
+                    ));
+                }
+                config.base_url.clone()
+            }
+        };
+
+        if let Some(auth) = &config.auth {
+            auth.validate()?;
+        }
+
+        let mut client = config.async_builder()?.timeout(config.timeout);
+        if let Some(timeout) = config.read_timeout {
+            client = client.read_timeout(timeout);
+        }
+        let client = client.build()?;
+
+        let stream_client = config
+            .async_builder()?
+            .connect_timeout(config.connect_timeout.unwrap_or(config.timeout))
+            .read_timeout(config.read_timeout.unwrap_or(config.timeout))
+            .build()?;
+
+        Ok(Self {
+            base_url,
+            config: config.clone(),
+            client,
+            stream_client,
+            blocking_client: OnceLock::new(),
+        })
+    }
+
+    /// Build the request URL for an endpoint path
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Get the blocking HTTP client, building it on first use
+    ///
+    /// Building the client starts `reqwest`'s background runtime thread,
+    /// so it is done once and the client, with its connection pool, is
+    /// reused by every later blocking request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built
+    fn blocking_client(&self) -> Result<&reqwest::blocking::Client> {
+        if let Some(client) = self.blocking_client.get() {
+            return Ok(client);
+        }
+
+        // Blocking timeouts already apply to each read
+        let client = self
+            .config
+            .blocking_builder()?
+            .timeout(self.config.read_timeout.unwrap_or(self.config.timeout))
+            .build()?;
+
+        // If another thread built one first, keep theirs and drop ours
+        Ok(self.blocking_client.get_or_init(|| client))
+    }
+
+    /// Add the configured credentials to an async request
+    ///
+    /// A credential provider is asked for a token on every call, so each
+    /// retry attempt uses a fresh token. It runs on Tokio's blocking thread
+    /// pool, since it may do I/O to get the token.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the credential provider fails or returns a
+    /// token that cannot be sent in a header
+    async fn authorize(&self, request: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder> {
+        Ok(match &self.config.auth {
+            None => request,
+            Some(Auth::Bearer(token)) => request.header(AUTHORIZATION, bearer_header(token)?),
+            Some(Auth::Basic { username, password }) => {
+                request.basic_auth(username, password.as_deref())
+            }
+            Some(Auth::Provider(provider)) => {
+                let provider = Arc::clone(provider);
+                let token = tokio::task::spawn_blocking(move || provider.token())
+                    .await
+                    .map_err(|e| Error::AuthError(e.to_string()))??;
+                request.header(AUTHORIZATION, bearer_header(&token)?)
+            }
+        })
+    }
+
+    /// Add the configured credentials to a blocking request
+    ///
+    /// Blocking counterpart of [`authorize`](Self::authorize).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the credential provider fails or returns a
+    /// token that cannot be sent in a header
+    fn authorize_blocking(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::RequestBuilder> {
+        Ok(match &self.config.auth {
+            None => request,
+            Some(Auth::Bearer(token)) => request.header(AUTHORIZATION, bearer_header(token)?),
+            Some(Auth::Basic { username, password }) => {
+                request.basic_auth(username, password.as_deref())
+            }
+            Some(Auth::Provider(provider)) => {
+                request.header(AUTHORIZATION, bearer_header(&provider.token()?)?)
+            }
+        })
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let client = if request.streaming {
+            &self.stream_client
+        } else {
+            &self.client
+        };
+        let builder = client
+            .request(request.method, self.url(&request.path))
+            .headers(request.headers);
+        let builder = match request.body {
+            RequestBody::Empty => builder,
+            RequestBody::Json(body) => builder.json(&body),
+            RequestBody::File { path, len } => builder
+                .header(CONTENT_LENGTH, len)
+                .body(async_file_body(&path)),
+        };
+
+        let response = self
+            .authorize(builder)
+            .await?
+            .send()
+            .await
+            .map_err(send_error)?;
+        Ok(TransportResponse {
+            status: response.status().as_u16(),
+            headers: response.headers().clone(),
+            body: ResponseBody::Stream(
+                response
+                    .bytes_stream()
+                    .map(|chunk| Ok(chunk?.to_vec()))
+                    .boxed(),
+            ),
+        })
+    }
+
+    fn send_blocking(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let builder = self
+            .blocking_client()?
+            .request(request.method, self.url(&request.path))
+            .headers(request.headers);
+        let builder = match request.body {
+            RequestBody::Empty => builder,
+            RequestBody::Json(body) => builder.json(&body),
+            RequestBody::File { path, len } => builder.body(blocking_file_body(&path, len)),
+        };
+
+        let response = self
+            .authorize_blocking(builder)?
+            .send()
+            .map_err(send_error)?;
+        Ok(TransportResponse {
+            status: response.status().as_u16(),
+            headers: response.headers().clone(),
+            body: ResponseBody::Reader(Box::new(response)),
+        })
+    }
+}
+
+/// Convert a failed send, keeping connection failures apart
+///
+/// Connection failures never reached the server, which the retry loop
+/// relies on to retry them for any endpoint.
+fn send_error(e: reqwest::Error) -> Error {
+    if e.is_connect() {
+        Error::ConnectionError(e.to_string())
+    } else {
+        e.into()
+    }
+}
+
+/// Request body streaming a file in chunks
+///
+/// The file is opened on the first read, so the body for every retry
+/// attempt starts from the beginning of the file.
+fn async_file_body(path: &Path) -> reqwest::Body {
+    let stream = futures::stream::try_unfold(
+        (path.to_path_buf(), None::<tokio::fs::File>),
+        |(path, file)| async move {
+            let mut file = match file {
+                Some(file) => file,
+                None => tokio::fs::File::open(&path).await?,
+            };
+            let mut buf = vec![0u8; CHUNK_SIZE];
+            let n = file.read(&mut buf).await?;
+            if n == 0 {
+                return Ok::<_, io::Error>(None);
+            }
+            buf.truncate(n);
+            Ok(Some((buf, (path, Some(file)))))
+        },
+    );
+    reqwest::Body::wrap_stream(stream)
+}
+
+/// Blocking request body streaming a file of known length
+///
+/// Like [`async_file_body`], the file is opened on the first read.
+fn blocking_file_body(path: &Path, len: u64) -> reqwest::blocking::Body {
+    reqwest::blocking::Body::sized(
+        LazyFile {
+            path: path.to_path_buf(),
+            file: None,
+        },
+        len,
+    )
+}
+
+/// Reader that opens its file on the first read
+struct LazyFile {
+    path: PathBuf,
+    file: Option<File>,
+}
+
+impl Read for LazyFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self.file.insert(File::open(&self.path)?),
+        };
+        file.read(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lazy_file_reads_from_start() {
+        let path = std::env::temp_dir().join(format!("ollama-oxide-lazy-{}", std::process::id()));
+        std::fs::write(&path, b"abc").unwrap();
+
+        let mut reader = LazyFile {
+            path: path.clone(),
+            file: None,
+        };
+        let mut content = String::new();
+        reader.read_to_string(&mut content).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(content, "abc");
+    }
+
+    #[test]
+    fn test_new_rejects_unsupported_scheme() {
+        let config = ClientConfig::default().with_base_url("ftp://localhost:11434");
+
+        let result = ReqwestTransport::new(&config);
+
+        assert!(matches!(result, Err(Error::InvalidUrlError(_))));
+    }
+}
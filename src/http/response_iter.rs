@@ -0,0 +1,107 @@
+//! Blocking iterator of decoded NDJSON chunks
+
+use std::io::Read;
+use std::marker::PhantomData;
+
+use crate::{Error, Result};
+
+use super::ndjson_decoder::NdjsonDecoder;
+use super::transport::{READ_BUFFER_SIZE, TransportResponse};
+
+/// Blocking iterator of response chunks from a streaming endpoint
+///
+/// The blocking counterpart of [`ResponseStream`](super::ResponseStream).
+/// Each call to [`next`](Iterator::next) blocks until the server has sent
+/// the next complete JSON object. Iteration ends after the final chunk or
+/// when the server closes the connection. A network or decoding error is
+/// yielded once and then iteration ends.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{GenerateRequest, OllamaApiSync, OllamaClient};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OllamaClient::default()?;
+/// let request = GenerateRequest::new("qwen3:0.6b", "Why is the sky blue?");
+///
+/// for chunk in client.generate_stream_blocking(&request)? {
+///     print!("{}", chunk?.text().unwrap_or(""));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct ResponseIter<T> {
+    status: u16,
+    body: Box<dyn Read + Send>,
+    decoder: NdjsonDecoder,
+    is_final: fn(&T) -> bool,
+    finished: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ResponseIter<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    /// Wrap a streaming response for blocking reads
+    ///
+    /// `is_final` marks the chunk that terminates iteration; anything the
+    /// server sends after it is ignored.
+    pub(super) fn new(response: TransportResponse, is_final: fn(&T) -> bool) -> Self {
+        Self {
+            decoder: NdjsonDecoder::new(response.status),
+            status: response.status,
+            body: response.body.into_reader(),
+            is_final,
+            finished: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for ResponseIter<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let mut buf = [0u8; READ_BUFFER_SIZE];
+        loop {
+            if let Some(item) = self.decoder.next::<T>() {
+                self.finished = match &item {
+                    Ok(chunk) => (self.is_final)(chunk),
+                    Err(_) => true,
+                };
+                return Some(item);
+            }
+
+            match self.body.read(&mut buf) {
+                Ok(0) => {
+                    self.finished = true;
+                    return self.decoder.finish::<T>();
+                }
+                Ok(n) => self.decoder.push(&buf[..n]),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(Error::HttpError(e.to_string())));
+                }
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for ResponseIter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseIter")
+            .field("status", &self.status)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
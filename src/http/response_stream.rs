@@ -0,0 +1,93 @@
+//! Async stream of decoded NDJSON chunks
+
+use std::pin::Pin;
+
+use futures::stream::BoxStream;
+use futures::{Stream, StreamExt};
+
+use crate::Result;
+
+use super::ndjson_decoder::NdjsonDecoder;
+use super::transport::TransportResponse;
+
+/// Async stream of response chunks from a streaming endpoint
+///
+/// Each item is one JSON object sent by the server, decoded into `T`.
+/// The stream ends after the final chunk (e.g. `done: true` for chat) or
+/// when the server closes the connection. A network or decoding error is
+/// yielded once and then the stream ends.
+///
+/// Use [`futures::StreamExt`] to consume it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use ollama_oxide::{ChatMessage, ChatRequest, OllamaApiAsync, OllamaClient};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OllamaClient::default()?;
+/// let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello!")]);
+///
+/// let mut stream = client.chat_stream(&request).await?;
+/// while let Some(chunk) = stream.next().await {
+///     print!("{}", chunk?.content().unwrap_or(""));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub type ResponseStream<T> = Pin<Box<dyn Stream<Item = Result<T>> + Send>>;
+
+struct StreamState<T> {
+    body: BoxStream<'static, Result<Vec<u8>>>,
+    decoder: NdjsonDecoder,
+    is_final: fn(&T) -> bool,
+    finished: bool,
+}
+
+/// Turn a streaming response into a [`ResponseStream`]
+///
+/// `is_final` marks the chunk that terminates the stream; anything the
+/// server sends after it is ignored.
+pub(super) fn ndjson_stream<T>(
+    response: TransportResponse,
+    is_final: fn(&T) -> bool,
+) -> ResponseStream<T>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+{
+    let state = StreamState {
+        decoder: NdjsonDecoder::new(response.status),
+        body: response.body.into_stream(),
+        is_final,
+        finished: false,
+    };
+
+    Box::pin(futures::stream::unfold(state, |mut state| async move {
+        if state.finished {
+            return None;
+        }
+
+        loop {
+            if let Some(item) = state.decoder.next::<T>() {
+                state.finished = match &item {
+                    Ok(chunk) => (state.is_final)(chunk),
+                    Err(_) => true,
+                };
+                return Some((item, state));
+            }
+
+            match state.body.next().await {
+                Some(Ok(bytes)) => state.decoder.push(&bytes),
+                None => {
+                    state.finished = true;
+                    return state.decoder.finish::<T>().map(|item| (item, state));
+                }
+                Some(Err(e)) => {
+                    state.finished = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    }))
+}
@@ -0,0 +1,349 @@
+//! Retry policy for failed HTTP requests
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::Method;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use super::endpoints::Endpoints;
+
+/// Controls when and how failed requests are retried
+///
+/// The number of retries is set by [`ClientConfig::max_retries`](super::ClientConfig::max_retries);
+/// this policy decides which failures are retried and how long to wait
+/// between attempts.
+///
+/// Delays grow exponentially: attempt `n` (starting at 0) waits
+/// `base_delay * multiplier^n`, capped at `max_delay`, then reduced by a
+/// random amount of up to `jitter` (a fraction between 0.0 and 1.0) so
+/// that many clients do not retry in lockstep. When the server sends a
+/// `Retry-After` header in seconds it is used instead, also capped at
+/// `max_delay`.
+///
+/// `GET` and `DELETE` requests are always safe to retry. `POST` requests
+/// are only retried for endpoints listed in `post_retry_endpoints`, since
+/// endpoints like `/api/create` or `/api/push` may have side effects when
+/// repeated. Connection failures, where the request never reached the
+/// server, are retried for every endpoint.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{ClientConfig, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::default()
+///     .with_base_delay(Duration::from_millis(250))
+///     .with_max_delay(Duration::from_secs(5))
+///     .with_retryable_statuses([429, 503])
+///     .allow_post_retry("/api/create");
+///
+/// let config = ClientConfig {
+///     max_retries: 5,
+///     retry_policy: policy,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Delay before the first retry
+    pub base_delay: Duration,
+
+    /// Upper bound for any single delay, including `Retry-After`
+    pub max_delay: Duration,
+
+    /// Factor applied to the delay after each attempt
+    pub multiplier: f64,
+
+    /// Maximum fraction of each delay removed at random (0.0 = no jitter)
+    pub jitter: f64,
+
+    /// HTTP status codes that trigger a retry
+    pub retryable_statuses: Vec<u16>,
+
+    /// Endpoints whose `POST` requests may be retried
+    pub post_retry_endpoints: Vec<String>,
+
+    /// Whether to honor the `Retry-After` response header
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.1,
+            retryable_statuses: vec![408, 429, 500, 502, 503, 504],
+            post_retry_endpoints: [
+                Endpoints::GENERATE,
+                Endpoints::CHAT,
+                Endpoints::EMBED,
+                Endpoints::SHOW,
+                Endpoints::COPY,
+                Endpoints::PULL,
+                Endpoints::BLOBS,
+            ]
+            .iter()
+            .map(|endpoint| endpoint.to_string())
+            .collect(),
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Set the delay before the first retry
+    pub fn with_base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Set the upper bound for any single delay
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Set the factor applied to the delay after each attempt
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Set the maximum fraction of each delay removed at random
+    ///
+    /// Values are clamped to the range 0.0 to 1.0.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Replace the HTTP status codes that trigger a retry
+    pub fn with_retryable_statuses(mut self, statuses: impl IntoIterator<Item = u16>) -> Self {
+        self.retryable_statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Allow `POST` requests to an endpoint to be retried
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path, e.g. `"/api/create"`
+    pub fn allow_post_retry(mut self, endpoint: impl Into<String>) -> Self {
+        let endpoint = endpoint.into();
+        if !self.post_retry_endpoints.contains(&endpoint) {
+            self.post_retry_endpoints.push(endpoint);
+        }
+        self
+    }
+
+    /// Prevent `POST` requests to an endpoint from being retried
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint path, e.g. `"/api/chat"`
+    pub fn deny_post_retry(mut self, endpoint: &str) -> Self {
+        self.post_retry_endpoints.retain(|e| e != endpoint);
+        self
+    }
+
+    /// Set whether to honor the `Retry-After` response header
+    pub fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Check if a response status should be retried
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    /// Check if a request may be retried after it reached the server
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method of the request
+    /// * `endpoint` - Endpoint path of the request
+    pub fn allows_retry(&self, method: &Method, endpoint: &str) -> bool {
+        match *method {
+            Method::POST => self.post_retry_endpoints.iter().any(|e| e == endpoint),
+            _ => method.is_idempotent(),
+        }
+    }
+
+    /// Exponential backoff for an attempt, without jitter
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - Zero-based number of the attempt that failed
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let secs = self.base_delay.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        if !secs.is_finite() || secs >= self.max_delay.as_secs_f64() {
+            return self.max_delay;
+        }
+        Duration::from_secs_f64(secs)
+    }
+
+    /// Delay to wait before retrying a failed attempt
+    ///
+    /// Uses `retry_after` when present and enabled, otherwise the
+    /// exponential backoff with jitter applied.
+    pub(super) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after.filter(|_| self.respect_retry_after) {
+            return retry_after.min(self.max_delay);
+        }
+
+        let backoff = self.backoff(attempt);
+        if self.jitter <= 0.0 {
+            return backoff;
+        }
+        backoff.mul_f64(1.0 - self.jitter.min(1.0) * random_unit())
+    }
+}
+
+/// Parse a `Retry-After` header given in seconds
+///
+/// The HTTP-date form is not supported and is ignored.
+pub(super) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Random number in `[0, 1)` for jitter
+///
+/// Every `RandomState` is seeded differently, which is enough to spread
+/// retries without pulling in a random number generator dependency.
+fn random_unit() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn no_jitter() -> RetryPolicy {
+        RetryPolicy::default().with_jitter(0.0)
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let policy = RetryPolicy::default();
+
+        assert_eq!(policy.base_delay, Duration::from_millis(100));
+        assert_eq!(policy.max_delay, Duration::from_secs(10));
+        assert_eq!(policy.multiplier, 2.0);
+        assert!(policy.respect_retry_after);
+        assert!(policy.is_retryable_status(503));
+        assert!(policy.is_retryable_status(429));
+        assert!(!policy.is_retryable_status(404));
+        assert!(!policy.is_retryable_status(501));
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = no_jitter().with_max_delay(Duration::from_millis(500));
+
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(1), Duration::from_millis(200));
+        assert_eq!(policy.backoff(2), Duration::from_millis(400));
+        assert_eq!(policy.backoff(3), Duration::from_millis(500));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_delay_without_jitter_matches_backoff() {
+        let policy = no_jitter();
+        assert_eq!(policy.delay(2, None), policy.backoff(2));
+    }
+
+    #[test]
+    fn test_delay_with_jitter_stays_in_range() {
+        let policy = RetryPolicy::default().with_jitter(0.5);
+
+        for _ in 0..100 {
+            let delay = policy.delay(1, None);
+            assert!(delay <= Duration::from_millis(200));
+            assert!(delay >= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_delay_uses_retry_after() {
+        let policy = no_jitter();
+
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(3))),
+            Duration::from_secs(3)
+        );
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(60))),
+            Duration::from_secs(10)
+        );
+
+        let policy = policy.with_respect_retry_after(false);
+        assert_eq!(
+            policy.delay(0, Some(Duration::from_secs(3))),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn test_allows_retry_by_method() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.allows_retry(&Method::GET, Endpoints::VERSION));
+        assert!(policy.allows_retry(&Method::DELETE, Endpoints::DELETE));
+        assert!(policy.allows_retry(&Method::POST, Endpoints::CHAT));
+        assert!(!policy.allows_retry(&Method::POST, Endpoints::CREATE));
+        assert!(!policy.allows_retry(&Method::POST, Endpoints::PUSH));
+        assert!(policy.allows_retry(&Method::POST, Endpoints::BLOBS));
+        assert!(policy.allows_retry(&Method::HEAD, Endpoints::BLOBS));
+    }
+
+    #[test]
+    fn test_allow_and_deny_post_retry() {
+        let policy = RetryPolicy::default()
+            .allow_post_retry(Endpoints::CREATE)
+            .allow_post_retry(Endpoints::CREATE)
+            .deny_post_retry(Endpoints::CHAT);
+
+        assert!(policy.allows_retry(&Method::POST, Endpoints::CREATE));
+        assert!(!policy.allows_retry(&Method::POST, Endpoints::CHAT));
+        assert_eq!(
+            policy
+                .post_retry_endpoints
+                .iter()
+                .filter(|e| *e == Endpoints::CREATE)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_with_jitter_is_clamped() {
+        assert_eq!(RetryPolicy::default().with_jitter(2.0).jitter, 1.0);
+        assert_eq!(RetryPolicy::default().with_jitter(-1.0).jitter, 0.0);
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+}
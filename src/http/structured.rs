@@ -0,0 +1,156 @@
+//! Helpers for typed structured output
+//!
+//! Shared by the async and blocking `chat_structured` and
+//! `generate_structured` implementations.
+
+use serde::de::DeserializeOwned;
+
+use crate::{ChatMessage, ChatRequest, Error, GenerateRequest, Result};
+
+/// Parse a model reply into `T`
+///
+/// Surrounding whitespace and a Markdown code fence, which some models add
+/// even when a format is requested, are ignored.
+///
+/// # Errors
+///
+/// Returns [`Error::StructuredOutputError`] holding the raw reply if it is
+/// not valid JSON for `T`
+pub(super) fn parse_reply<T: DeserializeOwned>(raw: Option<&str>) -> Result<T> {
+    let raw = raw.unwrap_or_default();
+    serde_json::from_str(strip_code_fence(raw)).map_err(|e| Error::StructuredOutputError {
+        message: e.to_string(),
+        raw: raw.to_string(),
+    })
+}
+
+/// Remove a surrounding ```` ``` ```` or ```` ```json ```` fence
+fn strip_code_fence(raw: &str) -> &str {
+    let trimmed = raw.trim();
+    trimmed
+        .strip_prefix("```")
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map(|inner| inner.strip_prefix("json").unwrap_or(inner).trim())
+        .unwrap_or(trimmed)
+}
+
+/// Instruction sent after a reply that could not be parsed
+fn correction_text(error: &Error) -> String {
+    let message = match error {
+        Error::StructuredOutputError { message, .. } => message.as_str(),
+        _ => "invalid reply",
+    };
+    format!(
+        "Your previous reply could not be parsed: {}. \
+         Reply again with only the corrected JSON, matching the requested schema.",
+        message
+    )
+}
+
+/// Extend a chat request with the failed reply and a corrective message
+pub(super) fn chat_correction(request: &mut ChatRequest, error: &Error) {
+    if let Error::StructuredOutputError { raw, .. } = error {
+        request.messages.push(ChatMessage::assistant(raw.clone()));
+    }
+    request
+        .messages
+        .push(ChatMessage::user(correction_text(error)));
+}
+
+/// Extend a generate prompt with the failed reply and a corrective note
+///
+/// Generate requests have no conversation, so the failed reply and the
+/// instruction are appended to the original prompt.
+pub(super) fn generate_correction(original: &GenerateRequest, error: &Error) -> GenerateRequest {
+    let raw = match error {
+        Error::StructuredOutputError { raw, .. } => raw.as_str(),
+        _ => "",
+    };
+    let prompt = format!(
+        "{}\n\nPrevious reply:\n{}\n\n{}",
+        original.prompt.as_deref().unwrap_or_default(),
+        raw,
+        correction_text(error)
+    );
+    GenerateRequest {
+        prompt: Some(prompt),
+        ..original.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        let point: Point = parse_reply(Some(r#" {"x": 1, "y": 2} "#)).unwrap();
+        assert_eq!(point, Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn test_parse_reply_strips_code_fence() {
+        let point: Point = parse_reply(Some("```json\n{\"x\": 1, \"y\": 2}\n```")).unwrap();
+        assert_eq!(point, Point { x: 1, y: 2 });
+    }
+
+    #[test]
+    fn test_parse_reply_error_keeps_raw_text() {
+        let result: Result<Point> = parse_reply(Some(r#"{"x": 1}"#));
+
+        match result {
+            Err(Error::StructuredOutputError { message, raw }) => {
+                assert!(message.contains("missing field `y`"));
+                assert_eq!(raw, r#"{"x": 1}"#);
+            }
+            other => panic!("expected StructuredOutputError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_reply_without_content() {
+        let result: Result<Point> = parse_reply(None);
+        assert!(matches!(result, Err(Error::StructuredOutputError { raw, .. }) if raw.is_empty()));
+    }
+
+    #[test]
+    fn test_chat_correction_appends_reply_and_instruction() {
+        let mut request = ChatRequest::new("model", [ChatMessage::user("Where?")]);
+        let error = Error::StructuredOutputError {
+            message: "expected value".to_string(),
+            raw: "nowhere".to_string(),
+        };
+
+        chat_correction(&mut request, &error);
+
+        assert_eq!(request.messages.len(), 3);
+        assert!(request.messages[1].is_assistant());
+        assert_eq!(request.messages[1].content, "nowhere");
+        assert!(request.messages[2].is_user());
+        assert!(request.messages[2].content.contains("expected value"));
+    }
+
+    #[test]
+    fn test_generate_correction_extends_prompt() {
+        let request = GenerateRequest::new("model", "Give a point").with_system("JSON only");
+        let error = Error::StructuredOutputError {
+            message: "expected value".to_string(),
+            raw: "a point".to_string(),
+        };
+
+        let corrected = generate_correction(&request, &error);
+        let prompt = corrected.prompt.unwrap();
+
+        assert!(prompt.starts_with("Give a point"));
+        assert!(prompt.contains("a point"));
+        assert!(prompt.contains("expected value"));
+        assert_eq!(corrected.system, request.system);
+    }
+}
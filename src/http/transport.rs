@@ -0,0 +1,523 @@
+//! Transport layer that carries requests to an Ollama server
+
+use std::fmt;
+use std::io::{self, Cursor, Read};
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use reqwest::Method;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{Error, Result};
+
+/// Size of each read from a blocking response body
+pub(super) const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Sends requests to an Ollama server and returns its responses
+///
+/// Every endpoint of [`OllamaClient`](super::OllamaClient) is built on a
+/// transport: the client serializes the request, and the transport only
+/// moves a method, a path and a JSON body to the server and the response
+/// back. Retries, error decoding and NDJSON streaming stay in the client,
+/// so they work the same over any transport.
+///
+/// [`ReqwestTransport`](super::ReqwestTransport) is used by default. Pass
+/// another implementation to [`OllamaClient::with_transport`](super::OllamaClient::with_transport)
+/// to answer requests in memory, or to reach Ollama over something other
+/// than HTTP.
+///
+/// # Errors
+///
+/// Return [`Error::ConnectionError`] when the request never reached the
+/// server; the client retries those for every endpoint. Other network
+/// failures, such as [`Error::HttpError`], are only retried where the
+/// [`RetryPolicy`](super::RetryPolicy) allows it, and any other error is
+/// returned to the caller unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use ollama_oxide::{
+///     ClientConfig, OllamaApiSync, OllamaClient, Result, Transport, TransportRequest,
+///     TransportResponse,
+/// };
+/// use serde_json::json;
+///
+/// #[derive(Debug)]
+/// struct FixedVersion;
+///
+/// #[async_trait]
+/// impl Transport for FixedVersion {
+///     async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+///         self.send_blocking(request)
+///     }
+///
+///     fn send_blocking(&self, request: TransportRequest) -> Result<TransportResponse> {
+///         assert_eq!(request.path, "/api/version");
+///         Ok(TransportResponse::json(200, &json!({"version": "0.12.6"})))
+///     }
+/// }
+///
+/// let client = OllamaClient::with_transport(ClientConfig::default(), FixedVersion)?;
+/// assert_eq!(client.version_blocking()?.version, "0.12.6");
+/// # Ok::<(), ollama_oxide::Error>(())
+/// ```
+#[async_trait]
+pub trait Transport: Send + Sync + fmt::Debug {
+    /// Send a request from async code
+    ///
+    /// Called once per attempt; the client retries by calling it again.
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse>;
+
+    /// Send a request from blocking code
+    ///
+    /// Blocking counterpart of [`send`](Self::send), used by
+    /// [`OllamaApiSync`](super::OllamaApiSync). It is never called from
+    /// inside an async runtime by the client.
+    fn send_blocking(&self, request: TransportRequest) -> Result<TransportResponse>;
+}
+
+/// A request for a [`Transport`] to send
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportRequest {
+    /// HTTP method
+    pub method: Method,
+
+    /// Endpoint path relative to the base URL, e.g. `/api/chat`
+    pub path: String,
+
+    /// Headers for this request, sent on top of
+    /// [`ClientConfig::headers`](super::ClientConfig::headers)
+    pub headers: HeaderMap,
+
+    /// Request body
+    pub body: RequestBody,
+
+    /// Whether the exchange may run for a long time, such as a streamed
+    /// response or a file upload
+    ///
+    /// [`ReqwestTransport`](super::ReqwestTransport) bounds each read
+    /// instead of the whole request for these.
+    pub streaming: bool,
+}
+
+impl TransportRequest {
+    /// Create a request without a body
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - HTTP method
+    /// * `path` - Endpoint path, e.g. `/api/version`
+    pub fn new(method: Method, path: impl Into<String>) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            headers: HeaderMap::new(),
+            body: RequestBody::Empty,
+            streaming: false,
+        }
+    }
+
+    /// Add a header to this request
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Set the request body
+    pub fn with_body(mut self, body: RequestBody) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Deserialize the JSON body into a request type
+    ///
+    /// Returns `None` when the body is not JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid JSON for `T`
+    pub fn body_json<T: DeserializeOwned>(&self) -> Option<Result<T>> {
+        match &self.body {
+            RequestBody::Json(body) => Some(T::deserialize(body).map_err(Error::from)),
+            RequestBody::Empty | RequestBody::File { .. } => None,
+        }
+    }
+
+    /// Mark the exchange as long-running
+    pub fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+}
+
+/// Body of a [`TransportRequest`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestBody {
+    /// No body
+    Empty,
+
+    /// JSON body, sent with `Content-Type: application/json`
+    Json(Value),
+
+    /// Raw contents of a file, streamed from disk by the transport
+    File {
+        /// File to send
+        path: PathBuf,
+        /// File size in bytes, sent as `Content-Length`
+        len: u64,
+    },
+}
+
+/// A response returned by a [`Transport`]
+#[derive(Debug)]
+pub struct TransportResponse {
+    /// HTTP status code
+    pub status: u16,
+
+    /// Response headers
+    pub headers: HeaderMap,
+
+    /// Response body, read lazily
+    pub body: ResponseBody,
+}
+
+impl TransportResponse {
+    /// Create a response without headers
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - HTTP status code
+    /// * `body` - Response body
+    pub fn new(status: u16, body: ResponseBody) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body,
+        }
+    }
+
+    /// Create a JSON response held in memory
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - HTTP status code
+    /// * `body` - JSON value to send
+    pub fn json(status: u16, body: &Value) -> Self {
+        let mut response = Self::new(status, ResponseBody::Bytes(body.to_string().into_bytes()));
+        response
+            .headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+
+    /// Check if the status is in the 2xx range
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Read the whole body into memory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the body fails
+    pub(super) async fn buffered(self) -> Result<Self> {
+        let Self {
+            status,
+            headers,
+            body,
+        } = self;
+        let body = ResponseBody::Bytes(body.bytes().await?);
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Read the whole body into memory from blocking code
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the body fails
+    pub(super) fn buffered_blocking(self) -> Result<Self> {
+        let Self {
+            status,
+            headers,
+            body,
+        } = self;
+        let body = ResponseBody::Bytes(body.bytes_blocking()?);
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    /// Deserialize a body held in memory into a response type
+    ///
+    /// Returns `None` unless the body is [`ResponseBody::Bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid JSON for `T`
+    pub fn body_json<T: DeserializeOwned>(&self) -> Option<Result<T>> {
+        self.body
+            .as_bytes()
+            .map(|bytes| serde_json::from_slice(bytes).map_err(Error::from))
+    }
+}
+
+/// Body of a [`TransportResponse`]
+///
+/// Streaming endpoints decode the body as it arrives, so a transport
+/// should hand over a [`Stream`](Self::Stream) or [`Reader`](Self::Reader)
+/// rather than buffer a long response. Any variant works with both the
+/// async and the blocking API.
+pub enum ResponseBody {
+    /// Complete body held in memory
+    Bytes(Vec<u8>),
+
+    /// Body read asynchronously in chunks
+    Stream(BoxStream<'static, Result<Vec<u8>>>),
+
+    /// Body read from a blocking reader
+    Reader(Box<dyn Read + Send>),
+}
+
+impl ResponseBody {
+    /// Get the body if it is held in memory
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(bytes) => Some(bytes),
+            Self::Stream(_) | Self::Reader(_) => None,
+        }
+    }
+
+    /// Read the whole body
+    ///
+    /// A [`Reader`](Self::Reader) body is read on Tokio's blocking thread
+    /// pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the body fails
+    pub async fn bytes(self) -> Result<Vec<u8>> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            body => {
+                body.into_stream()
+                    .try_fold(Vec::new(), |mut bytes, chunk| async move {
+                        bytes.extend_from_slice(&chunk);
+                        Ok(bytes)
+                    })
+                    .await
+            }
+        }
+    }
+
+    /// Read the whole body from blocking code
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the body fails
+    pub fn bytes_blocking(self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.into_reader()
+            .read_to_end(&mut bytes)
+            .map_err(|e| Error::HttpError(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Turn the body into an async stream of chunks
+    ///
+    /// Each read of a [`Reader`](Self::Reader) body runs on Tokio's
+    /// blocking thread pool, so it does not stall the async runtime.
+    pub fn into_stream(self) -> BoxStream<'static, Result<Vec<u8>>> {
+        match self {
+            Self::Bytes(bytes) => futures::stream::iter([Ok(bytes)]).boxed(),
+            Self::Stream(stream) => stream,
+            Self::Reader(reader) => futures::stream::unfold(Some(reader), |reader| async move {
+                let reader = reader?;
+                match tokio::task::spawn_blocking(move || read_chunk(reader)).await {
+                    Ok(next) => next.map(|(chunk, reader)| (chunk, Some(reader))),
+                    Err(e) => Some((Err(Error::HttpError(e.to_string())), None)),
+                }
+            })
+            .boxed(),
+        }
+    }
+
+    /// Turn the body into a blocking reader
+    ///
+    /// Reading a [`Stream`](Self::Stream) body blocks on each chunk, so
+    /// the reader must not be used inside an async runtime.
+    pub fn into_reader(self) -> Box<dyn Read + Send> {
+        match self {
+            Self::Bytes(bytes) => Box::new(Cursor::new(bytes)),
+            Self::Reader(reader) => reader,
+            Self::Stream(stream) => Box::new(StreamReader {
+                stream,
+                chunk: Cursor::new(Vec::new()),
+            }),
+        }
+    }
+}
+
+/// Reader of a [`ResponseBody::Reader`] body
+type BodyReader = Box<dyn Read + Send>;
+
+/// Read the next chunk of a blocking body, handing the reader back
+fn read_chunk(mut reader: BodyReader) -> Option<(Result<Vec<u8>>, BodyReader)> {
+    let mut buf = vec![0u8; READ_BUFFER_SIZE];
+    loop {
+        return match reader.read(&mut buf) {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some((Ok(buf), reader))
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => Some((Err(Error::HttpError(e.to_string())), reader)),
+        };
+    }
+}
+
+impl fmt::Debug for ResponseBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Stream(_) => f.write_str("Stream(..)"),
+            Self::Reader(_) => f.write_str("Reader(..)"),
+        }
+    }
+}
+
+/// Blocking reader over an async chunk stream
+struct StreamReader {
+    stream: BoxStream<'static, Result<Vec<u8>>>,
+    /// Unread rest of the current chunk
+    chunk: Cursor<Vec<u8>>,
+}
+
+impl Read for StreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let n = self.chunk.read(buf)?;
+            if n > 0 || buf.is_empty() {
+                return Ok(n);
+            }
+            match futures::executor::block_on(self.stream.next()) {
+                Some(Ok(chunk)) => self.chunk = Cursor::new(chunk),
+                Some(Err(e)) => return Err(io::Error::other(e.to_string())),
+                None => return Ok(0),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chunks() -> ResponseBody {
+        ResponseBody::Stream(futures::stream::iter([Ok(b"ab".to_vec()), Ok(b"c".to_vec())]).boxed())
+    }
+
+    #[tokio::test]
+    async fn test_bytes_collects_every_variant() {
+        let reader = ResponseBody::Reader(Box::new(Cursor::new(b"abc".to_vec())));
+
+        assert_eq!(
+            ResponseBody::Bytes(b"abc".to_vec()).bytes().await.unwrap(),
+            b"abc"
+        );
+        assert_eq!(chunks().bytes().await.unwrap(), b"abc");
+        assert_eq!(reader.bytes().await.unwrap(), b"abc");
+    }
+
+    /// Blocking reader whose data comes from another task
+    struct ChannelReader(std::sync::mpsc::Receiver<Vec<u8>>);
+
+    impl Read for ChannelReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.recv_timeout(std::time::Duration::from_secs(5)) {
+                Ok(data) => {
+                    buf[..data.len()].copy_from_slice(&data);
+                    Ok(data.len())
+                }
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => Ok(0),
+                Err(e) => Err(io::Error::other(e)),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reader_body_does_not_block_runtime() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        // On a current-thread runtime, this task only runs if reading
+        // leaves the executor thread free
+        tokio::spawn(async move {
+            sender.send(b"abc".to_vec()).unwrap();
+        });
+
+        let body = ResponseBody::Reader(Box::new(ChannelReader(receiver)));
+
+        assert_eq!(body.bytes().await.unwrap(), b"abc");
+    }
+
+    #[test]
+    fn test_bytes_blocking_reads_stream() {
+        assert_eq!(chunks().bytes_blocking().unwrap(), b"abc");
+    }
+
+    #[test]
+    fn test_stream_error_surfaces_when_read_blocking() {
+        let body = ResponseBody::Stream(
+            futures::stream::iter([
+                Ok(b"a".to_vec()),
+                Err(Error::HttpError("reset".to_string())),
+            ])
+            .boxed(),
+        );
+
+        let result = body.bytes_blocking();
+
+        assert!(matches!(result, Err(Error::HttpError(message)) if message.contains("reset")));
+    }
+
+    #[test]
+    fn test_body_json_decodes_json_bodies_only() {
+        let request = TransportRequest::new(Method::POST, "/api/show")
+            .with_body(RequestBody::Json(serde_json::json!({"model": "qwen3"})));
+        let response = TransportResponse::json(200, &serde_json::json!({"version": "1"}));
+        let streamed = TransportResponse::new(200, chunks());
+
+        let body: Value = request.body_json().unwrap().unwrap();
+        assert_eq!(body["model"], "qwen3");
+        let body: Value = response.body_json().unwrap().unwrap();
+        assert_eq!(body["version"], "1");
+        assert!(
+            TransportRequest::new(Method::GET, "/api/tags")
+                .body_json::<Value>()
+                .is_none()
+        );
+        assert!(streamed.body_json::<Value>().is_none());
+    }
+
+    #[test]
+    fn test_json_response() {
+        let response = TransportResponse::json(200, &serde_json::json!({"version": "1"}));
+
+        assert!(response.is_success());
+        assert_eq!(response.headers[CONTENT_TYPE], "application/json");
+        assert!(
+            matches!(response.body, ResponseBody::Bytes(bytes) if bytes == br#"{"version":"1"}"#)
+        );
+    }
+}
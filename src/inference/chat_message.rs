@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use super::ChatRole;
+use super::{ChatRole, ResponseMessage};
 
 #[cfg(feature = "tools")]
 use crate::tools::ToolCall;
@@ -54,6 +54,13 @@ pub struct ChatMessage {
     /// Text content of the message.
     pub content: String,
 
+    /// Thinking/reasoning output of a previous assistant message.
+    ///
+    /// When replaying a conversation with a thinking model, include the
+    /// thinking text the model returned with the assistant message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<String>,
+
     /// Optional base64-encoded images for multimodal models.
     ///
     /// When using vision-capable models, you can include images
@@ -70,6 +77,12 @@ pub struct ChatMessage {
     #[cfg(feature = "tools")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_calls: Option<Vec<ToolCall>>,
+
+    /// Name of the tool that produced this result (for tool messages).
+    ///
+    /// Ollama uses it to match a tool result to the call that produced it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
 }
 
 impl ChatMessage {
@@ -93,9 +106,11 @@ impl ChatMessage {
         Self {
             role,
             content: content.into(),
+            thinking: None,
             images: None,
             #[cfg(feature = "tools")]
             tool_calls: None,
+            tool_name: None,
         }
     }
 
@@ -185,6 +200,46 @@ impl ChatMessage {
         Self::new(ChatRole::Tool, content)
     }
 
+    /// Set the name of the tool that produced this result.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_name` - The function name of the tool call being answered
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::ChatMessage;
+    ///
+    /// let msg = ChatMessage::tool(r#"{"temperature": 22}"#)
+    ///     .with_tool_name("get_weather");
+    /// assert_eq!(msg.tool_name.as_deref(), Some("get_weather"));
+    /// ```
+    pub fn with_tool_name(mut self, tool_name: impl Into<String>) -> Self {
+        self.tool_name = Some(tool_name.into());
+        self
+    }
+
+    /// Set the thinking text (for assistant messages).
+    ///
+    /// # Arguments
+    ///
+    /// * `thinking` - The model's reasoning returned with the message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::ChatMessage;
+    ///
+    /// let msg = ChatMessage::assistant("42")
+    ///     .with_thinking("The user wants the answer to everything.");
+    /// assert!(msg.thinking.is_some());
+    /// ```
+    pub fn with_thinking(mut self, thinking: impl Into<String>) -> Self {
+        self.thinking = Some(thinking.into());
+        self
+    }
+
     /// Add an image to the message (base64-encoded).
     ///
     /// For use with multimodal/vision models. Images should be
@@ -327,6 +382,40 @@ impl ChatMessage {
     }
 }
 
+/// Convert a response message into a message for the conversation history.
+///
+/// Keeps content, thinking, images and tool calls. The role defaults to
+/// assistant when the response has none or an unknown one.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::{ChatMessage, ResponseMessage};
+///
+/// let reply = ResponseMessage::new("Hello!");
+/// let msg = ChatMessage::from(reply);
+/// assert!(msg.is_assistant());
+/// assert_eq!(msg.content, "Hello!");
+/// ```
+impl From<ResponseMessage> for ChatMessage {
+    fn from(message: ResponseMessage) -> Self {
+        let role = message
+            .role
+            .and_then(|role| serde_json::from_value(serde_json::Value::String(role)).ok())
+            .unwrap_or(ChatRole::Assistant);
+
+        Self {
+            role,
+            content: message.content.unwrap_or_default(),
+            thinking: message.thinking,
+            images: message.images,
+            #[cfg(feature = "tools")]
+            tool_calls: message.tool_calls,
+            tool_name: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,9 +505,11 @@ mod tests {
         let empty_images = ChatMessage {
             role: ChatRole::User,
             content: "test".into(),
+            thinking: None,
             images: Some(vec![]),
             #[cfg(feature = "tools")]
             tool_calls: None,
+            tool_name: None,
         };
         assert!(!empty_images.has_images());
 
@@ -435,8 +526,10 @@ mod tests {
         let empty_tools = ChatMessage {
             role: ChatRole::Assistant,
             content: "".into(),
+            thinking: None,
             images: None,
             tool_calls: Some(vec![]),
+            tool_name: None,
         };
         assert!(!empty_tools.has_tool_calls());
 
@@ -557,6 +650,63 @@ mod tests {
         assert_ne!(msg1, msg3);
     }
 
+    #[test]
+    fn test_chat_message_tool_name() {
+        let msg = ChatMessage::tool(r#"{"sum": 5}"#).with_tool_name("add");
+        let json = serde_json::to_value(&msg).unwrap();
+
+        assert_eq!(json["role"], "tool");
+        assert_eq!(json["tool_name"], "add");
+        assert!(
+            serde_json::to_value(ChatMessage::tool("x"))
+                .unwrap()
+                .get("tool_name")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_chat_message_thinking() {
+        let msg = ChatMessage::assistant("4").with_thinking("2 + 2 is 4");
+        let json = serde_json::to_value(&msg).unwrap();
+        assert_eq!(json["thinking"], "2 + 2 is 4");
+
+        let msg: ChatMessage = serde_json::from_value(json).unwrap();
+        assert_eq!(msg.thinking.as_deref(), Some("2 + 2 is 4"));
+    }
+
+    #[test]
+    fn test_chat_message_from_response_message() {
+        let response: ResponseMessage = serde_json::from_value(json!({
+            "role": "assistant",
+            "content": "Let me check.",
+            "thinking": "I need the weather tool.",
+            "images": ["img"],
+            "tool_calls": [{"function": {"name": "get_weather", "arguments": {"city": "Paris"}}}]
+        }))
+        .unwrap();
+
+        let msg = ChatMessage::from(response);
+        assert_eq!(msg.role, ChatRole::Assistant);
+        assert_eq!(msg.content, "Let me check.");
+        assert_eq!(msg.thinking.as_deref(), Some("I need the weather tool."));
+        assert_eq!(msg.images, Some(vec!["img".to_string()]));
+        assert!(msg.tool_name.is_none());
+        #[cfg(feature = "tools")]
+        assert_eq!(
+            msg.tool_calls.as_ref().unwrap()[0].function_name(),
+            Some("get_weather")
+        );
+    }
+
+    #[test]
+    fn test_chat_message_from_empty_response_message() {
+        let msg = ChatMessage::from(ResponseMessage::empty());
+        assert!(msg.is_assistant());
+        assert_eq!(msg.content, "");
+        assert!(msg.thinking.is_none());
+    }
+
     #[test]
     fn test_chat_message_into_string() {
         let msg = ChatMessage::user(String::from("owned"));
@@ -9,7 +9,8 @@ use crate::tools::ToolDefinition;
 /// Request body for POST /api/chat endpoint.
 ///
 /// Generates the next message in a chat conversation.
-/// This type always sets `stream: false` for non-streaming responses.
+/// `ChatRequest::new` sets `stream: false`; streaming client methods
+/// override it when sending.
 ///
 /// # Examples
 ///
@@ -102,7 +103,8 @@ pub struct ChatRequest {
 
     /// Whether to stream the response.
     ///
-    /// Always set to `false` for v0.1.0 (non-streaming only).
+    /// Defaults to `false`. Streaming methods such as `chat_stream`
+    /// send `true` regardless of this value.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
 
@@ -538,10 +538,12 @@ mod tests {
 
     #[test]
     fn test_chat_response_serialize() {
-        let mut response = ChatResponse::default();
-        response.model = Some("test".to_string());
-        response.done = Some(true);
-        response.message = Some(ResponseMessage::new("Hello"));
+        let response = ChatResponse {
+            model: Some("test".to_string()),
+            done: Some(true),
+            message: Some(ResponseMessage::new("Hello")),
+            ..Default::default()
+        };
 
         let json = serde_json::to_value(&response).unwrap();
 
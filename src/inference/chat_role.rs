@@ -122,6 +122,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_chat_role_clone() {
         let role = ChatRole::Assistant;
         let cloned = role.clone();
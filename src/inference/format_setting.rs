@@ -42,6 +42,35 @@ impl FormatSetting {
     pub fn schema(schema: serde_json::Value) -> Self {
         Self::Schema(schema)
     }
+
+    /// Create schema-based format from a Rust type
+    ///
+    /// The JSON schema is derived from `T` with `schemars` and normalized
+    /// with [`normalize_schema`](crate::tools::normalize_schema).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama_oxide::FormatSetting;
+    /// use schemars::JsonSchema;
+    ///
+    /// #[derive(JsonSchema)]
+    /// struct Country {
+    ///     name: String,
+    ///     capital: String,
+    /// }
+    ///
+    /// let format = FormatSetting::schema_for::<Country>();
+    /// ```
+    #[cfg(feature = "tools")]
+    pub fn schema_for<T: schemars::JsonSchema>() -> Self {
+        let schema = schemars::schema_for!(T);
+        Self::Schema(
+            serde_json::to_value(schema)
+                .map(crate::tools::normalize_schema)
+                .unwrap_or_else(|_| serde_json::json!({})),
+        )
+    }
 }
 
 impl From<&str> for FormatSetting {
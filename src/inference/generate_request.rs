@@ -58,7 +58,7 @@ pub struct GenerateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub system: Option<String>,
 
-    /// Whether to stream the response (always false for v0.1.0)
+    /// Whether to stream the response (streaming methods always send true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
 
@@ -91,6 +91,7 @@ impl GenerateRequest {
     /// Create a new generate request
     ///
     /// Creates a non-streaming request with the specified model and prompt.
+    /// Streaming methods such as `generate_stream` override `stream` when sending.
     ///
     /// # Arguments
     ///
@@ -1,13 +1,20 @@
 //! Model options primitive type
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 use super::StopSetting;
+use crate::{Error, Result};
 
 /// Runtime options that control model behavior
 ///
-/// These options can be used to customize embedding generation.
-/// All fields are optional and will use model defaults if not specified.
+/// Covers the runtime parameters accepted by Ollama in the `options` field
+/// of chat, generate and embed requests. All fields are optional and will
+/// use model defaults if not specified.
+///
+/// Parameters without a dedicated field, such as those added by newer
+/// Ollama versions, can be passed through [`extra`](Self::extra); they are
+/// sent alongside the known fields.
 ///
 /// # Example
 ///
@@ -16,17 +23,25 @@ use super::StopSetting;
 ///
 /// let options = ModelOptions::default()
 ///     .with_temperature(0.7)
-///     .with_num_ctx(4096);
+///     .with_num_ctx(4096)
+///     .with_repeat_penalty(1.1)
+///     .with_extra("mirostat", 2);
+/// assert!(options.validate().is_ok());
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct ModelOptions {
+    /// Number of tokens from the initial prompt to keep when the context
+    /// is shifted (-1 = all)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_keep: Option<i32>,
+
     /// Random seed for reproducible outputs
     #[serde(skip_serializing_if = "Option::is_none")]
     pub seed: Option<i64>,
 
-    /// Controls randomness in generation (higher = more random)
+    /// Maximum number of tokens to generate (-1 = unlimited, -2 = fill context)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub temperature: Option<f32>,
+    pub num_predict: Option<i32>,
 
     /// Limits next token selection to the K most likely
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -40,34 +55,109 @@ pub struct ModelOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_p: Option<f32>,
 
-    /// Context length size (number of tokens)
+    /// Probability threshold for locally typical sampling (1.0 = disabled)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub num_ctx: Option<i32>,
+    pub typical_p: Option<f32>,
 
-    /// Maximum number of tokens to generate
+    /// How far back to look for repetitions (0 = disabled, -1 = num_ctx)
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub num_predict: Option<i32>,
+    pub repeat_last_n: Option<i32>,
+
+    /// Controls randomness in generation (higher = more random)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// Penalty applied to repeated tokens (1.0 = no penalty)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_penalty: Option<f32>,
+
+    /// Penalty for tokens that already appeared in the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+
+    /// Penalty scaled by how often tokens already appeared in the text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
 
     /// Stop sequences that will halt generation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stop: Option<StopSetting>,
+
+    /// Context length size (number of tokens)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_ctx: Option<i32>,
+
+    /// Number of prompt tokens processed per batch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_batch: Option<i32>,
+
+    /// Number of layers offloaded to the GPU (-1 = decided by the server)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_gpu: Option<i32>,
+
+    /// GPU used for small tensors when the model is split across GPUs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub main_gpu: Option<i32>,
+
+    /// Whether to memory-map the model file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_mmap: Option<bool>,
+
+    /// Number of CPU threads used for generation (0 = decided by the server)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_thread: Option<i32>,
+
+    /// Additional parameters sent as-is, for options without a field above
+    ///
+    /// Unknown keys in deserialized options end up here as well.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
+/// Names of the parameters with a dedicated field
+const FIELDS: &[&str] = &[
+    "num_keep",
+    "seed",
+    "num_predict",
+    "top_k",
+    "top_p",
+    "min_p",
+    "typical_p",
+    "repeat_last_n",
+    "temperature",
+    "repeat_penalty",
+    "presence_penalty",
+    "frequency_penalty",
+    "stop",
+    "num_ctx",
+    "num_batch",
+    "num_gpu",
+    "main_gpu",
+    "use_mmap",
+    "num_thread",
+];
+
 impl ModelOptions {
     /// Create empty options (all defaults)
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Set the number of prompt tokens kept on context shift
+    pub fn with_num_keep(mut self, num_keep: i32) -> Self {
+        self.num_keep = Some(num_keep);
+        self
+    }
+
     /// Set the random seed
     pub fn with_seed(mut self, seed: i64) -> Self {
         self.seed = Some(seed);
         self
     }
 
-    /// Set the temperature
-    pub fn with_temperature(mut self, temperature: f32) -> Self {
-        self.temperature = Some(temperature);
+    /// Set the max tokens to generate
+    pub fn with_num_predict(mut self, num_predict: i32) -> Self {
+        self.num_predict = Some(num_predict);
         self
     }
 
@@ -89,15 +179,39 @@ impl ModelOptions {
         self
     }
 
-    /// Set the context length
-    pub fn with_num_ctx(mut self, num_ctx: i32) -> Self {
-        self.num_ctx = Some(num_ctx);
+    /// Set the typical_p value
+    pub fn with_typical_p(mut self, typical_p: f32) -> Self {
+        self.typical_p = Some(typical_p);
         self
     }
 
-    /// Set the max tokens to generate
-    pub fn with_num_predict(mut self, num_predict: i32) -> Self {
-        self.num_predict = Some(num_predict);
+    /// Set how far back to look for repetitions
+    pub fn with_repeat_last_n(mut self, repeat_last_n: i32) -> Self {
+        self.repeat_last_n = Some(repeat_last_n);
+        self
+    }
+
+    /// Set the temperature
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Set the repeat penalty
+    pub fn with_repeat_penalty(mut self, repeat_penalty: f32) -> Self {
+        self.repeat_penalty = Some(repeat_penalty);
+        self
+    }
+
+    /// Set the presence penalty
+    pub fn with_presence_penalty(mut self, presence_penalty: f32) -> Self {
+        self.presence_penalty = Some(presence_penalty);
+        self
+    }
+
+    /// Set the frequency penalty
+    pub fn with_frequency_penalty(mut self, frequency_penalty: f32) -> Self {
+        self.frequency_penalty = Some(frequency_penalty);
         self
     }
 
@@ -107,15 +221,306 @@ impl ModelOptions {
         self
     }
 
+    /// Set the context length
+    pub fn with_num_ctx(mut self, num_ctx: i32) -> Self {
+        self.num_ctx = Some(num_ctx);
+        self
+    }
+
+    /// Set the prompt batch size
+    pub fn with_num_batch(mut self, num_batch: i32) -> Self {
+        self.num_batch = Some(num_batch);
+        self
+    }
+
+    /// Set the number of layers offloaded to the GPU
+    pub fn with_num_gpu(mut self, num_gpu: i32) -> Self {
+        self.num_gpu = Some(num_gpu);
+        self
+    }
+
+    /// Set the main GPU
+    pub fn with_main_gpu(mut self, main_gpu: i32) -> Self {
+        self.main_gpu = Some(main_gpu);
+        self
+    }
+
+    /// Set whether to memory-map the model file
+    pub fn with_use_mmap(mut self, use_mmap: bool) -> Self {
+        self.use_mmap = Some(use_mmap);
+        self
+    }
+
+    /// Set the number of CPU threads
+    pub fn with_num_thread(mut self, num_thread: i32) -> Self {
+        self.num_thread = Some(num_thread);
+        self
+    }
+
+    /// Add a parameter without a dedicated field
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Parameter name as expected by Ollama
+    /// * `value` - Parameter value
+    pub fn with_extra(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(name.into(), value.into());
+        self
+    }
+
     /// Check if any options are set
     pub fn is_empty(&self) -> bool {
-        self.seed.is_none()
-            && self.temperature.is_none()
+        self.num_keep.is_none()
+            && self.seed.is_none()
+            && self.num_predict.is_none()
             && self.top_k.is_none()
             && self.top_p.is_none()
             && self.min_p.is_none()
-            && self.num_ctx.is_none()
-            && self.num_predict.is_none()
+            && self.typical_p.is_none()
+            && self.repeat_last_n.is_none()
+            && self.temperature.is_none()
+            && self.repeat_penalty.is_none()
+            && self.presence_penalty.is_none()
+            && self.frequency_penalty.is_none()
             && self.stop.is_none()
+            && self.num_ctx.is_none()
+            && self.num_batch.is_none()
+            && self.num_gpu.is_none()
+            && self.main_gpu.is_none()
+            && self.use_mmap.is_none()
+            && self.num_thread.is_none()
+            && self.extra.is_empty()
+    }
+
+    /// Check that all set values are within the ranges Ollama accepts
+    ///
+    /// Chat, generate and embed requests are validated before they are
+    /// sent, so invalid options fail fast instead of being silently
+    /// clamped or rejected by the server.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ValidationError`] listing every invalid value, e.g.
+    /// a negative `temperature`, a `top_p` above 1, a non-finite number,
+    /// or an `extra` key that duplicates a dedicated field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ollama_oxide::ModelOptions;
+    ///
+    /// let options = ModelOptions::new().with_temperature(-0.5).with_top_p(1.5);
+    /// let error = options.validate().unwrap_err();
+    /// assert!(error.to_string().contains("temperature"));
+    /// assert!(error.to_string().contains("top_p"));
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        let mut problems = Vec::new();
+
+        let mut float = |name: &str, value: Option<f32>, range: Option<(f32, f32)>| {
+            let Some(value) = value else { return };
+            if !value.is_finite() {
+                problems.push(format!("{} must be a finite number, got {}", name, value));
+            } else if let Some((min, max)) = range.filter(|(min, max)| value < *min || value > *max)
+            {
+                let range = if max.is_finite() {
+                    format!("between {} and {}", min, max)
+                } else {
+                    format!("at least {}", min)
+                };
+                problems.push(format!("{} must be {}, got {}", name, range, value));
+            }
+        };
+        float("temperature", self.temperature, Some((0.0, f32::INFINITY)));
+        float("top_p", self.top_p, Some((0.0, 1.0)));
+        float("min_p", self.min_p, Some((0.0, 1.0)));
+        float("typical_p", self.typical_p, Some((0.0, 1.0)));
+        float(
+            "repeat_penalty",
+            self.repeat_penalty,
+            Some((0.0, f32::INFINITY)),
+        );
+        // Penalties may be negative to encourage repetition
+        float("presence_penalty", self.presence_penalty, None);
+        float("frequency_penalty", self.frequency_penalty, None);
+
+        let mut integer = |name: &str, value: Option<i32>, min: i32| {
+            if let Some(value) = value.filter(|value| *value < min) {
+                problems.push(format!("{} must be at least {}, got {}", name, min, value));
+            }
+        };
+        integer("num_keep", self.num_keep, -1);
+        integer("num_predict", self.num_predict, -2);
+        integer("top_k", self.top_k, 0);
+        integer("repeat_last_n", self.repeat_last_n, -1);
+        integer("num_ctx", self.num_ctx, 1);
+        integer("num_batch", self.num_batch, 1);
+        integer("num_gpu", self.num_gpu, -1);
+        integer("main_gpu", self.main_gpu, 0);
+        integer("num_thread", self.num_thread, 0);
+
+        for name in self
+            .extra
+            .keys()
+            .filter(|name| FIELDS.contains(&name.as_str()))
+        {
+            problems.push(format!(
+                "extra option {:?} duplicates a dedicated field, set the field instead",
+                name
+            ));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::ValidationError(problems.join("; ")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_model_options_all_fields_serialization() {
+        let options = ModelOptions::new()
+            .with_num_keep(5)
+            .with_typical_p(0.5)
+            .with_repeat_last_n(33)
+            .with_repeat_penalty(1.25)
+            .with_presence_penalty(1.5)
+            .with_frequency_penalty(1.0)
+            .with_num_batch(2)
+            .with_num_gpu(1)
+            .with_main_gpu(0)
+            .with_use_mmap(true)
+            .with_num_thread(8);
+
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "num_keep": 5,
+                "typical_p": 0.5,
+                "repeat_last_n": 33,
+                "repeat_penalty": 1.25,
+                "presence_penalty": 1.5,
+                "frequency_penalty": 1.0,
+                "num_batch": 2,
+                "num_gpu": 1,
+                "main_gpu": 0,
+                "use_mmap": true,
+                "num_thread": 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_model_options_extra_is_flattened() {
+        let options = ModelOptions::new()
+            .with_temperature(0.5)
+            .with_extra("mirostat", 2)
+            .with_extra("penalize_newline", false);
+
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["temperature"], 0.5);
+        assert_eq!(json["mirostat"], 2);
+        assert_eq!(json["penalize_newline"], false);
+        assert!(json.get("extra").is_none());
+        assert!(!options.is_empty());
+    }
+
+    #[test]
+    fn test_model_options_unknown_keys_deserialize_into_extra() {
+        let options: ModelOptions =
+            serde_json::from_value(json!({"top_k": 20, "mirostat_tau": 5.0})).unwrap();
+
+        assert_eq!(options.top_k, Some(20));
+        assert_eq!(options.extra.len(), 1);
+        assert_eq!(options.extra["mirostat_tau"], 5.0);
+    }
+
+    #[test]
+    fn test_fields_list_matches_serialized_names() {
+        let options = ModelOptions {
+            num_keep: Some(0),
+            seed: Some(0),
+            num_predict: Some(0),
+            top_k: Some(0),
+            top_p: Some(0.0),
+            min_p: Some(0.0),
+            typical_p: Some(0.0),
+            repeat_last_n: Some(0),
+            temperature: Some(0.0),
+            repeat_penalty: Some(0.0),
+            presence_penalty: Some(0.0),
+            frequency_penalty: Some(0.0),
+            stop: Some(StopSetting::single("\n")),
+            num_ctx: Some(1),
+            num_batch: Some(1),
+            num_gpu: Some(0),
+            main_gpu: Some(0),
+            use_mmap: Some(false),
+            num_thread: Some(0),
+            extra: Map::new(),
+        };
+
+        let json = serde_json::to_value(&options).unwrap();
+        let mut names: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        let mut fields = FIELDS.to_vec();
+        names.sort_unstable();
+        fields.sort_unstable();
+        assert_eq!(names, fields);
+    }
+
+    #[test]
+    fn test_validate_accepts_valid_options() {
+        let options = ModelOptions::new()
+            .with_temperature(0.0)
+            .with_top_p(1.0)
+            .with_min_p(0.05)
+            .with_num_predict(-2)
+            .with_repeat_last_n(-1)
+            .with_num_gpu(-1)
+            .with_presence_penalty(-1.0)
+            .with_extra("mirostat", 1);
+
+        assert!(options.validate().is_ok());
+        assert!(ModelOptions::new().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_reports_every_violation() {
+        let options = ModelOptions::new()
+            .with_temperature(-0.1)
+            .with_top_p(1.5)
+            .with_top_k(-1)
+            .with_num_ctx(0)
+            .with_repeat_penalty(f32::NAN);
+
+        let Err(Error::ValidationError(message)) = options.validate() else {
+            panic!("expected a validation error");
+        };
+        assert!(message.contains("temperature must be at least 0, got -0.1"));
+        assert!(message.contains("top_p must be between 0 and 1, got 1.5"));
+        assert!(message.contains("top_k must be at least 0, got -1"));
+        assert!(message.contains("num_ctx must be at least 1, got 0"));
+        assert!(message.contains("repeat_penalty must be a finite number"));
+    }
+
+    #[test]
+    fn test_validate_rejects_extra_shadowing_a_field() {
+        let options = ModelOptions::new().with_extra("temperature", 0.5);
+
+        let error = options.validate().unwrap_err();
+
+        assert!(error.to_string().contains("\"temperature\""));
     }
 }
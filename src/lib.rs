@@ -35,7 +35,7 @@
 
 mod error;
 
-pub use error::{Error, Result};
+pub use error::{ApiErrorKind, Error, Result};
 
 // ============================================================================
 // Inference Module
@@ -75,7 +75,11 @@ pub use inference::{
 pub mod http;
 
 #[cfg(feature = "http")]
-pub use http::{ClientConfig, OllamaApiAsync, OllamaApiSync, OllamaClient};
+pub use http::{
+    Auth, ClientConfig, ClientIdentity, CredentialProvider, Middleware, OllamaApiAsync,
+    OllamaApiSync, OllamaClient, RequestBody, ReqwestTransport, ResponseBody, ResponseIter,
+    ResponseStream, RetryPolicy, Transport, TransportRequest, TransportResponse,
+};
 
 // ============================================================================
 // Model Module
@@ -87,8 +91,9 @@ pub mod model;
 #[cfg(feature = "model")]
 pub use model::{
     CopyRequest, CreateRequest, CreateResponse, DeleteRequest, LicenseSetting, ListResponse,
-    ModelDetails, ModelSummary, PsResponse, PullRequest, PullResponse, PushRequest, PushResponse,
-    RunningModel, ShowModelDetails, ShowRequest, ShowResponse,
+    ModelDetails, ModelSummary, ProgressResponse, PsResponse, PullRequest, PullResponse,
+    PushRequest, PushResponse, RunningModel, ShowModelDetails, ShowRequest, ShowResponse,
+    TransferProgress,
 };
 
 // ============================================================================
@@ -102,15 +107,32 @@ pub mod tools;
 #[cfg(feature = "tools")]
 pub use tools::{ToolCall, ToolCallFunction, ToolDefinition, ToolFunction};
 
+// ============================================================================
+// Conveniences Module (requires "conveniences" feature)
+// ============================================================================
+
+#[cfg(feature = "conveniences")]
+pub mod conveniences;
+
+// ============================================================================
+// Testing Module (requires "testing" feature)
+// ============================================================================
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 // ============================================================================
 // Prelude
 // ============================================================================
 
 pub mod prelude {
-    pub use crate::{Error, Result};
+    pub use crate::{ApiErrorKind, Error, Result};
 
     #[cfg(feature = "http")]
-    pub use crate::{ClientConfig, OllamaApiAsync, OllamaApiSync, OllamaClient};
+    pub use crate::{
+        Auth, ClientConfig, ClientIdentity, CredentialProvider, OllamaApiAsync, OllamaApiSync,
+        OllamaClient, ResponseIter, ResponseStream, RetryPolicy,
+    };
 
     #[cfg(feature = "inference")]
     pub use crate::{
@@ -144,7 +166,7 @@ pub mod prelude {
     #[cfg(feature = "model")]
     pub use crate::{
         CopyRequest, CreateRequest, CreateResponse, DeleteRequest, LicenseSetting, ListResponse,
-        ModelDetails, ModelSummary, PsResponse, RunningModel, ShowModelDetails, ShowRequest,
-        ShowResponse,
+        ModelDetails, ModelSummary, ProgressResponse, PsResponse, RunningModel, ShowModelDetails,
+        ShowRequest, ShowResponse, TransferProgress,
     };
 }
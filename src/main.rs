@@ -66,6 +66,7 @@ async fn async_example_custom() -> Result<(), Box<dyn std::error::Error>> {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(10),
         max_retries: 5,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config)?;
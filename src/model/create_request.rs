@@ -1,5 +1,7 @@
 //! Create request primitive type
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -42,7 +44,15 @@ pub struct CreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quantize: Option<String>,
 
-    /// Whether to stream status updates (always false for v0.1.0)
+    /// Model files by file name, mapped to blob digests (`sha256:<hex>`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<HashMap<String, String>>,
+
+    /// LoRA adapter files by file name, mapped to blob digests
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adapters: Option<HashMap<String, String>>,
+
+    /// Whether to stream status updates (streaming methods always send true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
 }
@@ -63,7 +73,9 @@ impl CreateRequest {
             parameters: None,
             messages: None,
             quantize: None,
-            stream: Some(false), // Non-streaming for v0.1.0
+            files: None,
+            adapters: None,
+            stream: Some(false),
         }
     }
 
@@ -83,7 +95,9 @@ impl CreateRequest {
             parameters: None,
             messages: None,
             quantize: None,
-            stream: Some(false), // Non-streaming for v0.1.0
+            files: None,
+            adapters: None,
+            stream: Some(false),
         }
     }
 
@@ -137,6 +151,64 @@ impl CreateRequest {
         self.quantize = Some(quantize.into());
         self
     }
+
+    /// Add a model file that was uploaded as a blob
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - File name, e.g. `model.gguf`
+    /// * `digest` - Blob digest in the form `sha256:<hex>`
+    pub fn with_file(mut self, name: impl Into<String>, digest: impl Into<String>) -> Self {
+        self.files
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), digest.into());
+        self
+    }
+
+    /// Set all model files, replacing any added before
+    pub fn with_files<I, K, V>(mut self, files: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.files = Some(
+            files
+                .into_iter()
+                .map(|(name, digest)| (name.into(), digest.into()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Add a LoRA adapter file that was uploaded as a blob
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - File name, e.g. `adapter.gguf`
+    /// * `digest` - Blob digest in the form `sha256:<hex>`
+    pub fn with_adapter(mut self, name: impl Into<String>, digest: impl Into<String>) -> Self {
+        self.adapters
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), digest.into());
+        self
+    }
+
+    /// Set all adapter files, replacing any added before
+    pub fn with_adapters<I, K, V>(mut self, adapters: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.adapters = Some(
+            adapters
+                .into_iter()
+                .map(|(name, digest)| (name.into(), digest.into()))
+                .collect(),
+        );
+        self
+    }
 }
 
 #[cfg(test)]
@@ -246,5 +318,32 @@ mod tests {
         assert!(!json.contains("\"parameters\""));
         assert!(!json.contains("\"messages\""));
         assert!(!json.contains("\"quantize\""));
+        assert!(!json.contains("\"files\""));
+        assert!(!json.contains("\"adapters\""));
+    }
+
+    #[test]
+    fn test_create_request_with_files_and_adapters() {
+        let request = CreateRequest::new("custom")
+            .with_file("model.gguf", "sha256:aaa")
+            .with_adapter("adapter.gguf", "sha256:bbb");
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["files"], json!({"model.gguf": "sha256:aaa"}));
+        assert_eq!(json["adapters"], json!({"adapter.gguf": "sha256:bbb"}));
+    }
+
+    #[test]
+    fn test_create_request_with_files_replaces() {
+        let request = CreateRequest::new("custom")
+            .with_file("old.gguf", "sha256:000")
+            .with_files([("a.gguf", "sha256:aaa"), ("b.gguf", "sha256:bbb")])
+            .with_adapters([("lora.gguf", "sha256:ccc")]);
+
+        let files = request.files.unwrap();
+        assert_eq!(files.len(), 2);
+        assert!(!files.contains_key("old.gguf"));
+        assert_eq!(files["b.gguf"], "sha256:bbb");
+        assert_eq!(request.adapters.unwrap()["lora.gguf"], "sha256:ccc");
     }
 }
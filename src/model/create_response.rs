@@ -2,9 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
-/// Response from POST /api/create endpoint (non-streaming)
+/// Response from POST /api/create endpoint
 ///
-/// Contains the status of the model creation operation.
+/// Contains the status of the model creation operation. When streaming,
+/// one response is sent per step (e.g., "reading model metadata",
+/// "quantizing", "writing manifest") and the last one is "success".
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct CreateResponse {
     /// Status message (e.g., "success")
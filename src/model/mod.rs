@@ -2,10 +2,12 @@ mod create_request;
 mod create_response;
 mod delete_request;
 mod license_setting;
+mod progress_response;
 mod pull_request;
 mod pull_response;
 mod push_request;
 mod push_response;
+mod transfer_progress;
 
 mod copy_request;
 mod list_response;
@@ -21,10 +23,12 @@ pub use create_request::CreateRequest;
 pub use create_response::CreateResponse;
 pub use delete_request::DeleteRequest;
 pub use license_setting::LicenseSetting;
+pub use progress_response::ProgressResponse;
 pub use pull_request::PullRequest;
 pub use pull_response::PullResponse;
 pub use push_request::PushRequest;
 pub use push_response::PushResponse;
+pub use transfer_progress::TransferProgress;
 
 pub use copy_request::CopyRequest;
 pub use list_response::ListResponse;
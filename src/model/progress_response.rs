@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+
+/// Progress event streamed by POST /api/pull and POST /api/push.
+///
+/// While a model is transferred the server sends one event per status
+/// change or progress tick. Layer transfers carry the layer `digest`
+/// together with its `total` size and the number of bytes `completed`
+/// so far. The last event has the status `"success"`.
+///
+/// # JSON Examples
+///
+/// Status update:
+/// ```json
+/// {
+///   "status": "pulling manifest"
+/// }
+/// ```
+///
+/// Layer progress:
+/// ```json
+/// {
+///   "status": "pulling 2ae6f6dd7a3d",
+///   "digest": "sha256:2ae6f6dd7a3dd734790bbbf58b8909a606e0e7e97e94b7604e0aa7ae4490e6d8",
+///   "total": 2142590208,
+///   "completed": 241970
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProgressResponse {
+    /// Status message describing the current step
+    #[serde(default)]
+    pub status: Option<String>,
+
+    /// Digest of the layer being transferred
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+
+    /// Total size of the layer in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+
+    /// Number of bytes of the layer transferred so far
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<u64>,
+}
+
+impl ProgressResponse {
+    /// Get the status message.
+    ///
+    /// # Returns
+    ///
+    /// The status string if present, or None.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Get the digest of the layer being transferred.
+    ///
+    /// # Returns
+    ///
+    /// The digest string if this event reports layer progress, or None.
+    pub fn digest(&self) -> Option<&str> {
+        self.digest.as_deref()
+    }
+
+    /// Check if the operation finished successfully.
+    ///
+    /// # Returns
+    ///
+    /// `true` if status is "success", `false` otherwise.
+    pub fn is_success(&self) -> bool {
+        self.status.as_deref() == Some("success")
+    }
+
+    /// Get the layer progress as a percentage.
+    ///
+    /// # Returns
+    ///
+    /// A value between 0.0 and 100.0, or None if the event has no
+    /// layer size or the size is zero.
+    pub fn percentage(&self) -> Option<f64> {
+        let total = self.total.filter(|t| *t > 0)?;
+        let completed = self.completed.unwrap_or(0).min(total);
+        Some(completed as f64 / total as f64 * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialization_status_only() {
+        let json = r#"{"status": "pulling manifest"}"#;
+        let response: ProgressResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.status(), Some("pulling manifest"));
+        assert_eq!(response.digest(), None);
+        assert_eq!(response.total, None);
+        assert_eq!(response.completed, None);
+        assert!(!response.is_success());
+    }
+
+    #[test]
+    fn test_deserialization_layer_progress() {
+        let json = r#"{
+            "status": "pulling 2ae6f6dd7a3d",
+            "digest": "sha256:2ae6f6dd7a3d",
+            "total": 2000,
+            "completed": 500
+        }"#;
+        let response: ProgressResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.digest(), Some("sha256:2ae6f6dd7a3d"));
+        assert_eq!(response.total, Some(2000));
+        assert_eq!(response.completed, Some(500));
+        assert_eq!(response.percentage(), Some(25.0));
+    }
+
+    #[test]
+    fn test_is_success() {
+        let response = ProgressResponse {
+            status: Some("success".to_string()),
+            ..Default::default()
+        };
+        assert!(response.is_success());
+    }
+
+    #[test]
+    fn test_percentage_without_completed_is_zero() {
+        let response = ProgressResponse {
+            total: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(response.percentage(), Some(0.0));
+    }
+
+    #[test]
+    fn test_percentage_none_without_total() {
+        let response = ProgressResponse {
+            completed: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(response.percentage(), None);
+
+        let response = ProgressResponse {
+            total: Some(0),
+            completed: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(response.percentage(), None);
+    }
+
+    #[test]
+    fn test_serialization_skips_missing_fields() {
+        let response = ProgressResponse {
+            status: Some("writing manifest".to_string()),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json, serde_json::json!({"status": "writing manifest"}));
+    }
+}
@@ -30,15 +30,17 @@ pub struct PullRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub insecure: Option<bool>,
 
-    /// Stream progress updates. Default: true in Ollama, but we set false for v0.1.0
+    /// Stream progress updates. Default: true in Ollama, but we set false
+    /// unless sent through a streaming method
     #[serde(skip_serializing_if = "Option::is_none")]
-    stream: Option<bool>,
+    pub(crate) stream: Option<bool>,
 }
 
 impl PullRequest {
     /// Create a new pull request for the specified model.
     ///
     /// The request is configured with `stream: false` for non-streaming mode.
+    /// Streaming methods such as `pull_model_stream` override it when sending.
     ///
     /// # Arguments
     ///
@@ -55,7 +57,7 @@ impl PullRequest {
         Self {
             model: model.into(),
             insecure: None,
-            stream: Some(false), // v0.1.0: non-streaming only
+            stream: Some(false),
         }
     }
 
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use super::ProgressResponse;
+
+/// Overall progress of a pull or push, combined across layers.
+///
+/// Ollama reports progress per layer, so a model with several layers
+/// produces several independent byte counters. Feed every
+/// [`ProgressResponse`] from the stream into [`update`](Self::update) to
+/// keep running totals suitable for a single progress bar.
+///
+/// Layers are only known once the server starts reporting them, so the
+/// total may grow while a transfer is in progress.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use ollama_oxide::{OllamaApiAsync, OllamaClient, PullRequest, TransferProgress};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = OllamaClient::default()?;
+/// let mut stream = client.pull_model_stream(&PullRequest::new("qwen3:0.6b")).await?;
+/// let mut progress = TransferProgress::new();
+///
+/// while let Some(event) = stream.next().await {
+///     progress.update(&event?);
+///     if let Some(pct) = progress.percentage() {
+///         println!("{:.1}% ({} / {} bytes)", pct, progress.completed(), progress.total());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransferProgress {
+    /// Latest status message received
+    status: Option<String>,
+
+    /// Bytes completed and total bytes, keyed by layer digest
+    layers: HashMap<String, (u64, u64)>,
+}
+
+impl TransferProgress {
+    /// Create an empty progress tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a progress event.
+    ///
+    /// Events without a digest only update the status message. A layer
+    /// event that omits `completed` or `total` keeps the previously
+    /// reported value for that field.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - Progress event received from the stream
+    pub fn update(&mut self, event: &ProgressResponse) {
+        if let Some(status) = event.status() {
+            self.status = Some(status.to_string());
+        }
+
+        if let Some(digest) = event.digest() {
+            let layer = self.layers.entry(digest.to_string()).or_default();
+            if let Some(completed) = event.completed {
+                layer.0 = completed;
+            }
+            if let Some(total) = event.total {
+                layer.1 = total;
+            }
+        }
+    }
+
+    /// Get the latest status message.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// Get the number of bytes transferred across all layers.
+    pub fn completed(&self) -> u64 {
+        self.layers
+            .values()
+            .map(|(completed, total)| (*completed).min(*total))
+            .sum()
+    }
+
+    /// Get the total number of bytes across all layers seen so far.
+    pub fn total(&self) -> u64 {
+        self.layers.values().map(|(_, total)| total).sum()
+    }
+
+    /// Get the number of layers seen so far.
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Get the overall progress as a percentage.
+    ///
+    /// # Returns
+    ///
+    /// A value between 0.0 and 100.0, or None until a layer with a
+    /// known size has been reported.
+    pub fn percentage(&self) -> Option<f64> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+        Some(self.completed() as f64 / total as f64 * 100.0)
+    }
+
+    /// Check if the transfer finished successfully.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the latest status is "success", `false` otherwise.
+    pub fn is_success(&self) -> bool {
+        self.status.as_deref() == Some("success")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(digest: &str, completed: Option<u64>, total: Option<u64>) -> ProgressResponse {
+        ProgressResponse {
+            status: Some(format!("pulling {}", digest)),
+            digest: Some(digest.to_string()),
+            total,
+            completed,
+        }
+    }
+
+    fn status(status: &str) -> ProgressResponse {
+        ProgressResponse {
+            status: Some(status.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_new_is_empty() {
+        let progress = TransferProgress::new();
+
+        assert_eq!(progress.status(), None);
+        assert_eq!(progress.completed(), 0);
+        assert_eq!(progress.total(), 0);
+        assert_eq!(progress.layer_count(), 0);
+        assert_eq!(progress.percentage(), None);
+        assert!(!progress.is_success());
+    }
+
+    #[test]
+    fn test_combines_layers() {
+        let mut progress = TransferProgress::new();
+        progress.update(&layer("sha256:a", Some(100), Some(400)));
+        progress.update(&layer("sha256:b", Some(0), Some(600)));
+
+        assert_eq!(progress.layer_count(), 2);
+        assert_eq!(progress.completed(), 100);
+        assert_eq!(progress.total(), 1000);
+        assert_eq!(progress.percentage(), Some(10.0));
+
+        progress.update(&layer("sha256:a", Some(400), Some(400)));
+        progress.update(&layer("sha256:b", Some(100), Some(600)));
+
+        assert_eq!(progress.layer_count(), 2);
+        assert_eq!(progress.completed(), 500);
+        assert_eq!(progress.percentage(), Some(50.0));
+    }
+
+    #[test]
+    fn test_keeps_previous_values_when_fields_missing() {
+        let mut progress = TransferProgress::new();
+        progress.update(&layer("sha256:a", None, Some(200)));
+        progress.update(&layer("sha256:a", Some(50), None));
+
+        assert_eq!(progress.completed(), 50);
+        assert_eq!(progress.total(), 200);
+        assert_eq!(progress.percentage(), Some(25.0));
+    }
+
+    #[test]
+    fn test_completed_capped_at_total() {
+        let mut progress = TransferProgress::new();
+        progress.update(&layer("sha256:a", Some(300), Some(200)));
+
+        assert_eq!(progress.completed(), 200);
+        assert_eq!(progress.percentage(), Some(100.0));
+    }
+
+    #[test]
+    fn test_status_events_update_status_only() {
+        let mut progress = TransferProgress::new();
+        progress.update(&layer("sha256:a", Some(10), Some(10)));
+        progress.update(&status("verifying sha256 digest"));
+
+        assert_eq!(progress.status(), Some("verifying sha256 digest"));
+        assert_eq!(progress.layer_count(), 1);
+        assert!(!progress.is_success());
+
+        progress.update(&status("success"));
+        assert!(progress.is_success());
+    }
+}
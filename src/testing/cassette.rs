@@ -0,0 +1,181 @@
+//! Cassette file of recorded Ollama traffic
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::cassette_interaction::{CassetteInteraction, CassetteRequest};
+use super::line_diff::line_diff;
+use crate::Result;
+
+/// Recorded request and response pairs, stored as a JSON file
+///
+/// Written by a client in [`CassetteMode::Record`](super::CassetteMode::Record)
+/// and read back by one in [`CassetteMode::Replay`](super::CassetteMode::Replay).
+/// The file is plain JSON and can be inspected or edited by hand.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::testing::Cassette;
+///
+/// let cassette = Cassette::load("tests/cassettes/chat.json")?;
+/// for interaction in &cassette.interactions {
+///     println!(
+///         "{} {} -> {} in {} ms",
+///         interaction.request.method,
+///         interaction.request.path,
+///         interaction.response.status,
+///         interaction.response.elapsed_ms
+///     );
+/// }
+/// # Ok::<(), ollama_oxide::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Recorded pairs, in the order their responses completed
+    pub interactions: Vec<CassetteInteraction>,
+}
+
+impl Cassette {
+    /// Read a cassette file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a cassette
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Write the cassette as pretty-printed JSON, creating parent directories
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Describe why no interaction matches `request`
+    ///
+    /// Shows a diff against the closest recorded request for the same
+    /// endpoint, or lists the recorded endpoints when there is none.
+    pub(super) fn mismatch_report(&self, request: &CassetteRequest) -> String {
+        let endpoint = format!("{} {}", request.method, request.path);
+        let actual = pretty_body(request);
+
+        let closest = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, recorded)| {
+                recorded.request.method == request.method && recorded.request.path == request.path
+            })
+            .map(|(index, recorded)| (index, line_diff(&pretty_body(&recorded.request), &actual)))
+            .min_by_key(|(_, diff)| diff.lines().filter(|line| !line.starts_with(' ')).count());
+
+        match closest {
+            Some((index, diff)) => format!(
+                "no recorded interaction matches {}; body differs from interaction #{} (- recorded, + actual):\n{}",
+                endpoint, index, diff
+            ),
+            None => {
+                let recorded: Vec<String> = self
+                    .interactions
+                    .iter()
+                    .map(|recorded| {
+                        format!("{} {}", recorded.request.method, recorded.request.path)
+                    })
+                    .collect();
+                format!(
+                    "no recorded interaction for {}; recorded endpoints: [{}]",
+                    endpoint,
+                    recorded.join(", ")
+                )
+            }
+        }
+    }
+}
+
+fn pretty_body(request: &CassetteRequest) -> String {
+    match &request.body {
+        Some(body) => serde_json::to_string_pretty(body).unwrap_or_default(),
+        None => "(no body)".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::cassette_interaction::CassetteResponse;
+    use serde_json::{Value, json};
+
+    fn interaction(method: &str, path: &str, body: Option<Value>) -> CassetteInteraction {
+        CassetteInteraction {
+            request: CassetteRequest {
+                method: method.to_string(),
+                path: path.to_string(),
+                body,
+            },
+            response: CassetteResponse {
+                status: 200,
+                headers: Default::default(),
+                elapsed_ms: 0,
+                body: "{}".to_string(),
+                chunks: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_mismatch_report_diffs_closest_body() {
+        let cassette = Cassette {
+            interactions: vec![
+                interaction("GET", "/api/tags", None),
+                interaction(
+                    "POST",
+                    "/api/chat",
+                    Some(json!({"model": "qwen3:0.6b", "stream": false})),
+                ),
+            ],
+        };
+        let request = CassetteRequest {
+            method: "POST".to_string(),
+            path: "/api/chat".to_string(),
+            body: Some(json!({"model": "llama3.2", "stream": false})),
+        };
+
+        let report = cassette.mismatch_report(&request);
+
+        assert!(report.starts_with("no recorded interaction matches POST /api/chat"));
+        assert!(report.contains("interaction #1"));
+        assert!(report.contains("-   \"model\": \"qwen3:0.6b\","));
+        assert!(report.contains("+   \"model\": \"llama3.2\","));
+    }
+
+    #[test]
+    fn test_mismatch_report_lists_recorded_endpoints() {
+        let cassette = Cassette {
+            interactions: vec![interaction("GET", "/api/tags", None)],
+        };
+        let request = CassetteRequest {
+            method: "GET".to_string(),
+            path: "/api/ps".to_string(),
+            body: None,
+        };
+
+        assert_eq!(
+            cassette.mismatch_report(&request),
+            "no recorded interaction for GET /api/ps; recorded endpoints: [GET /api/tags]"
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! One recorded request and response pair
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A request and the response it received, as stored in a [`Cassette`](super::Cassette)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteInteraction {
+    /// Request sent by the client
+    pub request: CassetteRequest,
+
+    /// Response sent by the server
+    pub response: CassetteResponse,
+}
+
+/// A recorded request
+///
+/// Only what replay matches on is stored: request headers, including
+/// credentials, are never written to the cassette.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteRequest {
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+
+    /// Endpoint path relative to the base URL, e.g. `/api/chat`
+    pub path: String,
+
+    /// JSON body, or None for requests without one (or with a file body)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// A recorded response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteResponse {
+    /// HTTP status code
+    pub status: u16,
+
+    /// Response headers
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// Milliseconds from sending the request to receiving the headers
+    #[serde(default)]
+    pub elapsed_ms: u64,
+
+    /// Complete body of a non-streamed response
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+
+    /// Chunks of a streamed (NDJSON) response, in the order received
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chunks: Vec<CassetteChunk>,
+}
+
+/// A chunk of a streamed response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CassetteChunk {
+    /// Milliseconds from sending the request to receiving this chunk
+    pub offset_ms: u64,
+
+    /// Chunk content
+    pub data: String,
+}
+
+impl CassetteResponse {
+    /// Get the full body, joining the chunks of a streamed response
+    pub fn body_bytes(&self) -> Vec<u8> {
+        let mut body = self.body.clone().into_bytes();
+        for chunk in &self.chunks {
+            body.extend_from_slice(chunk.data.as_bytes());
+        }
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cassette_interaction_round_trip() {
+        let interaction = CassetteInteraction {
+            request: CassetteRequest {
+                method: "POST".to_string(),
+                path: "/api/pull".to_string(),
+                body: Some(json!({"model": "llama3.2", "stream": true})),
+            },
+            response: CassetteResponse {
+                status: 200,
+                headers: BTreeMap::from([(
+                    "content-type".to_string(),
+                    "application/x-ndjson".to_string(),
+                )]),
+                elapsed_ms: 3,
+                body: String::new(),
+                chunks: vec![
+                    CassetteChunk {
+                        offset_ms: 4,
+                        data: "{\"status\":\"pulling\"}\n".to_string(),
+                    },
+                    CassetteChunk {
+                        offset_ms: 90,
+                        data: "{\"status\":\"success\"}\n".to_string(),
+                    },
+                ],
+            },
+        };
+
+        let json = serde_json::to_value(&interaction).unwrap();
+        assert!(json["response"].get("body").is_none());
+
+        let parsed: CassetteInteraction = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, interaction);
+        assert_eq!(
+            parsed.response.body_bytes(),
+            b"{\"status\":\"pulling\"}\n{\"status\":\"success\"}\n"
+        );
+    }
+}
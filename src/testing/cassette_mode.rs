@@ -0,0 +1,50 @@
+//! Recording or replaying client traffic
+
+use std::path::PathBuf;
+
+/// Whether an [`OllamaClient`](crate::OllamaClient) records its traffic to
+/// a cassette file or replays it from one
+///
+/// Set with [`ClientConfig::with_cassette`](crate::ClientConfig::with_cassette).
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::testing::CassetteMode;
+/// use ollama_oxide::{ClientConfig, OllamaClient};
+///
+/// // First run, against a real server
+/// let config = ClientConfig::default()
+///     .with_cassette(CassetteMode::Record("tests/cassettes/chat.json".into()));
+/// let recording = OllamaClient::new(config)?;
+///
+/// // Later runs, with no server at all
+/// let config = ClientConfig::default()
+///     .with_cassette(CassetteMode::Replay("tests/cassettes/chat.json".into()));
+/// let replaying = OllamaClient::new(config)?;
+/// # Ok::<(), ollama_oxide::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to the server and write every exchange to the file
+    ///
+    /// The file is replaced when the client is created, and rewritten as
+    /// each response is read to the end or dropped.
+    Record(PathBuf),
+
+    /// Answer requests from the file without contacting any server
+    ///
+    /// Responses are returned at once, without their recorded timing.
+    /// Streamed responses still yield their chunks one by one.
+    ///
+    /// A request that matches no recorded exchange fails with
+    /// [`Error::CassetteMismatchError`](crate::Error::CassetteMismatchError).
+    Replay(PathBuf),
+
+    /// Like [`Replay`](Self::Replay), keeping the recorded timing
+    ///
+    /// Each response arrives after its recorded `elapsed_ms`, and streamed
+    /// responses yield their chunks at their recorded `offset_ms`. Useful
+    /// to test timeouts or progress display against realistic latency.
+    ReplayTimed(PathBuf),
+}
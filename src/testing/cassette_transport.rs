@@ -0,0 +1,544 @@
+//! Cassette recording and replay as a transport
+
+use std::collections::BTreeMap;
+use std::io::{self, Cursor, Read};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use super::cassette::Cassette;
+use super::cassette_interaction::{
+    CassetteChunk, CassetteInteraction, CassetteRequest, CassetteResponse,
+};
+use super::cassette_mode::CassetteMode;
+use crate::http::{RequestBody, ResponseBody, Transport, TransportRequest, TransportResponse};
+use crate::{Error, Result};
+
+/// Response headers not worth replaying
+const SKIPPED_HEADERS: [&str; 3] = ["connection", "date", "transfer-encoding"];
+
+/// Records the requests sent through another transport, or replays them
+/// without one
+#[derive(Debug)]
+pub(crate) struct CassetteTransport {
+    /// Transport for recorded requests; unused when replaying
+    inner: Arc<dyn Transport>,
+    mode: Arc<SessionMode>,
+}
+
+#[derive(Debug)]
+enum SessionMode {
+    Record {
+        path: PathBuf,
+        cassette: Mutex<Cassette>,
+    },
+    Replay {
+        cassette: Cassette,
+        /// Which interactions have already been replayed
+        used: Mutex<Vec<bool>>,
+        /// Whether responses wait for their recorded timing
+        timed: bool,
+    },
+}
+
+impl CassetteTransport {
+    /// Start recording to an empty cassette, or load one to replay
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cassette file cannot be written (record)
+    /// or read (replay)
+    pub(crate) fn open(mode: &CassetteMode, inner: Arc<dyn Transport>) -> Result<Self> {
+        let mode = match mode {
+            CassetteMode::Record(path) => {
+                let cassette = Cassette::default();
+                cassette.save(path)?;
+                SessionMode::Record {
+                    path: path.clone(),
+                    cassette: Mutex::new(cassette),
+                }
+            }
+            CassetteMode::Replay(path) | CassetteMode::ReplayTimed(path) => {
+                let cassette = Cassette::load(path)?;
+                let used = Mutex::new(vec![false; cassette.interactions.len()]);
+                SessionMode::Replay {
+                    cassette,
+                    used,
+                    timed: matches!(mode, CassetteMode::ReplayTimed(_)),
+                }
+            }
+        };
+
+        Ok(Self {
+            inner,
+            mode: Arc::new(mode),
+        })
+    }
+
+    /// Pass a live response through, recording its body as it is read
+    fn tee(
+        &self,
+        request: CassetteRequest,
+        started: Instant,
+        response: TransportResponse,
+    ) -> TransportResponse {
+        let mut recorder = Recorder::new(
+            &self.mode,
+            request,
+            started,
+            response.status,
+            &response.headers,
+        );
+        let body = match response.body {
+            ResponseBody::Bytes(bytes) => {
+                recorder.push(&bytes);
+                ResponseBody::Bytes(bytes)
+            }
+            ResponseBody::Stream(stream) => ResponseBody::Stream(
+                stream
+                    .inspect(move |chunk| {
+                        if let Ok(bytes) = chunk {
+                            recorder.push(bytes);
+                        }
+                    })
+                    .boxed(),
+            ),
+            ResponseBody::Reader(reader) => {
+                ResponseBody::Reader(Box::new(RecordingReader { reader, recorder }))
+            }
+        };
+
+        TransportResponse {
+            status: response.status,
+            headers: response.headers,
+            body,
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let recorded = describe(&request);
+        let started = Instant::now();
+        if let SessionMode::Replay {
+            cassette,
+            used,
+            timed,
+        } = &*self.mode
+        {
+            let recorded = replay(cassette, used, &recorded)?;
+            let started = timed.then_some(started);
+            if let Some(started) = started {
+                tokio::time::sleep(remaining(started, recorded.elapsed_ms)).await;
+            }
+            let body = if recorded.chunks.is_empty() {
+                ResponseBody::Bytes(recorded.body_bytes())
+            } else {
+                ResponseBody::Stream(replay_stream(recorded.chunks.clone(), started))
+            };
+            return replayed_response(recorded, body);
+        }
+
+        let response = self.inner.send(request).await?;
+        Ok(self.tee(recorded, started, response))
+    }
+
+    fn send_blocking(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let recorded = describe(&request);
+        let started = Instant::now();
+        if let SessionMode::Replay {
+            cassette,
+            used,
+            timed,
+        } = &*self.mode
+        {
+            let recorded = replay(cassette, used, &recorded)?;
+            let started = timed.then_some(started);
+            if let Some(started) = started {
+                std::thread::sleep(remaining(started, recorded.elapsed_ms));
+            }
+            let body = if recorded.chunks.is_empty() {
+                ResponseBody::Bytes(recorded.body_bytes())
+            } else {
+                ResponseBody::Reader(Box::new(ReplayReader {
+                    chunks: recorded.chunks.clone().into_iter(),
+                    started,
+                    chunk: Cursor::new(Vec::new()),
+                }))
+            };
+            return replayed_response(recorded, body);
+        }
+
+        let response = self.inner.send_blocking(request)?;
+        Ok(self.tee(recorded, started, response))
+    }
+}
+
+impl SessionMode {
+    /// Append a finished interaction and rewrite the cassette file
+    fn record(&self, interaction: CassetteInteraction) {
+        if let Self::Record { path, cassette } = self {
+            let mut cassette = cassette.lock().unwrap();
+            cassette.interactions.push(interaction);
+            // The path was writable when the session opened; a later
+            // failure has no caller to report to
+            let _ = cassette.save(path);
+        }
+    }
+}
+
+/// Describe a request the way it is stored and matched
+///
+/// File uploads are stored without their contents.
+fn describe(request: &TransportRequest) -> CassetteRequest {
+    CassetteRequest {
+        method: request.method.as_str().to_string(),
+        path: request.path.clone(),
+        body: match &request.body {
+            RequestBody::Json(body) => Some(body.clone()),
+            RequestBody::Empty | RequestBody::File { .. } => None,
+        },
+    }
+}
+
+/// Find the response for `request` among the recorded interactions
+///
+/// Each interaction is replayed once, in recorded order, so repeated
+/// identical requests (such as retries) get successive responses. Once
+/// all matching interactions are used, the last one is repeated.
+fn replay<'a>(
+    cassette: &'a Cassette,
+    used: &Mutex<Vec<bool>>,
+    request: &CassetteRequest,
+) -> Result<&'a CassetteResponse> {
+    let mut used = used.lock().unwrap();
+    let matching: Vec<usize> = cassette
+        .interactions
+        .iter()
+        .enumerate()
+        .filter(|(_, interaction)| interaction.request == *request)
+        .map(|(index, _)| index)
+        .collect();
+    let index = matching
+        .iter()
+        .copied()
+        .find(|&index| !used[index])
+        .or_else(|| matching.last().copied())
+        .ok_or_else(|| Error::CassetteMismatchError(cassette.mismatch_report(request)))?;
+    used[index] = true;
+    Ok(&cassette.interactions[index].response)
+}
+
+/// Build the replayed response around a body
+fn replayed_response(recorded: &CassetteResponse, body: ResponseBody) -> Result<TransportResponse> {
+    let invalid = |e: &dyn std::fmt::Display| {
+        Error::SerializationError(format!("invalid response in cassette: {}", e))
+    };
+
+    let mut response = TransportResponse::new(recorded.status, body);
+    for (name, value) in &recorded.headers {
+        response.headers.append(
+            HeaderName::from_bytes(name.as_bytes()).map_err(|e| invalid(&e))?,
+            HeaderValue::from_str(value).map_err(|e| invalid(&e))?,
+        );
+    }
+    Ok(response)
+}
+
+/// Time left until `offset_ms` after `started`
+fn remaining(started: Instant, offset_ms: u64) -> Duration {
+    (started + Duration::from_millis(offset_ms)).saturating_duration_since(Instant::now())
+}
+
+/// Async body yielding recorded chunks, at their recorded offsets from
+/// `started` if given
+fn replay_stream(
+    chunks: Vec<CassetteChunk>,
+    started: Option<Instant>,
+) -> futures::stream::BoxStream<'static, Result<Vec<u8>>> {
+    futures::stream::iter(chunks)
+        .then(move |chunk| async move {
+            if let Some(started) = started {
+                tokio::time::sleep(remaining(started, chunk.offset_ms)).await;
+            }
+            Ok(chunk.data.into_bytes())
+        })
+        .boxed()
+}
+
+/// Blocking body yielding recorded chunks, at their recorded offsets from
+/// `started` if given
+struct ReplayReader {
+    chunks: std::vec::IntoIter<CassetteChunk>,
+    started: Option<Instant>,
+    /// Unread rest of the current chunk
+    chunk: Cursor<Vec<u8>>,
+}
+
+impl Read for ReplayReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let n = self.chunk.read(buf)?;
+            if n > 0 || buf.is_empty() {
+                return Ok(n);
+            }
+            let Some(chunk) = self.chunks.next() else {
+                return Ok(0);
+            };
+            if let Some(started) = self.started {
+                std::thread::sleep(remaining(started, chunk.offset_ms));
+            }
+            self.chunk = Cursor::new(chunk.data.into_bytes());
+        }
+    }
+}
+
+/// Blocking body that records what is read from it
+struct RecordingReader {
+    reader: Box<dyn Read + Send>,
+    recorder: Recorder,
+}
+
+impl Read for RecordingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        self.recorder.push(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Collects a response body as it is read
+///
+/// The interaction is recorded when the recorder is dropped, that is once
+/// the body has been read to the end or the response discarded.
+struct Recorder {
+    mode: Arc<SessionMode>,
+    request: Option<CassetteRequest>,
+    response: CassetteResponse,
+    started: Instant,
+    streamed: bool,
+    /// Body read so far; for a streamed response, only the part after
+    /// the last complete line
+    body: Vec<u8>,
+}
+
+impl Recorder {
+    fn new(
+        mode: &Arc<SessionMode>,
+        request: CassetteRequest,
+        started: Instant,
+        status: u16,
+        headers: &HeaderMap,
+    ) -> Self {
+        let headers: BTreeMap<String, String> = headers
+            .iter()
+            .filter(|(name, _)| !SKIPPED_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let streamed = headers
+            .get("content-type")
+            .is_some_and(|value| value.starts_with("application/x-ndjson"));
+
+        Self {
+            mode: Arc::clone(mode),
+            request: Some(request),
+            response: CassetteResponse {
+                status,
+                headers,
+                elapsed_ms: started.elapsed().as_millis() as u64,
+                body: String::new(),
+                chunks: Vec::new(),
+            },
+            started,
+            streamed,
+            body: Vec::new(),
+        }
+    }
+
+    /// Add bytes read from the body
+    ///
+    /// Streamed bodies are recorded as a chunk per read, cut back to the
+    /// last newline: a read can end inside a multi-byte character, which
+    /// would not survive being stored as text.
+    fn push(&mut self, bytes: &[u8]) {
+        self.body.extend_from_slice(bytes);
+        if !self.streamed {
+            return;
+        }
+        if let Some(end) = self.body.iter().rposition(|&byte| byte == b'\n') {
+            let lines: Vec<u8> = self.body.drain(..=end).collect();
+            self.push_chunk(&lines);
+        }
+    }
+
+    fn push_chunk(&mut self, bytes: &[u8]) {
+        self.response.chunks.push(CassetteChunk {
+            offset_ms: self.started.elapsed().as_millis() as u64,
+            data: String::from_utf8_lossy(bytes).into_owned(),
+        });
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let Some(request) = self.request.take() else {
+            return;
+        };
+        // A stream cut off mid-line keeps its unterminated tail
+        if self.streamed && !self.body.is_empty() {
+            let tail = std::mem::take(&mut self.body);
+            self.push_chunk(&tail);
+        }
+        let mut response = std::mem::replace(
+            &mut self.response,
+            CassetteResponse {
+                status: 0,
+                headers: BTreeMap::new(),
+                elapsed_ms: 0,
+                body: String::new(),
+                chunks: Vec::new(),
+            },
+        );
+        response.body = String::from_utf8_lossy(&self.body).into_owned();
+        self.mode.record(CassetteInteraction { request, response });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Method;
+    use serde_json::json;
+
+    fn response(status: u16, body: &str) -> CassetteResponse {
+        CassetteResponse {
+            status,
+            headers: BTreeMap::from([("content-type".to_string(), "application/json".to_string())]),
+            elapsed_ms: 0,
+            body: body.to_string(),
+            chunks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_describe_keeps_json_body_only() {
+        let chat = TransportRequest::new(Method::POST, "/api/chat")
+            .with_body(RequestBody::Json(json!({"model": "m", "stream": false})));
+        let upload = TransportRequest::new(Method::POST, "/api/blobs/sha256:ab").with_body(
+            RequestBody::File {
+                path: PathBuf::from("model.gguf"),
+                len: 3,
+            },
+        );
+
+        let chat = describe(&chat);
+
+        assert_eq!(chat.method, "POST");
+        assert_eq!(chat.path, "/api/chat");
+        assert_eq!(chat.body, Some(json!({"model": "m", "stream": false})));
+        assert_eq!(describe(&upload).body, None);
+    }
+
+    #[test]
+    fn test_replay_serves_matches_in_order_then_repeats_last() {
+        let request = CassetteRequest {
+            method: "POST".to_string(),
+            path: "/api/chat".to_string(),
+            body: Some(json!({"model": "m"})),
+        };
+        let cassette = Cassette {
+            interactions: vec![
+                CassetteInteraction {
+                    request: request.clone(),
+                    response: response(503, ""),
+                },
+                CassetteInteraction {
+                    request: request.clone(),
+                    response: response(200, "{}"),
+                },
+            ],
+        };
+        let used = Mutex::new(vec![false; 2]);
+
+        let statuses: Vec<u16> = (0..3)
+            .map(|_| replay(&cassette, &used, &request).unwrap().status)
+            .collect();
+
+        assert_eq!(statuses, vec![503, 200, 200]);
+    }
+
+    #[test]
+    fn test_record_stream_keeps_characters_split_across_reads() {
+        let path =
+            std::env::temp_dir().join(format!("ollama-oxide-recorder-{}.json", std::process::id()));
+        let mode = Arc::new(SessionMode::Record {
+            path: path.clone(),
+            cassette: Mutex::new(Cassette::default()),
+        });
+        let headers = HeaderMap::from_iter([(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/x-ndjson"),
+        )]);
+        let request = describe(&TransportRequest::new(Method::POST, "/api/generate"));
+        let line = "{\"response\":\"café\"}\n".as_bytes();
+        // Split between the two bytes of "é"
+        let split = line.iter().position(|&byte| byte == 0xC3).unwrap() + 1;
+
+        let mut recorder = Recorder::new(&mode, request, Instant::now(), 200, &headers);
+        recorder.push(&line[..split]);
+        recorder.push(&line[split..]);
+        recorder.push(b"{\"done\":");
+        drop(recorder);
+
+        let SessionMode::Record { cassette, .. } = &*mode else {
+            unreachable!();
+        };
+        let response = cassette.lock().unwrap().interactions[0].response.clone();
+        let _ = std::fs::remove_file(path);
+        let chunks: Vec<&str> = response.chunks.iter().map(|c| c.data.as_str()).collect();
+        assert_eq!(chunks, ["{\"response\":\"café\"}\n", "{\"done\":"]);
+    }
+
+    #[test]
+    fn test_replay_reader_waits_for_chunk_offsets() {
+        let chunk = |offset_ms, data: &str| CassetteChunk {
+            offset_ms,
+            data: data.to_string(),
+        };
+        let started = Instant::now();
+        let mut reader = ReplayReader {
+            chunks: vec![chunk(0, "a\n"), chunk(60, "b\n")].into_iter(),
+            started: Some(started),
+            chunk: Cursor::new(Vec::new()),
+        };
+        let mut buf = [0u8; 16];
+
+        let first = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..first], b"a\n");
+        assert!(started.elapsed() < Duration::from_millis(60));
+        let second = reader.read(&mut buf).unwrap();
+        assert_eq!(&buf[..second], b"b\n");
+        assert!(started.elapsed() >= Duration::from_millis(60));
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_replay_mismatch_is_an_error() {
+        let cassette = Cassette::default();
+        let used = Mutex::new(Vec::new());
+        let request = CassetteRequest {
+            method: "GET".to_string(),
+            path: "/api/tags".to_string(),
+            body: None,
+        };
+
+        let result = replay(&cassette, &used, &request);
+
+        assert!(matches!(result, Err(Error::CassetteMismatchError(_))));
+    }
+}
@@ -0,0 +1,223 @@
+//! Minimal HTTP/1.1 reading and writing for `MockOllama`
+//!
+//! Only what `OllamaClient` sends is understood: one request per
+//! connection, with a `Content-Length` or chunked body.
+
+use std::io::{self, BufRead, Write};
+use std::thread;
+
+use super::mock_response::{MockBody, MockResponse};
+use super::recorded_request::RecordedRequest;
+
+/// Read one request, or `None` if the connection closed first
+pub(super) fn read_request(reader: &mut impl BufRead) -> io::Result<Option<RecordedRequest>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(invalid_data(format!("malformed request line: {:?}", line)));
+    };
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), Some(query.to_string())),
+        None => (target.to_string(), None),
+    };
+    let method = method.to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+        }
+    }
+
+    let mut request = RecordedRequest {
+        method,
+        path,
+        query,
+        headers,
+        body: Vec::new(),
+    };
+    request.body = if request
+        .header("transfer-encoding")
+        .is_some_and(|value| value.eq_ignore_ascii_case("chunked"))
+    {
+        read_chunked_body(reader)?
+    } else {
+        let length = match request.header("content-length") {
+            Some(value) => value
+                .parse()
+                .map_err(|_| invalid_data(format!("invalid Content-Length: {}", value)))?,
+            None => 0,
+        };
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        body
+    };
+
+    Ok(Some(request))
+}
+
+/// Read a `Transfer-Encoding: chunked` body
+fn read_chunked_body(reader: &mut impl BufRead) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let size = line.trim_end().split(';').next().unwrap_or_default();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|_| invalid_data(format!("invalid chunk size: {:?}", line)))?;
+
+        if size == 0 {
+            // Skip trailers up to the final empty line
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+                    return Ok(body);
+                }
+            }
+        }
+
+        let start = body.len();
+        body.resize(start + size, 0);
+        reader.read_exact(&mut body[start..])?;
+        let mut crlf = [0; 2];
+        reader.read_exact(&mut crlf)?;
+    }
+}
+
+/// Write a scripted response, applying its delays
+///
+/// Every response closes the connection, so clients never reuse one
+/// the server is about to drop.
+pub(super) fn write_response(writer: &mut impl Write, response: &MockResponse) -> io::Result<()> {
+    thread::sleep(response.delay);
+
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\nConnection: close\r\n",
+        response.status,
+        reason_phrase(response.status)
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+
+    match &response.body {
+        MockBody::Full { content_type, data } => {
+            head.push_str(&format!(
+                "Content-Type: {}\r\nContent-Length: {}\r\n\r\n",
+                content_type,
+                data.len()
+            ));
+            writer.write_all(head.as_bytes())?;
+            writer.write_all(data)?;
+        }
+        MockBody::Stream(lines) => {
+            head.push_str(
+                "Content-Type: application/x-ndjson\r\nTransfer-Encoding: chunked\r\n\r\n",
+            );
+            writer.write_all(head.as_bytes())?;
+            writer.flush()?;
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    thread::sleep(response.chunk_delay);
+                }
+                write!(writer, "{:x}\r\n{}\n\r\n", line.len() + 1, line)?;
+                writer.flush()?;
+            }
+            writer.write_all(b"0\r\n\r\n")?;
+        }
+        MockBody::Disconnect => return Ok(()),
+    }
+
+    writer.flush()
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        204 => "No Content",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        408 => "Request Timeout",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_read_request_with_content_length() {
+        let raw = b"POST /api/chat?x=1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 2\r\n\r\n{}";
+
+        let request = read_request(&mut &raw[..]).unwrap().unwrap();
+
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/api/chat");
+        assert_eq!(request.query.as_deref(), Some("x=1"));
+        assert_eq!(request.header("Host"), Some("localhost"));
+        assert_eq!(request.body, b"{}");
+    }
+
+    #[test]
+    fn test_read_request_with_chunked_body() {
+        let raw = b"POST /api/blobs/sha256:ab HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n\
+                    3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n";
+
+        let request = read_request(&mut &raw[..]).unwrap().unwrap();
+
+        assert_eq!(request.path, "/api/blobs/sha256:ab");
+        assert_eq!(request.body, b"abcde");
+    }
+
+    #[test]
+    fn test_read_request_on_closed_connection() {
+        assert!(read_request(&mut &b""[..]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_write_streamed_response() {
+        let response = MockResponse::stream([json!({"done": false}), json!({"done": true})]);
+        let mut out = Vec::new();
+
+        write_response(&mut out, &response).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(out.contains("Transfer-Encoding: chunked\r\n"));
+        assert!(out.ends_with("f\r\n{\"done\":false}\n\r\ne\r\n{\"done\":true}\n\r\n0\r\n\r\n"));
+    }
+
+    #[test]
+    fn test_write_disconnect_sends_nothing() {
+        let mut out = Vec::new();
+
+        write_response(&mut out, &MockResponse::disconnect()).unwrap();
+
+        assert!(out.is_empty());
+    }
+}
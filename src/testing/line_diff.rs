@@ -0,0 +1,86 @@
+//! Line-based diff for readable mismatch reports
+
+/// Unchanged lines kept around each change
+const CONTEXT_LINES: usize = 2;
+
+/// Diff two texts line by line
+///
+/// Removed lines start with `-`, added lines with `+` and unchanged ones
+/// with a space. Unchanged runs are cut to a few lines of context around
+/// each change, with `...` marking the gaps.
+pub(super) fn line_diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] = longest common subsequence of expected[i..] and actual[j..]
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push((' ', expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', expected[i]));
+            i += 1;
+        } else {
+            lines.push(('+', actual[j]));
+            j += 1;
+        }
+    }
+
+    let near_change = |index: usize| {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(lines.len());
+        lines[start..end].iter().any(|(tag, _)| *tag != ' ')
+    };
+
+    let mut out = Vec::new();
+    let mut skipped = false;
+    for (index, (tag, line)) in lines.iter().enumerate() {
+        if near_change(index) {
+            out.push(format!("{} {}", tag, line));
+            skipped = false;
+        } else if !skipped {
+            out.push("  ...".to_string());
+            skipped = true;
+        }
+    }
+    out.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_diff_marks_changes() {
+        let diff = line_diff("a\nb\nc", "a\nx\nc");
+
+        assert_eq!(diff, "  a\n- b\n+ x\n  c");
+    }
+
+    #[test]
+    fn test_line_diff_elides_distant_context() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8";
+        let actual = "1\n2\n3\n4\n5\n6\n7\nX";
+
+        assert_eq!(line_diff(expected, actual), "  ...\n  6\n  7\n- 8\n+ X");
+    }
+
+    #[test]
+    fn test_line_diff_equal_texts() {
+        assert_eq!(line_diff("a", "a"), "  ...");
+    }
+}
@@ -0,0 +1,457 @@
+//! In-process mock Ollama server
+
+use std::collections::VecDeque;
+use std::io::{self, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use super::http_exchange::{read_request, write_response};
+use super::mock_response::MockResponse;
+use super::recorded_request::RecordedRequest;
+use crate::http::endpoints::Endpoints;
+use crate::{OllamaClient, Result};
+
+/// A mock Ollama server bound to a local port or Unix socket
+///
+/// Responses are scripted per method and path. Scripting the same route
+/// several times queues the responses: each request takes the next one,
+/// and the last one keeps answering once the queue is down to it, which
+/// makes retry scenarios (`503`, then `200`) easy to write. Routes match
+/// their exact path or any path below it, so `/api/blobs` also answers
+/// `/api/blobs/sha256:...`. Requests without a scripted response get a
+/// `501` error.
+///
+/// Every request is recorded, scripted or not, and can be inspected with
+/// [`requests`](Self::requests).
+///
+/// The server runs on its own threads, so it serves both async and
+/// blocking clients, from any runtime or none. It stops when dropped.
+///
+/// # Examples
+///
+/// ```
+/// use ollama_oxide::testing::{MockOllama, MockResponse};
+/// use ollama_oxide::{OllamaApiSync, OllamaClient};
+/// use serde_json::json;
+///
+/// # fn main() -> ollama_oxide::Result<()> {
+/// let server = MockOllama::start()?;
+/// server.on_version(MockResponse::json(json!({"version": "0.12.6"})));
+///
+/// let client = OllamaClient::with_base_url(server.url())?;
+/// let version = client.version_blocking()?;
+///
+/// assert_eq!(version.version, "0.12.6");
+/// assert_eq!(server.requests()[0].path, "/api/version");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MockOllama {
+    bound: Bound,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Arc<AtomicBool>,
+    acceptor: Option<JoinHandle<()>>,
+}
+
+/// Where the server listens
+#[derive(Debug)]
+enum Bound {
+    Tcp(SocketAddr),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+/// Routes and recorded requests, shared with the connection threads
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Vec<Route>,
+    requests: Vec<RecordedRequest>,
+}
+
+/// Queued responses for one method and path
+#[derive(Debug)]
+struct Route {
+    method: String,
+    path: String,
+    responses: VecDeque<MockResponse>,
+}
+
+impl MockOllama {
+    /// Start a server on a free port of `127.0.0.1`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no local port can be bound
+    pub fn start() -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let bound = Bound::Tcp(listener.local_addr()?);
+        Ok(Self::serve_on(bound, move |state, shutdown| {
+            accept(listener.incoming(), &state, &shutdown)
+        }))
+    }
+
+    /// Start a server on a Unix domain socket at `path`
+    ///
+    /// The socket file is removed when the server is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket cannot be bound, for example because
+    /// the file already exists
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ollama_oxide::testing::{MockOllama, MockResponse};
+    /// use ollama_oxide::{OllamaApiSync, OllamaClient};
+    /// use serde_json::json;
+    ///
+    /// # fn main() -> ollama_oxide::Result<()> {
+    /// let path = std::env::temp_dir().join(format!("ollama-doc-{}.sock", std::process::id()));
+    /// let server = MockOllama::start_unix(&path)?;
+    /// server.on_version(MockResponse::json(json!({"version": "0.12.6"})));
+    ///
+    /// let client = OllamaClient::with_base_url(server.url())?;
+    /// assert_eq!(client.version_blocking()?.version, "0.12.6");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(unix)]
+    pub fn start_unix(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let listener = UnixListener::bind(path)?;
+        let bound = Bound::Unix(path.to_path_buf());
+        Ok(Self::serve_on(bound, move |state, shutdown| {
+            accept(listener.incoming(), &state, &shutdown)
+        }))
+    }
+
+    /// Run `acceptor` on its own thread with fresh state
+    fn serve_on<F>(bound: Bound, acceptor: F) -> Self
+    where
+        F: FnOnce(Arc<Mutex<MockState>>, Arc<AtomicBool>) + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let acceptor = {
+            let state = Arc::clone(&state);
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || acceptor(state, shutdown))
+        };
+
+        Self {
+            bound,
+            state,
+            shutdown,
+            acceptor: Some(acceptor),
+        }
+    }
+
+    /// Get the base URL to pass to [`OllamaClient::with_base_url`]
+    ///
+    /// This is `http://127.0.0.1:<port>`, or `unix://<path>` for a server
+    /// started with [`start_unix`](Self::start_unix).
+    pub fn url(&self) -> String {
+        match &self.bound {
+            Bound::Tcp(address) => format!("http://{}", address),
+            #[cfg(unix)]
+            Bound::Unix(path) => format!("unix://{}", path.display()),
+        }
+    }
+
+    /// Get the socket address the server listens on
+    ///
+    /// Returns `None` for a server on a Unix socket.
+    pub fn address(&self) -> Option<SocketAddr> {
+        match &self.bound {
+            Bound::Tcp(address) => Some(*address),
+            #[cfg(unix)]
+            Bound::Unix(_) => None,
+        }
+    }
+
+    /// Create a client with the default configuration pointing at this server
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be built
+    pub fn client(&self) -> Result<OllamaClient> {
+        OllamaClient::with_base_url(self.url())
+    }
+
+    /// Queue a response for `method` requests to `path`
+    pub fn mock(&self, method: &str, path: &str, response: MockResponse) -> &Self {
+        let mut state = self.state.lock().unwrap();
+        let method = method.to_ascii_uppercase();
+        match state
+            .routes
+            .iter_mut()
+            .find(|route| route.method == method && route.path == path)
+        {
+            Some(route) => route.responses.push_back(response),
+            None => state.routes.push(Route {
+                method,
+                path: path.to_string(),
+                responses: VecDeque::from([response]),
+            }),
+        }
+        self
+    }
+
+    /// Queue a response for `GET /api/version`
+    pub fn on_version(&self, response: MockResponse) -> &Self {
+        self.mock("GET", Endpoints::VERSION, response)
+    }
+
+    /// Queue a response for `POST /api/generate`
+    pub fn on_generate(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::GENERATE, response)
+    }
+
+    /// Queue a response for `POST /api/chat`
+    pub fn on_chat(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::CHAT, response)
+    }
+
+    /// Queue a response for `POST /api/embed`
+    pub fn on_embed(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::EMBED, response)
+    }
+
+    /// Queue a response for `GET /api/tags`
+    pub fn on_tags(&self, response: MockResponse) -> &Self {
+        self.mock("GET", Endpoints::TAGS, response)
+    }
+
+    /// Queue a response for `GET /api/ps`
+    pub fn on_ps(&self, response: MockResponse) -> &Self {
+        self.mock("GET", Endpoints::PS, response)
+    }
+
+    /// Queue a response for `POST /api/show`
+    pub fn on_show(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::SHOW, response)
+    }
+
+    /// Queue a response for `POST /api/create`
+    pub fn on_create(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::CREATE, response)
+    }
+
+    /// Queue a response for `POST /api/copy`
+    pub fn on_copy(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::COPY, response)
+    }
+
+    /// Queue a response for `POST /api/pull`
+    pub fn on_pull(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::PULL, response)
+    }
+
+    /// Queue a response for `POST /api/push`
+    pub fn on_push(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::PUSH, response)
+    }
+
+    /// Queue a response for `DELETE /api/delete`
+    pub fn on_delete(&self, response: MockResponse) -> &Self {
+        self.mock("DELETE", Endpoints::DELETE, response)
+    }
+
+    /// Queue a response for `HEAD /api/blobs/:digest` (blob existence check)
+    pub fn on_blob_check(&self, response: MockResponse) -> &Self {
+        self.mock("HEAD", Endpoints::BLOBS, response)
+    }
+
+    /// Queue a response for `POST /api/blobs/:digest` (blob upload)
+    pub fn on_blob_upload(&self, response: MockResponse) -> &Self {
+        self.mock("POST", Endpoints::BLOBS, response)
+    }
+
+    /// Get every request received so far, in order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    /// Get the requests received for `path`, in order
+    pub fn requests_to(&self, path: &str) -> Vec<RecordedRequest> {
+        self.state
+            .lock()
+            .unwrap()
+            .requests
+            .iter()
+            .filter(|request| request.path == path)
+            .cloned()
+            .collect()
+    }
+
+    /// Get the most recent request
+    pub fn last_request(&self) -> Option<RecordedRequest> {
+        self.state.lock().unwrap().requests.last().cloned()
+    }
+
+    /// Remove all scripted responses and recorded requests
+    pub fn reset(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.routes.clear();
+        state.requests.clear();
+    }
+}
+
+impl Drop for MockOllama {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the acceptor so it sees the flag
+        match &self.bound {
+            Bound::Tcp(address) => drop(TcpStream::connect(address)),
+            #[cfg(unix)]
+            Bound::Unix(path) => drop(UnixStream::connect(path)),
+        }
+        if let Some(acceptor) = self.acceptor.take() {
+            let _ = acceptor.join();
+        }
+        #[cfg(unix)]
+        if let Bound::Unix(path) = &self.bound {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+impl MockState {
+    /// Record a request and pick its response
+    fn respond(&mut self, request: RecordedRequest) -> MockResponse {
+        let response = self
+            .routes
+            .iter_mut()
+            .find(|route| route.matches(&request))
+            .map(Route::next_response)
+            .unwrap_or_else(|| {
+                MockResponse::error(
+                    501,
+                    format!(
+                        "MockOllama: no response scripted for {} {}",
+                        request.method, request.path
+                    ),
+                )
+            });
+        self.requests.push(request);
+        response
+    }
+}
+
+impl Route {
+    fn matches(&self, request: &RecordedRequest) -> bool {
+        self.method == request.method
+            && request
+                .path
+                .strip_prefix(&self.path)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+
+    /// Take the next queued response, keeping the last one
+    fn next_response(&mut self) -> MockResponse {
+        if self.responses.len() > 1 {
+            self.responses.pop_front().unwrap()
+        } else {
+            self.responses[0].clone()
+        }
+    }
+}
+
+/// Accept connections until shutdown, answering each on its own thread
+fn accept<S>(
+    incoming: impl Iterator<Item = io::Result<S>>,
+    state: &Arc<Mutex<MockState>>,
+    shutdown: &AtomicBool,
+) where
+    S: Send + 'static,
+    for<'a> &'a S: Read + Write,
+{
+    for stream in incoming {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        if let Ok(stream) = stream {
+            let state = Arc::clone(state);
+            thread::spawn(move || serve(stream, &state));
+        }
+    }
+}
+
+/// Answer one connection
+fn serve<S>(stream: S, state: &Mutex<MockState>)
+where
+    for<'a> &'a S: Read + Write,
+{
+    let mut reader = BufReader::new(&stream);
+    let Ok(Some(request)) = read_request(&mut reader) else {
+        return;
+    };
+
+    let response = state.lock().unwrap().respond(request);
+    let _ = write_response(&mut &stream, &response);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(method: &str, path: &str) -> RecordedRequest {
+        RecordedRequest {
+            method: method.to_string(),
+            path: path.to_string(),
+            query: None,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_route_matches_path_and_subpaths() {
+        let route = Route {
+            method: "HEAD".to_string(),
+            path: Endpoints::BLOBS.to_string(),
+            responses: VecDeque::from([MockResponse::empty(200)]),
+        };
+
+        assert!(route.matches(&request("HEAD", "/api/blobs")));
+        assert!(route.matches(&request("HEAD", "/api/blobs/sha256:ab")));
+        assert!(!route.matches(&request("POST", "/api/blobs/sha256:ab")));
+        assert!(!route.matches(&request("HEAD", "/api/blobsx")));
+    }
+
+    #[test]
+    fn test_state_serves_queued_responses_then_repeats_last() {
+        let mut state = MockState::default();
+        state.routes.push(Route {
+            method: "POST".to_string(),
+            path: Endpoints::CHAT.to_string(),
+            responses: VecDeque::from([MockResponse::error(503, "busy"), MockResponse::empty(200)]),
+        });
+
+        let statuses: Vec<u16> = (0..3)
+            .map(|_| state.respond(request("POST", "/api/chat")).status())
+            .collect();
+
+        assert_eq!(statuses, vec![503, 200, 200]);
+        assert_eq!(state.requests.len(), 3);
+    }
+
+    #[test]
+    fn test_state_unscripted_request_gets_501() {
+        let mut state = MockState::default();
+
+        let response = state.respond(request("GET", "/api/tags"));
+
+        assert_eq!(response.status(), 501);
+        assert_eq!(state.requests[0].path, "/api/tags");
+    }
+}
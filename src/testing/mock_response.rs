@@ -0,0 +1,212 @@
+//! Scripted response served by `MockOllama`
+
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::json;
+
+/// A scripted response for a [`MockOllama`](super::MockOllama) route
+///
+/// Bodies are either a single JSON document, an NDJSON stream of chunks
+/// (as sent by the streaming `chat`, `generate`, `create`, `pull` and
+/// `push` endpoints), or empty. Latency and failures are injected with
+/// [`with_delay`](Self::with_delay), [`with_chunk_delay`](Self::with_chunk_delay),
+/// [`error`](Self::error) and [`disconnect`](Self::disconnect).
+///
+/// # Examples
+///
+/// ```
+/// use ollama_oxide::testing::MockResponse;
+/// use serde_json::json;
+/// use std::time::Duration;
+///
+/// let reply = MockResponse::json(json!({"version": "0.12.6"}));
+///
+/// let stream = MockResponse::stream([
+///     json!({"message": {"role": "assistant", "content": "Hel"}, "done": false}),
+///     json!({"message": {"role": "assistant", "content": "lo"}, "done": true}),
+/// ])
+/// .with_chunk_delay(Duration::from_millis(20));
+///
+/// let overloaded = MockResponse::error(503, "server busy").with_header("Retry-After", "1");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    pub(super) status: u16,
+    pub(super) headers: Vec<(String, String)>,
+    pub(super) body: MockBody,
+    pub(super) delay: Duration,
+    pub(super) chunk_delay: Duration,
+}
+
+/// Body of a [`MockResponse`]
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum MockBody {
+    /// A complete body with its content type
+    Full {
+        content_type: &'static str,
+        data: Vec<u8>,
+    },
+    /// NDJSON lines sent as separate chunks
+    Stream(Vec<String>),
+    /// Close the connection without answering
+    Disconnect,
+}
+
+impl MockResponse {
+    /// Respond `200 OK` with `body` serialized as JSON
+    ///
+    /// # Panics
+    ///
+    /// Panics if `body` cannot be serialized to JSON.
+    pub fn json(body: impl Serialize) -> Self {
+        let data = serde_json::to_vec(&body).expect("mock response body must serialize to JSON");
+        Self::with_body(
+            200,
+            MockBody::Full {
+                content_type: "application/json",
+                data,
+            },
+        )
+    }
+
+    /// Respond `200 OK` with an NDJSON stream, one line per chunk
+    ///
+    /// Each chunk is flushed separately, so clients see them arrive one by
+    /// one; see [`with_chunk_delay`](Self::with_chunk_delay).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a chunk cannot be serialized to JSON.
+    pub fn stream<I, T>(chunks: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Serialize,
+    {
+        let lines = chunks
+            .into_iter()
+            .map(|chunk| {
+                serde_json::to_string(&chunk).expect("mock stream chunk must serialize to JSON")
+            })
+            .collect();
+        Self::with_body(200, MockBody::Stream(lines))
+    }
+
+    /// Respond with `status` and an Ollama error body (`{"error": message}`)
+    pub fn error(status: u16, message: impl Into<String>) -> Self {
+        Self::json(json!({ "error": message.into() })).with_status(status)
+    }
+
+    /// Respond with `status` and no body
+    ///
+    /// Matches endpoints such as `copy`, `delete` or the blob check, which
+    /// answer with a bare status code.
+    pub fn empty(status: u16) -> Self {
+        Self::with_body(
+            status,
+            MockBody::Full {
+                content_type: "text/plain",
+                data: Vec::new(),
+            },
+        )
+    }
+
+    /// Close the connection without sending a response
+    ///
+    /// Simulates a network failure: the client sees a connection error.
+    pub fn disconnect() -> Self {
+        Self::with_body(200, MockBody::Disconnect)
+    }
+
+    /// Set the HTTP status code
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Add a response header
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Wait before sending the response
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Wait between the chunks of a streamed response
+    pub fn with_chunk_delay(mut self, delay: Duration) -> Self {
+        self.chunk_delay = delay;
+        self
+    }
+
+    /// Get the HTTP status code
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    fn with_body(status: u16, body: MockBody) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+            delay: Duration::ZERO,
+            chunk_delay: Duration::ZERO,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_response_json() {
+        let response = MockResponse::json(json!({"version": "0.12.6"}));
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(
+            response.body,
+            MockBody::Full {
+                content_type: "application/json",
+                data: br#"{"version":"0.12.6"}"#.to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_mock_response_stream() {
+        let response = MockResponse::stream([json!({"done": false}), json!({"done": true})]);
+
+        assert_eq!(
+            response.body,
+            MockBody::Stream(vec![
+                r#"{"done":false}"#.to_string(),
+                r#"{"done":true}"#.to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_mock_response_error() {
+        let response = MockResponse::error(404, "model not found")
+            .with_header("X-Test", "1")
+            .with_delay(Duration::from_millis(5));
+
+        assert_eq!(response.status(), 404);
+        assert_eq!(
+            response.headers,
+            vec![("X-Test".to_string(), "1".to_string())]
+        );
+        assert_eq!(response.delay, Duration::from_millis(5));
+        assert_eq!(
+            response.body,
+            MockBody::Full {
+                content_type: "application/json",
+                data: br#"{"error":"model not found"}"#.to_vec(),
+            }
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Test helpers for code built on `OllamaClient` (requires `testing` feature)
+//!
+//! [`MockOllama`] is an Ollama server running in-process on a local port
+//! or Unix socket. Script its responses, point a client at
+//! [`MockOllama::url`], then assert on the [`RecordedRequest`]s it received.
+//!
+//! A client configured with a [`CassetteMode`] records its traffic with a
+//! real server to a [`Cassette`] file, or replays it with no server at all.
+//!
+//! # Components
+//!
+//! - [`MockOllama`] - Mock server with scripted routes and request recording
+//! - [`MockResponse`] - JSON, NDJSON streaming, error or dropped-connection response
+//! - [`RecordedRequest`] - A request received by the server
+//! - [`Cassette`] - Recorded request and response pairs, stored as JSON
+//! - [`CassetteMode`] - Record to or replay from a cassette file
+//!
+//! # Examples
+//!
+//! ```
+//! use ollama_oxide::testing::{MockOllama, MockResponse};
+//! use ollama_oxide::{ChatMessage, ChatRequest, OllamaApiAsync, OllamaClient};
+//! use serde_json::json;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> ollama_oxide::Result<()> {
+//! let server = MockOllama::start()?;
+//! server.on_chat(MockResponse::json(json!({
+//!     "model": "qwen3:0.6b",
+//!     "message": {"role": "assistant", "content": "Hi!"},
+//!     "done": true
+//! })));
+//!
+//! let client = OllamaClient::with_base_url(server.url())?;
+//! let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+//! let response = client.chat(&request).await?;
+//! assert_eq!(response.content(), Some("Hi!"));
+//!
+//! let sent: ChatRequest = server.requests_to("/api/chat")[0].body_json()?;
+//! assert_eq!(sent.messages[0].content, "Hello");
+//! # Ok(())
+//! # }
+//! ```
+
+mod cassette;
+mod cassette_interaction;
+mod cassette_mode;
+pub(crate) mod cassette_transport;
+mod http_exchange;
+mod line_diff;
+mod mock_ollama;
+mod mock_response;
+mod recorded_request;
+
+pub use cassette::Cassette;
+pub use cassette_interaction::{
+    CassetteChunk, CassetteInteraction, CassetteRequest, CassetteResponse,
+};
+pub use cassette_mode::CassetteMode;
+pub use mock_ollama::MockOllama;
+pub use mock_response::MockResponse;
+pub use recorded_request::RecordedRequest;
@@ -0,0 +1,100 @@
+//! Request received by `MockOllama`
+
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+
+/// A request received by [`MockOllama`](super::MockOllama)
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::testing::MockOllama;
+/// use ollama_oxide::ChatRequest;
+///
+/// # fn example(server: &MockOllama) -> ollama_oxide::Result<()> {
+/// let request = server.last_request().expect("no request received");
+/// assert_eq!(request.method, "POST");
+/// assert_eq!(request.path, "/api/chat");
+///
+/// let chat: ChatRequest = request.body_json()?;
+/// assert_eq!(chat.model, "qwen3:0.6b");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    /// HTTP method, e.g. `POST`
+    pub method: String,
+
+    /// Request path without the query string, e.g. `/api/chat`
+    pub path: String,
+
+    /// Query string, if any
+    pub query: Option<String>,
+
+    /// Headers in the order received, with lowercase names
+    pub headers: Vec<(String, String)>,
+
+    /// Raw request body
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Get the first value of a header (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get the body as UTF-8 text
+    pub fn body_text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.body).ok()
+    }
+
+    /// Deserialize the JSON body
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid JSON for `T`
+    pub fn body_json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    fn request() -> RecordedRequest {
+        RecordedRequest {
+            method: "POST".to_string(),
+            path: "/api/chat".to_string(),
+            query: None,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: br#"{"model":"qwen3:0.6b"}"#.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_recorded_request_header_is_case_insensitive() {
+        let request = request();
+
+        assert_eq!(request.header("Content-Type"), Some("application/json"));
+        assert_eq!(request.header("authorization"), None);
+    }
+
+    #[test]
+    fn test_recorded_request_body() {
+        let request = request();
+
+        assert_eq!(request.body_text(), Some(r#"{"model":"qwen3:0.6b"}"#));
+        let body: Value = request.body_json().unwrap();
+        assert_eq!(body["model"], "qwen3:0.6b");
+        assert!(request.body_json::<Vec<u8>>().is_err());
+    }
+}
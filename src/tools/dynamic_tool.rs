@@ -0,0 +1,194 @@
+//! Runtime-defined tools
+//!
+//! This module provides `DynamicTool` for tools whose name, description and
+//! JSON Schema are only known at runtime, such as tools discovered by a
+//! plugin system.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::ToolDefinition;
+
+use super::erased_tool::{ErasedTool, block_on};
+use super::tool_error::ToolResult;
+
+/// Boxed future returned by a dynamic tool handler
+type HandlerFuture = Pin<Box<dyn Future<Output = ToolResult<serde_json::Value>> + Send>>;
+
+/// Type-erased dynamic tool handler
+type Handler = Arc<dyn Fn(serde_json::Value) -> HandlerFuture + Send + Sync>;
+
+/// Tool defined by a `ToolDefinition` and a closure
+///
+/// Unlike [`Tool`](super::Tool), a `DynamicTool` has no static parameter
+/// or output types: the definition, including the JSON Schema of the
+/// parameters, is supplied as data and the handler receives the raw JSON
+/// arguments of a call.
+///
+/// Register it with
+/// [`ToolRegistry::register_dynamic`](super::ToolRegistry::register_dynamic).
+///
+/// # Examples
+///
+/// ```no_run
+/// use ollama_oxide::ToolDefinition;
+/// use ollama_oxide::tools::{DynamicTool, ToolError, ToolRegistry};
+/// use serde_json::json;
+///
+/// let definition = ToolDefinition::function("echo", json!({
+///     "type": "object",
+///     "properties": {"text": {"type": "string"}},
+///     "required": ["text"]
+/// })).with_description("Echo the given text");
+///
+/// let echo = DynamicTool::new(definition, |args| async move {
+///     let text = args["text"]
+///         .as_str()
+///         .ok_or_else(|| ToolError::Custom("missing text".to_string()))?;
+///     Ok(json!({ "text": text }))
+/// });
+///
+/// let mut registry = ToolRegistry::new();
+/// registry.register_dynamic(echo);
+/// assert!(registry.contains("echo"));
+/// ```
+#[derive(Clone)]
+pub struct DynamicTool {
+    definition: ToolDefinition,
+    handler: Handler,
+}
+
+impl DynamicTool {
+    /// Create a dynamic tool from its definition and an async handler
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - Name, description and parameter schema sent to the model
+    /// * `handler` - Called with the JSON arguments of each tool call
+    pub fn new<F, Fut>(definition: ToolDefinition, handler: F) -> Self
+    where
+        F: Fn(serde_json::Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ToolResult<serde_json::Value>> + Send + 'static,
+    {
+        Self {
+            definition,
+            handler: Arc::new(move |args| Box::pin(handler(args))),
+        }
+    }
+
+    /// Get the tool name
+    pub fn name(&self) -> &str {
+        self.definition.name()
+    }
+
+    /// Get the tool description if available
+    pub fn description(&self) -> Option<&str> {
+        self.definition.description()
+    }
+
+    /// Get the tool definition
+    pub fn definition(&self) -> &ToolDefinition {
+        &self.definition
+    }
+
+    /// Call the handler with JSON arguments
+    pub async fn call(&self, args: serde_json::Value) -> ToolResult<serde_json::Value> {
+        (self.handler)(args).await
+    }
+}
+
+impl ErasedTool for DynamicTool {
+    fn name(&self) -> String {
+        self.definition.name().to_string()
+    }
+
+    fn definition(&self) -> &ToolDefinition {
+        &self.definition
+    }
+
+    fn execute_erased<'a>(
+        &'a self,
+        args: serde_json::Value,
+    ) -> Pin<Box<dyn Future<Output = ToolResult<serde_json::Value>> + Send + 'a>> {
+        (self.handler)(args)
+    }
+
+    fn execute_erased_blocking(&self, args: serde_json::Value) -> ToolResult<serde_json::Value> {
+        block_on(self.call(args))?
+    }
+}
+
+impl std::fmt::Debug for DynamicTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicTool")
+            .field("definition", &self.definition)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::ToolError;
+    use serde_json::json;
+
+    fn add_tool() -> DynamicTool {
+        let definition = ToolDefinition::function(
+            "add",
+            json!({
+                "type": "object",
+                "properties": {"a": {"type": "integer"}, "b": {"type": "integer"}},
+                "required": ["a", "b"]
+            }),
+        )
+        .with_description("Add two numbers");
+
+        DynamicTool::new(definition, |args| async move {
+            match (args["a"].as_i64(), args["b"].as_i64()) {
+                (Some(a), Some(b)) => Ok(json!({ "sum": a + b })),
+                _ => Err(ToolError::Custom("a and b must be integers".to_string())),
+            }
+        })
+    }
+
+    #[test]
+    fn test_dynamic_tool_definition() {
+        let tool = add_tool();
+        assert_eq!(tool.name(), "add");
+        assert_eq!(tool.description(), Some("Add two numbers"));
+        assert_eq!(
+            tool.definition().function.parameters["required"],
+            json!(["a", "b"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_tool_call() {
+        let tool = add_tool();
+
+        let result = tool.call(json!({"a": 2, "b": 3})).await.unwrap();
+        assert_eq!(result["sum"], 5);
+
+        let result = tool.call(json!({"a": "two"})).await;
+        assert!(matches!(result, Err(ToolError::Custom(_))));
+    }
+
+    #[test]
+    fn test_dynamic_tool_execute_blocking() {
+        let tool = add_tool();
+        let erased: &dyn ErasedTool = &tool;
+
+        let result = erased
+            .execute_erased_blocking(json!({"a": 1, "b": 1}))
+            .unwrap();
+        assert_eq!(result["sum"], 2);
+    }
+
+    #[test]
+    fn test_dynamic_tool_debug() {
+        let debug = format!("{:?}", add_tool());
+        assert!(debug.contains("DynamicTool"));
+        assert!(debug.contains("add"));
+    }
+}
@@ -6,6 +6,8 @@
 use std::future::Future;
 use std::pin::Pin;
 
+use tokio::runtime::RuntimeFlavor;
+
 use crate::ToolDefinition;
 
 use super::tool_error::{ToolError, ToolResult};
@@ -18,10 +20,10 @@ use super::tool_error::{ToolError, ToolResult};
 #[allow(dead_code)]
 pub trait ErasedTool: Send + Sync {
     /// Returns the name of the tool
-    fn name(&self) -> &'static str;
+    fn name(&self) -> String;
 
     /// Returns the tool definition
-    fn definition(&self) -> ToolDefinition;
+    fn definition(&self) -> &ToolDefinition;
 
     /// Execute the tool with JSON arguments, returning JSON result
     fn execute_erased<'a>(
@@ -34,13 +36,17 @@ pub trait ErasedTool: Send + Sync {
 }
 
 /// Wrapper that implements `ErasedTool` for any `Tool`
+///
+/// The definition is generated once, when the tool is wrapped.
 pub(crate) struct ToolWrapper<T> {
     tool: T,
+    definition: ToolDefinition,
 }
 
-impl<T> ToolWrapper<T> {
+impl<T: super::tool_trait::Tool> ToolWrapper<T> {
     pub fn new(tool: T) -> Self {
-        Self { tool }
+        let definition = tool.to_definition();
+        Self { tool, definition }
     }
 }
 
@@ -48,12 +54,12 @@ impl<T> ErasedTool for ToolWrapper<T>
 where
     T: super::tool_trait::Tool + 'static,
 {
-    fn name(&self) -> &'static str {
+    fn name(&self) -> String {
         self.tool.name()
     }
 
-    fn definition(&self) -> ToolDefinition {
-        self.tool.to_definition()
+    fn definition(&self) -> &ToolDefinition {
+        &self.definition
     }
 
     fn execute_erased<'a>(
@@ -85,13 +91,44 @@ where
     }
 
     fn execute_erased_blocking(&self, args: serde_json::Value) -> ToolResult<serde_json::Value> {
-        // Use tokio's block_on for sync execution
-        tokio::task::block_in_place(|| {
-            tokio::runtime::Handle::current().block_on(self.execute_erased(args))
-        })
+        block_on(self.execute_erased(args))?
+    }
+}
+
+/// Run a future to completion from sync code
+///
+/// Inside a multi-threaded runtime the current runtime is used, blocking
+/// its worker in place. A current-thread runtime has no worker to spare,
+/// so there, as in plain sync code, the future runs on a temporary
+/// current-thread runtime; inside a runtime that runtime gets its own
+/// thread, since a runtime cannot be started from within another.
+pub(super) fn block_on<F>(future: F) -> ToolResult<F::Output>
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            Ok(tokio::task::block_in_place(|| handle.block_on(future)))
+        }
+        Ok(_) => std::thread::scope(|scope| {
+            scope
+                .spawn(|| Ok(temporary_runtime()?.block_on(future)))
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+        }),
+        Err(_) => Ok(temporary_runtime()?.block_on(future)),
     }
 }
 
+/// Build a current-thread runtime for a single [`block_on`]
+fn temporary_runtime() -> ToolResult<tokio::runtime::Runtime> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| ToolError::ExecutionError(e.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,12 +152,12 @@ mod tests {
         type Params = TestParams;
         type Output = TestOutput;
 
-        fn name(&self) -> &'static str {
-            "double"
+        fn name(&self) -> String {
+            "double".to_string()
         }
 
-        fn description(&self) -> &'static str {
-            "Doubles a number"
+        fn description(&self) -> String {
+            "Doubles a number".to_string()
         }
 
         async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -167,4 +204,40 @@ mod tests {
 
         assert!(matches!(result, Err(ToolError::DeserializationError(_))));
     }
+
+    #[test]
+    fn test_erased_tool_execute_blocking_without_runtime() {
+        let wrapper = ToolWrapper::new(DoubleTool);
+        let erased: &dyn ErasedTool = &wrapper;
+
+        let result = erased
+            .execute_erased_blocking(serde_json::json!({"x": 4}))
+            .unwrap();
+
+        assert_eq!(result["doubled"], 8);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_erased_tool_execute_blocking_inside_runtime() {
+        let wrapper = ToolWrapper::new(DoubleTool);
+        let erased: &dyn ErasedTool = &wrapper;
+
+        let result = erased
+            .execute_erased_blocking(serde_json::json!({"x": 6}))
+            .unwrap();
+
+        assert_eq!(result["doubled"], 12);
+    }
+
+    #[tokio::test]
+    async fn test_erased_tool_execute_blocking_inside_current_thread_runtime() {
+        let wrapper = ToolWrapper::new(DoubleTool);
+        let erased: &dyn ErasedTool = &wrapper;
+
+        let result = erased
+            .execute_erased_blocking(serde_json::json!({"x": 7}))
+            .unwrap();
+
+        assert_eq!(result["doubled"], 14);
+    }
 }
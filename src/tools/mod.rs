@@ -21,6 +21,14 @@
 //! 2. **Type-Safe** (this module): `Tool` trait - auto-generated schemas from Rust types
 //! 3. **Registry** (this module): `ToolRegistry` - automatic dispatch
 //!
+//! With the `macros` feature, the `#[tool]` attribute generates the
+//! parameter struct and the `Tool` implementation from an async function;
+//! its doc comments become the tool and parameter descriptions.
+//!
+//! Tools only known at runtime, with a name, description and JSON Schema
+//! supplied as data, are wrapped in a `DynamicTool` and registered with
+//! `ToolRegistry::register_dynamic`.
+//!
 //! # Quick Start
 //!
 //! ```no_run
@@ -50,8 +58,8 @@
 //!     type Params = WeatherParams;
 //!     type Output = WeatherResult;
 //!
-//!     fn name(&self) -> &'static str { "get_weather" }
-//!     fn description(&self) -> &'static str { "Get current weather" }
+//!     fn name(&self) -> String { "get_weather".to_string() }
+//!     fn description(&self) -> String { "Get current weather".to_string() }
 //!
 //!     async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
 //!         // Your implementation here
@@ -100,7 +108,10 @@
 //! let results = registry.execute_all(&response).await;
 //! ```
 
+mod dynamic_tool;
 mod erased_tool;
+mod schema_normalization;
+mod schema_validation;
 mod tool_call;
 mod tool_call_function;
 mod tool_definition;
@@ -109,6 +120,9 @@ mod tool_function;
 mod tool_registry;
 mod tool_trait;
 
+pub use dynamic_tool::DynamicTool;
+pub use schema_normalization::normalize_schema;
+pub use schema_validation::SchemaViolation;
 pub use tool_call::ToolCall;
 pub use tool_call_function::ToolCallFunction;
 pub use tool_definition::ToolDefinition;
@@ -116,3 +130,14 @@ pub use tool_error::{ToolError, ToolResult};
 pub use tool_function::ToolFunction;
 pub use tool_registry::ToolRegistry;
 pub use tool_trait::Tool;
+
+#[cfg(feature = "macros")]
+pub use ollama_oxide_macros::tool;
+
+/// Re-exports used by code generated by `#[tool]`
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use schemars;
+    pub use serde;
+}
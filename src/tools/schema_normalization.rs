@@ -0,0 +1,488 @@
+//! Ollama-compatible JSON Schema normalization
+//!
+//! `schemars` produces complete JSON Schema documents: `$schema` and
+//! `title` keys, shared definitions under `$defs` referenced with `$ref`,
+//! `anyOf` with a `null` alternative for `Option<T>`, and one `const`
+//! alternative per documented enum variant. Many small models handle these
+//! poorly in a tool prompt or a `format` schema. This module rewrites such
+//! schemas into the plain, self-contained form they handle best.
+
+use serde_json::{Map, Value};
+
+/// Keys describing the document rather than the value
+const META_KEYS: [&str; 6] = [
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "$defs",
+    "definitions",
+];
+
+/// Keys holding shared definitions referenced by `$ref`
+const DEFINITION_KEYS: [&str; 2] = ["$defs", "definitions"];
+
+/// Numeric `format` values specific to `schemars`; bounds are already
+/// expressed with `minimum` and `maximum`
+const RUST_FORMATS: [&str; 14] = [
+    "int", "int8", "int16", "int32", "int64", "int128", "uint", "uint8", "uint16", "uint32",
+    "uint64", "uint128", "float", "double",
+];
+
+/// Keys an enum variant alternative may have to be merged into `enum`
+const ENUM_VARIANT_KEYS: [&str; 4] = ["const", "enum", "type", "description"];
+
+/// Normalize a JSON Schema for Ollama
+///
+/// The pass:
+/// - Inlines every `$ref` to `$defs` or `definitions`. A recursive
+///   reference is replaced by a schema with only the `type` of its target.
+/// - Removes meta keys (`$schema`, `$id`, `$comment`, `title`, `$defs`,
+///   `definitions`) and `schemars` numeric formats such as `uint8`
+/// - Flattens `Option<T>`: `null` is removed from `type`, `enum` and
+///   `anyOf`/`oneOf`, and nullable properties are removed from `required`
+/// - Turns `oneOf`/`anyOf` lists of constants into a plain `enum` array;
+///   variant descriptions are appended to the description
+///
+/// `Tool::parameters_schema` and `FormatSetting::schema_for` apply it
+/// automatically. Call it directly for hand-written schemas, e.g. those of
+/// a `DynamicTool`.
+///
+/// # Examples
+///
+/// ```
+/// use ollama_oxide::tools::normalize_schema;
+/// use serde_json::json;
+///
+/// let schema = normalize_schema(json!({
+///     "$schema": "https://json-schema.org/draft/2020-12/schema",
+///     "title": "Params",
+///     "type": "object",
+///     "properties": {
+///         "unit": {"anyOf": [{"$ref": "#/$defs/Unit"}, {"type": "null"}]}
+///     },
+///     "$defs": {"Unit": {"type": "string", "enum": ["celsius", "fahrenheit"]}}
+/// }));
+///
+/// assert_eq!(schema, json!({
+///     "type": "object",
+///     "properties": {
+///         "unit": {"type": "string", "enum": ["celsius", "fahrenheit"]}
+///     }
+/// }));
+/// ```
+pub fn normalize_schema(schema: Value) -> Value {
+    let mut definitions = Map::new();
+    if let Value::Object(root) = &schema {
+        for key in DEFINITION_KEYS {
+            if let Some(Value::Object(defs)) = root.get(key) {
+                definitions.extend(defs.clone());
+            }
+        }
+    }
+
+    let root_type = schema.get("type").cloned();
+    let mut normalizer = Normalizer {
+        definitions,
+        root_type,
+        stack: Vec::new(),
+    };
+    normalizer.normalize(schema).0
+}
+
+struct Normalizer {
+    /// Definitions collected from the root
+    definitions: Map<String, Value>,
+    /// Type of the root schema, for recursive `#` references
+    root_type: Option<Value>,
+    /// Definitions being inlined, to detect recursion
+    stack: Vec<String>,
+}
+
+impl Normalizer {
+    /// Normalize a schema, returning it and whether it allowed `null`
+    fn normalize(&mut self, schema: Value) -> (Value, bool) {
+        let Value::Object(mut schema) = schema else {
+            return (schema, false);
+        };
+
+        for key in META_KEYS {
+            schema.remove(key);
+        }
+        if schema
+            .get("format")
+            .and_then(Value::as_str)
+            .is_some_and(|format| RUST_FORMATS.contains(&format))
+        {
+            schema.remove("format");
+        }
+
+        let reference = match schema.remove("$ref") {
+            Some(Value::String(reference)) => Some(reference),
+            Some(other) => {
+                schema.insert("$ref".to_string(), other);
+                None
+            }
+            None => None,
+        };
+
+        self.normalize_properties(&mut schema);
+        for key in ["additionalProperties", "items", "not"] {
+            if let Some(subschema) = schema.remove(key) {
+                let subschema = self.normalize_any(subschema);
+                schema.insert(key.to_string(), subschema);
+            }
+        }
+        for key in ["prefixItems", "allOf", "anyOf", "oneOf"] {
+            if let Some(subschemas) = schema.remove(key) {
+                let subschemas = self.normalize_any(subschemas);
+                schema.insert(key.to_string(), subschemas);
+            }
+        }
+
+        let mut nullable = strip_null(&mut schema);
+        collapse_enum_alternatives(&mut schema);
+
+        if let Some(reference) = reference {
+            let (target, target_nullable) = self.resolve(reference);
+            nullable |= target_nullable;
+            merge_missing(&mut schema, target);
+        }
+
+        (Value::Object(schema), nullable)
+    }
+
+    /// Normalize a schema or each schema of an array of schemas
+    fn normalize_any(&mut self, value: Value) -> Value {
+        match value {
+            Value::Array(schemas) => schemas
+                .into_iter()
+                .map(|schema| self.normalize(schema).0)
+                .collect(),
+            schema => self.normalize(schema).0,
+        }
+    }
+
+    /// Normalize `properties` and drop nullable ones from `required`
+    fn normalize_properties(&mut self, schema: &mut Map<String, Value>) {
+        let Some(Value::Object(properties)) = schema.remove("properties") else {
+            return;
+        };
+
+        let mut nullable = Vec::new();
+        let properties: Map<String, Value> = properties
+            .into_iter()
+            .map(|(name, property)| {
+                let (property, is_nullable) = self.normalize(property);
+                if is_nullable {
+                    nullable.push(Value::String(name.clone()));
+                }
+                (name, property)
+            })
+            .collect();
+        schema.insert("properties".to_string(), Value::Object(properties));
+
+        if let Some(Value::Array(required)) = schema.get_mut("required") {
+            required.retain(|name| !nullable.contains(name));
+            if required.is_empty() {
+                schema.remove("required");
+            }
+        }
+    }
+
+    /// Resolve and normalize the target of a `$ref`
+    fn resolve(&mut self, reference: String) -> (Value, bool) {
+        if reference == "#" {
+            return (type_only(self.root_type.as_ref()), false);
+        }
+
+        let name = DEFINITION_KEYS
+            .iter()
+            .find_map(|key| reference.strip_prefix(&format!("#/{}/", key)));
+        let Some(definition) = name.and_then(|name| self.definitions.get(name)).cloned() else {
+            // Leave references we cannot resolve untouched
+            return (
+                Value::Object(Map::from_iter([("$ref".to_string(), reference.into())])),
+                false,
+            );
+        };
+        let name = name.unwrap_or_default().to_string();
+
+        if self.stack.contains(&name) {
+            return (type_only(definition.get("type")), false);
+        }
+
+        self.stack.push(name);
+        let resolved = self.normalize(definition);
+        self.stack.pop();
+        resolved
+    }
+}
+
+/// Schema keeping only a `type`, used to cut recursion
+fn type_only(schema_type: Option<&Value>) -> Value {
+    let mut schema = Map::new();
+    if let Some(schema_type) = schema_type {
+        schema.insert("type".to_string(), schema_type.clone());
+    }
+    Value::Object(schema)
+}
+
+/// Copy keys of `source` that `target` does not have
+fn merge_missing(target: &mut Map<String, Value>, source: Value) {
+    if let Value::Object(source) = source {
+        for (key, value) in source {
+            target.entry(key).or_insert(value);
+        }
+    }
+}
+
+/// Remove `null` from a schema, returning whether it was allowed
+fn strip_null(schema: &mut Map<String, Value>) -> bool {
+    let mut nullable = false;
+
+    if let Some(Value::Array(types)) = schema.get_mut("type")
+        && types.iter().any(|t| t == "null")
+    {
+        nullable = true;
+        types.retain(|t| t != "null");
+        match types.len() {
+            0 => {
+                schema.remove("type");
+            }
+            1 => {
+                let single = types.remove(0);
+                schema.insert("type".to_string(), single);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(Value::Array(values)) = schema.get_mut("enum")
+        && values.contains(&Value::Null)
+    {
+        nullable = true;
+        values.retain(|value| !value.is_null());
+    }
+
+    for key in ["anyOf", "oneOf"] {
+        let Some(Value::Array(alternatives)) = schema.get_mut(key) else {
+            continue;
+        };
+        let count = alternatives.len();
+        alternatives.retain(|alternative| !is_null_schema(alternative));
+        if alternatives.len() == count {
+            continue;
+        }
+
+        nullable = true;
+        match alternatives.len() {
+            0 => {
+                schema.remove(key);
+            }
+            1 => {
+                let single = alternatives.remove(0);
+                schema.remove(key);
+                merge_missing(schema, single);
+            }
+            _ => {}
+        }
+    }
+
+    nullable
+}
+
+/// Check whether a schema only allows `null`
+fn is_null_schema(schema: &Value) -> bool {
+    schema.get("type").is_some_and(|t| t == "null")
+        || schema.get("const").is_some_and(Value::is_null)
+}
+
+/// Replace `oneOf`/`anyOf` lists of constants with a plain `enum`
+fn collapse_enum_alternatives(schema: &mut Map<String, Value>) {
+    if schema.contains_key("enum") || schema.contains_key("const") {
+        return;
+    }
+
+    for key in ["oneOf", "anyOf"] {
+        let Some(Value::Array(alternatives)) = schema.get(key) else {
+            continue;
+        };
+        if alternatives.is_empty() || !alternatives.iter().all(is_enum_variant) {
+            continue;
+        }
+
+        let mut values = Vec::new();
+        let mut types = Vec::new();
+        let mut notes = Vec::new();
+        for alternative in alternatives {
+            let variant_values = match (alternative.get("const"), alternative.get("enum")) {
+                (Some(value), _) => vec![value.clone()],
+                (None, Some(Value::Array(values))) => values.clone(),
+                _ => Vec::new(),
+            };
+            if let Some(description) = alternative.get("description").and_then(Value::as_str) {
+                for value in &variant_values {
+                    notes.push(format!("{}: {}", display_value(value), description));
+                }
+            }
+            if let Some(schema_type) = alternative.get("type")
+                && !types.contains(schema_type)
+            {
+                types.push(schema_type.clone());
+            }
+            values.extend(variant_values);
+        }
+
+        schema.remove(key);
+        schema.insert("enum".to_string(), Value::Array(values));
+        if let [schema_type] = types.as_slice() {
+            schema.entry("type").or_insert_with(|| schema_type.clone());
+        }
+        if !notes.is_empty() {
+            let notes = notes.join("\n");
+            let description = match schema.get("description").and_then(Value::as_str) {
+                Some(description) => format!("{}\n{}", description, notes),
+                None => notes,
+            };
+            schema.insert("description".to_string(), Value::String(description));
+        }
+        return;
+    }
+}
+
+/// Check whether an alternative only describes one or more constants
+fn is_enum_variant(alternative: &Value) -> bool {
+    let Value::Object(alternative) = alternative else {
+        return false;
+    };
+    (alternative.contains_key("const") || alternative.contains_key("enum"))
+        && alternative
+            .keys()
+            .all(|key| ENUM_VARIANT_KEYS.contains(&key.as_str()))
+}
+
+/// Show a constant in a description, without quotes for strings
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize_removes_meta_keys() {
+        let schema = normalize_schema(json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "title": "Params",
+            "type": "object",
+            "properties": {"n": {"type": "integer", "format": "uint8", "minimum": 0}}
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "type": "object",
+                "properties": {"n": {"type": "integer", "minimum": 0}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_standard_formats() {
+        let schema = normalize_schema(json!({"type": "string", "format": "date-time"}));
+        assert_eq!(schema, json!({"type": "string", "format": "date-time"}));
+    }
+
+    #[test]
+    fn test_normalize_inlines_refs_with_sibling_precedence() {
+        let schema = normalize_schema(json!({
+            "type": "object",
+            "properties": {
+                "unit": {"description": "Unit to use", "$ref": "#/definitions/Unit"}
+            },
+            "definitions": {
+                "Unit": {"description": "A unit", "type": "string", "enum": ["c", "f"]}
+            }
+        }));
+
+        assert_eq!(
+            schema["properties"]["unit"],
+            json!({"description": "Unit to use", "type": "string", "enum": ["c", "f"]})
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_unresolved_refs() {
+        let schema = normalize_schema(json!({"$ref": "https://example.com/schema.json"}));
+        assert_eq!(schema, json!({"$ref": "https://example.com/schema.json"}));
+    }
+
+    #[test]
+    fn test_normalize_flattens_nullable_types() {
+        let schema = normalize_schema(json!({
+            "type": "object",
+            "properties": {
+                "a": {"type": ["string", "null"]},
+                "b": {"type": ["integer", "string", "null"]},
+                "c": {"enum": ["x", null]},
+                "d": {"type": "boolean"}
+            },
+            "required": ["a", "b", "c", "d"]
+        }));
+
+        assert_eq!(schema["properties"]["a"], json!({"type": "string"}));
+        assert_eq!(
+            schema["properties"]["b"],
+            json!({"type": ["integer", "string"]})
+        );
+        assert_eq!(schema["properties"]["c"], json!({"enum": ["x"]}));
+        assert_eq!(schema["required"], json!(["d"]));
+    }
+
+    #[test]
+    fn test_normalize_removes_empty_required() {
+        let schema = normalize_schema(json!({
+            "type": "object",
+            "properties": {"a": {"type": ["string", "null"]}},
+            "required": ["a"]
+        }));
+        assert!(schema.get("required").is_none());
+    }
+
+    #[test]
+    fn test_normalize_collapses_const_alternatives() {
+        let schema = normalize_schema(json!({
+            "description": "Direction",
+            "oneOf": [
+                {"type": "string", "const": "up", "description": "Move up"},
+                {"type": "string", "const": "down"}
+            ]
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "description": "Direction\nup: Move up",
+                "type": "string",
+                "enum": ["up", "down"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_data_carrying_alternatives() {
+        let original = json!({
+            "oneOf": [
+                {"type": "string", "enum": ["Unit"]},
+                {"type": "object", "properties": {"Tuple": {"type": "integer"}}}
+            ]
+        });
+        assert_eq!(normalize_schema(original.clone()), original);
+    }
+}
@@ -0,0 +1,606 @@
+//! Validation of tool arguments against a JSON Schema
+//!
+//! This module checks tool call arguments against the parameter schema of
+//! a tool before it runs, so that every problem can be reported to the
+//! model at once instead of the first serde error.
+//!
+//! The supported keywords cover what `schemars` generates: `type`, `enum`,
+//! `const`, `properties`, `required`, `additionalProperties`, `items`,
+//! `prefixItems`, `anyOf`, `oneOf`, `allOf`, `$ref` to the same document,
+//! and the numeric, length and size bounds. Other keywords are ignored.
+//!
+//! An explicit `null` for a property that is not `required` is treated as
+//! if the property were absent. Schema normalization removes `null` from
+//! the type of `Option<T>` fields, yet serde still accepts it for them.
+
+use serde_json::{Map, Value};
+
+/// A single way in which a value does not match its schema
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON path of the offending value, e.g. `$.items[2].name`
+    pub path: String,
+
+    /// What is wrong with the value
+    pub message: String,
+}
+
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validate `value` against `schema`, returning every violation found
+pub(crate) fn validate(schema: &Value, value: &Value) -> Vec<SchemaViolation> {
+    let mut validator = Validator {
+        root: schema,
+        violations: Vec::new(),
+    };
+    validator.check(schema, value, "$".to_string(), 0);
+    validator.violations
+}
+
+/// Maximum `$ref` nesting followed before giving up on a recursive schema
+const MAX_DEPTH: usize = 64;
+
+struct Validator<'a> {
+    root: &'a Value,
+    violations: Vec<SchemaViolation>,
+}
+
+impl<'a> Validator<'a> {
+    fn report(&mut self, path: &str, message: impl Into<String>) {
+        self.violations.push(SchemaViolation {
+            path: path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// Check whether `value` matches `schema` without recording violations
+    fn matches(&self, schema: &Value, value: &Value, depth: usize) -> bool {
+        let mut probe = Validator {
+            root: self.root,
+            violations: Vec::new(),
+        };
+        probe.check(schema, value, String::new(), depth);
+        probe.violations.is_empty()
+    }
+
+    fn check(&mut self, schema: &Value, value: &Value, path: String, depth: usize) {
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => return self.report(&path, "no value is allowed here"),
+            Value::Object(schema) => schema,
+            _ => return,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            match self.resolve(reference) {
+                Some(target) if depth < MAX_DEPTH => {
+                    self.check(target, value, path.clone(), depth + 1)
+                }
+                Some(_) => {}
+                None => self.report(&path, format!("unresolved schema reference {}", reference)),
+            }
+        }
+
+        if let Some(expected) = schema.get("type")
+            && !type_matches(expected, value)
+        {
+            return self.report(
+                &path,
+                format!("expected {}, got {}", type_names(expected), type_of(value)),
+            );
+        }
+
+        if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+            && !allowed.contains(value)
+        {
+            self.report(
+                &path,
+                format!(
+                    "expected one of {}, got {}",
+                    Value::from(allowed.clone()),
+                    value
+                ),
+            );
+        }
+        if let Some(expected) = schema.get("const")
+            && expected != value
+        {
+            self.report(&path, format!("expected {}, got {}", expected, value));
+        }
+
+        self.check_combinators(schema, value, &path, depth);
+
+        match value {
+            Value::Object(object) => self.check_object(schema, object, &path, depth),
+            Value::Array(items) => self.check_array(schema, items, &path, depth),
+            Value::String(text) => self.check_string(schema, text, &path),
+            Value::Number(_) => self.check_number(schema, value, &path),
+            _ => {}
+        }
+    }
+
+    fn check_combinators(
+        &mut self,
+        schema: &Map<String, Value>,
+        value: &Value,
+        path: &str,
+        depth: usize,
+    ) {
+        if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+            for subschema in all {
+                self.check(subschema, value, path.to_string(), depth);
+            }
+        }
+
+        if let Some(any) = schema.get("anyOf").and_then(Value::as_array) {
+            self.check_alternatives(any, value, path, depth, false);
+        }
+        if let Some(one) = schema.get("oneOf").and_then(Value::as_array) {
+            self.check_alternatives(one, value, path, depth, true);
+        }
+    }
+
+    /// Check `anyOf` (at least one match) or `oneOf` (exactly one match)
+    fn check_alternatives(
+        &mut self,
+        alternatives: &[Value],
+        value: &Value,
+        path: &str,
+        depth: usize,
+        exclusive: bool,
+    ) {
+        let matching = alternatives
+            .iter()
+            .filter(|subschema| self.matches(subschema, value, depth))
+            .count();
+
+        match matching {
+            0 if alternatives.len() == 1 => {
+                self.check(&alternatives[0], value, path.to_string(), depth)
+            }
+            0 => self.report(
+                path,
+                format!(
+                    "does not match any of the {} allowed schemas",
+                    alternatives.len()
+                ),
+            ),
+            1 => {}
+            n if exclusive => self.report(
+                path,
+                format!("matches {} schemas, but exactly one is allowed", n),
+            ),
+            _ => {}
+        }
+    }
+
+    fn check_object(
+        &mut self,
+        schema: &Map<String, Value>,
+        object: &Map<String, Value>,
+        path: &str,
+        depth: usize,
+    ) {
+        let required = schema.get("required").and_then(Value::as_array);
+        if let Some(required) = required {
+            for name in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(name) {
+                    self.report(&property_path(path, name), "required property is missing");
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(Value::as_object);
+        let additional = schema.get("additionalProperties");
+
+        for (name, property) in object {
+            if property.is_null()
+                && !required.is_some_and(|required| required.iter().any(|r| r == name))
+            {
+                continue;
+            }
+            let property_path = property_path(path, name);
+            match properties.and_then(|properties| properties.get(name)) {
+                Some(subschema) => self.check(subschema, property, property_path, depth),
+                None => match additional {
+                    Some(Value::Bool(false)) => self.report(&property_path, "unexpected property"),
+                    Some(subschema) => self.check(subschema, property, property_path, depth),
+                    None => {}
+                },
+            }
+        }
+    }
+
+    fn check_array(
+        &mut self,
+        schema: &Map<String, Value>,
+        items: &[Value],
+        path: &str,
+        depth: usize,
+    ) {
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+            && (items.len() as u64) < min
+        {
+            self.report(
+                path,
+                format!("expected at least {} items, got {}", min, items.len()),
+            );
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+            && (items.len() as u64) > max
+        {
+            self.report(
+                path,
+                format!("expected at most {} items, got {}", max, items.len()),
+            );
+        }
+
+        let prefix = schema
+            .get("prefixItems")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (index, item) in items.iter().enumerate() {
+            let subschema = match prefix.get(index) {
+                Some(subschema) => subschema,
+                None => match schema.get("items") {
+                    Some(subschema) => subschema,
+                    None => continue,
+                },
+            };
+            self.check(subschema, item, format!("{}[{}]", path, index), depth);
+        }
+    }
+
+    fn check_string(&mut self, schema: &Map<String, Value>, text: &str, path: &str) {
+        let len = text.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64)
+            && len < min
+        {
+            self.report(
+                path,
+                format!("expected at least {} characters, got {}", min, len),
+            );
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64)
+            && len > max
+        {
+            self.report(
+                path,
+                format!("expected at most {} characters, got {}", max, len),
+            );
+        }
+    }
+
+    fn check_number(&mut self, schema: &Map<String, Value>, value: &Value, path: &str) {
+        let Some(number) = value.as_f64() else {
+            return;
+        };
+        let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+
+        if let Some(min) = bound("minimum")
+            && number < min
+        {
+            self.report(path, format!("expected a value >= {}, got {}", min, value));
+        }
+        if let Some(max) = bound("maximum")
+            && number > max
+        {
+            self.report(path, format!("expected a value <= {}, got {}", max, value));
+        }
+        if let Some(min) = bound("exclusiveMinimum")
+            && number <= min
+        {
+            self.report(path, format!("expected a value > {}, got {}", min, value));
+        }
+        if let Some(max) = bound("exclusiveMaximum")
+            && number >= max
+        {
+            self.report(path, format!("expected a value < {}, got {}", max, value));
+        }
+    }
+
+    /// Resolve a `$ref` such as `#/$defs/Unit` against the root schema
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let pointer = reference.strip_prefix('#')?;
+        self.root.pointer(pointer)
+    }
+}
+
+/// Check a value against a `type` keyword (a name or a list of names)
+fn type_matches(expected: &Value, value: &Value) -> bool {
+    match expected {
+        Value::String(name) => is_type(name, value),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| is_type(name, value)),
+        _ => true,
+    }
+}
+
+fn is_type(name: &str, value: &Value) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        _ => true,
+    }
+}
+
+/// Describe a `type` keyword, e.g. `integer` or `string or null`
+fn type_names(expected: &Value) -> String {
+    match expected {
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        Value::String(name) => name.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// JSON type name of a value
+fn type_of(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Append a property name to a JSON path
+fn property_path(path: &str, name: &str) -> String {
+    let plain = !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if plain {
+        format!("{}.{}", path, name)
+    } else {
+        format!("{}[{}]", path, Value::from(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn messages(schema: &Value, value: &Value) -> Vec<String> {
+        validate(schema, value)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_valid_object() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "location": {"type": "string"},
+                "days": {"type": "integer", "minimum": 1}
+            },
+            "required": ["location"]
+        });
+
+        assert!(validate(&schema, &json!({"location": "Paris", "days": 3})).is_empty());
+        assert!(validate(&schema, &json!({"location": "Paris"})).is_empty());
+    }
+
+    #[test]
+    fn test_validate_null_optional_property() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "location": {"type": "string"},
+                "unit": {"type": "string"}
+            },
+            "required": ["location"]
+        });
+
+        assert!(messages(&schema, &json!({"location": "Paris", "unit": null})).is_empty());
+        assert_eq!(
+            messages(&schema, &json!({"location": null})),
+            vec!["$.location: expected string, got null"]
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_every_violation() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "location": {"type": "string"},
+                "days": {"type": "integer", "minimum": 1},
+                "unit": {"enum": ["celsius", "fahrenheit"]}
+            },
+            "required": ["location", "days"],
+            "additionalProperties": false
+        });
+
+        let found = messages(
+            &schema,
+            &json!({"days": 0, "unit": "kelvin", "extra": true}),
+        );
+        assert_eq!(
+            found,
+            vec![
+                "$.location: required property is missing",
+                "$.days: expected a value >= 1, got 0",
+                "$.extra: unexpected property",
+                "$.unit: expected one of [\"celsius\",\"fahrenheit\"], got \"kelvin\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_type_mismatch() {
+        let schema = json!({"type": "integer"});
+        assert_eq!(
+            messages(&schema, &json!("3")),
+            vec!["$: expected integer, got string"]
+        );
+        assert!(validate(&schema, &json!(3.0)).is_empty());
+        assert_eq!(
+            messages(&schema, &json!(2.5)),
+            vec!["$: expected integer, got number"]
+        );
+
+        let nullable = json!({"type": ["string", "null"]});
+        assert!(validate(&nullable, &Value::Null).is_empty());
+        assert_eq!(
+            messages(&nullable, &json!(1)),
+            vec!["$: expected string or null, got integer"]
+        );
+    }
+
+    #[test]
+    fn test_validate_nested_paths() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {"name": {"type": "string"}},
+                        "required": ["name"]
+                    }
+                },
+                "odd key": {"type": "boolean"}
+            }
+        });
+
+        let found = messages(
+            &schema,
+            &json!({"items": [{"name": "a"}, {"name": 1}, {}], "odd key": "yes"}),
+        );
+        assert_eq!(
+            found,
+            vec![
+                "$.items[1].name: expected string, got integer",
+                "$.items[2].name: required property is missing",
+                "$[\"odd key\"]: expected boolean, got string",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_refs() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"unit": {"$ref": "#/$defs/Unit"}},
+            "$defs": {"Unit": {"type": "string", "enum": ["c", "f"]}}
+        });
+
+        assert!(validate(&schema, &json!({"unit": "c"})).is_empty());
+        assert_eq!(
+            messages(&schema, &json!({"unit": "k"})),
+            vec!["$.unit: expected one of [\"c\",\"f\"], got \"k\""]
+        );
+    }
+
+    #[test]
+    fn test_validate_recursive_ref_terminates() {
+        let schema = json!({
+            "$ref": "#/$defs/Node",
+            "$defs": {
+                "Node": {
+                    "type": "object",
+                    "properties": {"next": {"$ref": "#/$defs/Node"}}
+                }
+            }
+        });
+
+        assert!(validate(&schema, &json!({"next": {"next": {}}})).is_empty());
+        assert_eq!(
+            messages(&schema, &json!({"next": {"next": 1}})),
+            vec!["$.next.next: expected object, got integer"]
+        );
+    }
+
+    #[test]
+    fn test_validate_any_of_and_one_of() {
+        let any = json!({"anyOf": [{"type": "string"}, {"type": "integer"}]});
+        assert!(validate(&any, &json!(1)).is_empty());
+        assert_eq!(
+            messages(&any, &json!(true)),
+            vec!["$: does not match any of the 2 allowed schemas"]
+        );
+
+        let one = json!({"oneOf": [{"type": "number"}, {"type": "integer"}]});
+        assert_eq!(
+            messages(&one, &json!(1)),
+            vec!["$: matches 2 schemas, but exactly one is allowed"]
+        );
+
+        // A single alternative reports its own violations
+        let single = json!({"anyOf": [{"type": "object", "required": ["a"]}]});
+        assert_eq!(
+            messages(&single, &json!({})),
+            vec!["$.a: required property is missing"]
+        );
+    }
+
+    #[test]
+    fn test_validate_bounds() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "minLength": 2, "maxLength": 3},
+                "tags": {"type": "array", "maxItems": 1},
+                "ratio": {"type": "number", "exclusiveMaximum": 1}
+            }
+        });
+
+        let found = messages(
+            &schema,
+            &json!({"name": "abcd", "tags": [1, 2], "ratio": 1}),
+        );
+        assert_eq!(
+            found,
+            vec![
+                "$.name: expected at most 3 characters, got 4",
+                "$.ratio: expected a value < 1, got 1",
+                "$.tags: expected at most 1 items, got 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_schemars_schema() {
+        #[derive(schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct Params {
+            location: String,
+            days: Option<u8>,
+        }
+
+        let schema = serde_json::to_value(schemars::schema_for!(Params)).unwrap();
+
+        assert!(validate(&schema, &json!({"location": "Paris", "days": null})).is_empty());
+        assert_eq!(
+            messages(&schema, &json!({"days": 300})),
+            vec![
+                "$.location: required property is missing",
+                "$.days: expected a value <= 255, got 300",
+            ]
+        );
+    }
+}
@@ -2,8 +2,14 @@
 //!
 //! This module provides error handling for tool execution.
 
+use std::time::Duration;
+
 use thiserror::Error;
 
+use crate::ChatMessage;
+
+use super::schema_validation::SchemaViolation;
+
 /// Result type for tool operations
 pub type ToolResult<T> = std::result::Result<T, ToolError>;
 
@@ -26,6 +32,24 @@ pub enum ToolError {
     #[error("execution error: {0}")]
     ExecutionError(String),
 
+    /// Tool arguments do not match the tool's parameter schema
+    #[error("invalid arguments for '{name}': {}", join_violations(violations))]
+    InvalidArguments {
+        /// Name of the tool
+        name: String,
+        /// Every violation found, with its JSON path
+        violations: Vec<SchemaViolation>,
+    },
+
+    /// Tool did not finish within its timeout
+    #[error("tool '{name}' timed out after {elapsed:?}")]
+    Timeout {
+        /// Name of the tool
+        name: String,
+        /// Time the call ran before it was cancelled
+        elapsed: Duration,
+    },
+
     /// Tool call is missing required function information
     #[error("invalid tool call: missing function")]
     InvalidToolCall,
@@ -45,6 +69,57 @@ impl ToolError {
     pub fn from_json_error(err: serde_json::Error) -> Self {
         Self::DeserializationError(err.to_string())
     }
+
+    /// Build a tool message reporting this error to the model
+    ///
+    /// Append it to the conversation in place of the tool output so the
+    /// model can correct its call on the next turn. Invalid arguments are
+    /// listed one violation per line with their JSON path.
+    ///
+    /// Errors naming their tool set the message's `tool_name`; for the
+    /// others, add it with [`ChatMessage::with_tool_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ollama_oxide::tools::ToolError;
+    ///
+    /// let err = ToolError::NotFound("get_weather".to_string());
+    /// let message = err.to_chat_message();
+    /// assert_eq!(message.content, "Error: tool not found: get_weather");
+    /// assert_eq!(message.tool_name.as_deref(), Some("get_weather"));
+    /// ```
+    pub fn to_chat_message(&self) -> ChatMessage {
+        let content = match self {
+            Self::InvalidArguments { name, violations } => {
+                let mut content = format!("Error: invalid arguments for tool '{}':\n", name);
+                for violation in violations {
+                    content.push_str(&format!("- {}\n", violation));
+                }
+                content.push_str(
+                    "Call the tool again with arguments that match its parameter schema.",
+                );
+                content
+            }
+            other => format!("Error: {}", other),
+        };
+        let message = ChatMessage::tool(content);
+        match self {
+            Self::NotFound(name)
+            | Self::InvalidArguments { name, .. }
+            | Self::Timeout { name, .. } => message.with_tool_name(name),
+            _ => message,
+        }
+    }
+}
+
+/// Join violations for the error display
+fn join_violations(violations: &[SchemaViolation]) -> String {
+    violations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 impl From<serde_json::Error> for ToolError {
@@ -90,6 +165,58 @@ mod tests {
         assert_eq!(err.to_string(), "invalid tool call: missing function");
     }
 
+    #[test]
+    fn test_tool_error_invalid_arguments() {
+        let err = ToolError::InvalidArguments {
+            name: "get_weather".to_string(),
+            violations: vec![
+                SchemaViolation {
+                    path: "$.location".to_string(),
+                    message: "required property is missing".to_string(),
+                },
+                SchemaViolation {
+                    path: "$.days".to_string(),
+                    message: "expected integer, got string".to_string(),
+                },
+            ],
+        };
+        assert_eq!(
+            err.to_string(),
+            "invalid arguments for 'get_weather': $.location: required property is missing; $.days: expected integer, got string"
+        );
+
+        let message = err.to_chat_message();
+        assert_eq!(message.role, crate::ChatRole::Tool);
+        assert_eq!(message.tool_name.as_deref(), Some("get_weather"));
+        assert_eq!(
+            message.content,
+            "Error: invalid arguments for tool 'get_weather':\n\
+             - $.location: required property is missing\n\
+             - $.days: expected integer, got string\n\
+             Call the tool again with arguments that match its parameter schema."
+        );
+    }
+
+    #[test]
+    fn test_tool_error_to_chat_message() {
+        let message = ToolError::ExecutionError("boom".to_string()).to_chat_message();
+        assert_eq!(message.content, "Error: execution error: boom");
+        assert_eq!(message.tool_name, None);
+    }
+
+    #[test]
+    fn test_tool_error_timeout() {
+        let err = ToolError::Timeout {
+            name: "get_weather".to_string(),
+            elapsed: Duration::from_millis(1500),
+        };
+        assert_eq!(err.to_string(), "tool 'get_weather' timed out after 1.5s");
+        assert_eq!(
+            err.to_chat_message().tool_name.as_deref(),
+            Some("get_weather")
+        );
+    }
+
     #[test]
     fn test_tool_error_custom() {
         let err = ToolError::custom("something went wrong");
@@ -5,10 +5,16 @@
 
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use futures::StreamExt;
+use tokio::time::Instant;
 
 use crate::{ChatResponse, ToolCall, ToolDefinition};
 
-use super::erased_tool::{ErasedTool, ToolWrapper};
+use super::dynamic_tool::DynamicTool;
+use super::erased_tool::{ErasedTool, ToolWrapper, block_on};
+use super::schema_validation;
 use super::tool_error::{ToolError, ToolResult};
 use super::tool_trait::Tool;
 
@@ -18,6 +24,28 @@ use super::tool_trait::Tool;
 /// - Tool registration with automatic schema generation
 /// - Generation of `ToolDefinition` list for chat requests
 /// - Automatic dispatch of tool calls from responses
+/// - Concurrent execution with an optional concurrency limit and timeouts
+///
+/// # Concurrency and Timeouts
+///
+/// [`execute_all`](Self::execute_all) runs the calls of a response
+/// concurrently, at most [`with_max_concurrency`](Self::with_max_concurrency)
+/// at a time, and returns the results in call order.
+///
+/// A call that takes longer than its timeout fails with
+/// [`ToolError::Timeout`] without holding up the other calls. The timeout
+/// of a call is the one set for its tool with
+/// [`with_tool_timeout`](Self::with_tool_timeout), or else the default
+/// from [`with_timeout`](Self::with_timeout). [`with_total_timeout`](Self::with_total_timeout)
+/// additionally bounds a whole `execute_all` batch.
+///
+/// # Argument Validation
+///
+/// Before a tool runs, the arguments of the call are checked against the
+/// tool's parameter schema. Mismatches fail with
+/// [`ToolError::InvalidArguments`], listing every violation with its JSON
+/// path; [`ToolError::to_chat_message`] turns the error into a tool message
+/// the model can use to correct its call.
 ///
 /// # Thread Safety
 ///
@@ -42,8 +70,8 @@ use super::tool_trait::Tool;
 /// impl Tool for AddTool {
 ///     type Params = AddParams;
 ///     type Output = AddResult;
-///     fn name(&self) -> &'static str { "add" }
-///     fn description(&self) -> &'static str { "Add two numbers" }
+///     fn name(&self) -> String { "add".to_string() }
+///     fn description(&self) -> String { "Add two numbers".to_string() }
 ///     async fn execute(&self, p: Self::Params) -> ToolResult<Self::Output> {
 ///         Ok(AddResult { sum: p.a + p.b })
 ///     }
@@ -61,6 +89,16 @@ use super::tool_trait::Tool;
 #[derive(Clone)]
 pub struct ToolRegistry {
     tools: Arc<RwLock<HashMap<String, Arc<dyn ErasedTool>>>>,
+    /// Maximum number of calls run at once by `execute_all` (None = all)
+    max_concurrency: Option<usize>,
+    /// Default timeout for a single call
+    timeout: Option<Duration>,
+    /// Timeouts for specific tools, overriding `timeout`
+    tool_timeouts: HashMap<String, Duration>,
+    /// Timeout for a whole `execute_all` batch
+    total_timeout: Option<Duration>,
+    /// Check arguments against the parameter schema before execution
+    validate_arguments: bool,
 }
 
 impl Default for ToolRegistry {
@@ -74,18 +112,78 @@ impl ToolRegistry {
     pub fn new() -> Self {
         Self {
             tools: Arc::new(RwLock::new(HashMap::new())),
+            max_concurrency: None,
+            timeout: None,
+            tool_timeouts: HashMap::new(),
+            total_timeout: None,
+            validate_arguments: true,
         }
     }
 
+    /// Limit how many calls `execute_all` runs at once
+    ///
+    /// By default all calls of a response run concurrently. A limit of 1
+    /// runs them one after another.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency.max(1));
+        self
+    }
+
+    /// Set the default timeout for a single tool call
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the timeout for calls to one tool, overriding the default
+    pub fn with_tool_timeout(mut self, name: impl Into<String>, timeout: Duration) -> Self {
+        self.tool_timeouts.insert(name.into(), timeout);
+        self
+    }
+
+    /// Set the timeout for a whole `execute_all` batch
+    ///
+    /// Calls still running, or not yet started, when it expires fail with
+    /// [`ToolError::Timeout`]; results of finished calls are kept.
+    pub fn with_total_timeout(mut self, timeout: Duration) -> Self {
+        self.total_timeout = Some(timeout);
+        self
+    }
+
+    /// Enable or disable argument validation (enabled by default)
+    ///
+    /// When disabled, arguments are only checked by deserializing them
+    /// into the tool's parameter type.
+    pub fn with_argument_validation(mut self, enabled: bool) -> Self {
+        self.validate_arguments = enabled;
+        self
+    }
+
     /// Register a tool with the registry
     ///
     /// The tool's name is used as the key for dispatch.
     pub fn register<T: Tool + 'static>(&mut self, tool: T) {
-        let name = tool.name().to_string();
+        let name = tool.name();
         let wrapper = Arc::new(ToolWrapper::new(tool));
         self.tools.write().unwrap().insert(name, wrapper);
     }
 
+    /// Register a runtime-defined tool with the registry
+    ///
+    /// The name in the tool's definition is used as the key for dispatch.
+    /// A tool already registered under that name is replaced.
+    pub fn register_dynamic(&mut self, tool: DynamicTool) {
+        let name = tool.name().to_string();
+        self.tools.write().unwrap().insert(name, Arc::new(tool));
+    }
+
+    /// Remove a tool from the registry
+    ///
+    /// Returns true if a tool with the given name was registered.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.tools.write().unwrap().remove(name).is_some()
+    }
+
     /// Get the number of registered tools
     pub fn len(&self) -> usize {
         self.tools.read().unwrap().len()
@@ -113,12 +211,14 @@ impl ToolRegistry {
             .read()
             .unwrap()
             .values()
-            .map(|t| t.definition())
+            .map(|t| t.definition().clone())
             .collect()
     }
 
     /// Execute a single tool call asynchronously
     ///
+    /// The call is bounded by its tool timeout or the default timeout.
+    ///
     /// # Arguments
     ///
     /// * `call` - The tool call from a chat response
@@ -127,53 +227,96 @@ impl ToolRegistry {
     ///
     /// Returns the tool's output as JSON, or an error if:
     /// - The tool is not found
+    /// - The arguments do not match the tool's parameter schema
     /// - The arguments are invalid
     /// - The tool execution fails
+    /// - The tool times out
     pub async fn execute(&self, call: &ToolCall) -> ToolResult<serde_json::Value> {
-        let func_name = call.function_name().ok_or(ToolError::InvalidToolCall)?;
-
-        let args = call
-            .arguments()
-            .cloned()
-            .unwrap_or_else(|| serde_json::json!({}));
-
-        let tool = {
-            let tools = self.tools.read().unwrap();
-            tools
-                .get(func_name)
-                .cloned()
-                .ok_or_else(|| ToolError::NotFound(func_name.to_string()))?
-        };
-
-        tool.execute_erased(args).await
+        self.execute_until(call, None).await
     }
 
     /// Execute all tool calls from a chat response asynchronously
     ///
+    /// Calls run concurrently; see [`execute_calls`](Self::execute_calls).
+    ///
     /// # Arguments
     ///
     /// * `response` - The chat response that may contain tool calls
     ///
     /// # Returns
     ///
-    /// Returns a vector of results, one for each tool call.
+    /// Returns a vector of results, one for each tool call, in call order.
     /// If the response has no tool calls, returns an empty vector.
     pub async fn execute_all(&self, response: &ChatResponse) -> Vec<ToolResult<serde_json::Value>> {
-        let Some(calls) = response.tool_calls() else {
-            return Vec::new();
-        };
-
-        let mut results = Vec::with_capacity(calls.len());
-        for call in calls {
-            results.push(self.execute(call).await);
+        match response.tool_calls() {
+            Some(calls) => self.execute_calls(calls).await,
+            None => Vec::new(),
         }
-        results
+    }
+
+    /// Execute tool calls concurrently
+    ///
+    /// At most `max_concurrency` calls run at once. Each call is bounded
+    /// by its timeout and the batch by the total timeout.
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of results, one for each tool call, in call order.
+    pub async fn execute_calls(&self, calls: &[ToolCall]) -> Vec<ToolResult<serde_json::Value>> {
+        let deadline = self.total_timeout.map(|timeout| Instant::now() + timeout);
+        let limit = self.max_concurrency.unwrap_or(calls.len()).max(1);
+
+        // Futures are built up front rather than in a stream `map`: the
+        // closure would keep the future from being provably `Send`, which
+        // `execute_calls_blocking` needs
+        let calls: Vec<_> = calls
+            .iter()
+            .map(|call| self.execute_until(call, deadline))
+            .collect();
+        futures::stream::iter(calls).buffered(limit).collect().await
     }
 
     /// Execute a single tool call synchronously (blocking)
     ///
     /// This is a convenience method for sync code.
     pub fn execute_blocking(&self, call: &ToolCall) -> ToolResult<serde_json::Value> {
+        block_on(self.execute(call))?
+    }
+
+    /// Execute all tool calls from a chat response synchronously (blocking)
+    ///
+    /// This is a convenience method for sync code. Calls still run
+    /// concurrently, with the same limit and timeouts as
+    /// [`execute_all`](Self::execute_all).
+    pub fn execute_all_blocking(
+        &self,
+        response: &ChatResponse,
+    ) -> Vec<ToolResult<serde_json::Value>> {
+        match response.tool_calls() {
+            Some(calls) => self.execute_calls_blocking(calls),
+            None => Vec::new(),
+        }
+    }
+
+    /// Execute tool calls concurrently (blocking)
+    ///
+    /// Blocking counterpart of [`execute_calls`](Self::execute_calls).
+    pub fn execute_calls_blocking(&self, calls: &[ToolCall]) -> Vec<ToolResult<serde_json::Value>> {
+        block_on(self.execute_calls(calls)).unwrap_or_else(|e| {
+            let message = e.to_string();
+            calls
+                .iter()
+                .map(|_| Err(ToolError::ExecutionError(message.clone())))
+                .collect()
+        })
+    }
+
+    /// Execute a call, bounded by its timeout and an optional deadline
+    async fn execute_until(
+        &self,
+        call: &ToolCall,
+        deadline: Option<Instant>,
+    ) -> ToolResult<serde_json::Value> {
         let func_name = call.function_name().ok_or(ToolError::InvalidToolCall)?;
 
         let args = call
@@ -189,24 +332,43 @@ impl ToolRegistry {
                 .ok_or_else(|| ToolError::NotFound(func_name.to_string()))?
         };
 
-        tool.execute_erased_blocking(args)
-    }
+        if self.validate_arguments {
+            let violations =
+                schema_validation::validate(&tool.definition().function.parameters, &args);
+            if !violations.is_empty() {
+                return Err(ToolError::InvalidArguments {
+                    name: func_name.to_string(),
+                    violations,
+                });
+            }
+        }
 
-    /// Execute all tool calls from a chat response synchronously (blocking)
-    ///
-    /// This is a convenience method for sync code.
-    pub fn execute_all_blocking(
-        &self,
-        response: &ChatResponse,
-    ) -> Vec<ToolResult<serde_json::Value>> {
-        let Some(calls) = response.tool_calls() else {
-            return Vec::new();
+        let started = Instant::now();
+        // A call queued behind the concurrency limit may only get its turn
+        // after the total timeout; it must not start then, even briefly
+        if deadline.is_some_and(|deadline| started >= deadline) {
+            return Err(ToolError::Timeout {
+                name: func_name.to_string(),
+                elapsed: Duration::ZERO,
+            });
+        }
+        let timeout = self.tool_timeouts.get(func_name).copied().or(self.timeout);
+        let until = match (timeout.map(|timeout| started + timeout), deadline) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
         };
 
-        calls
-            .iter()
-            .map(|call| self.execute_blocking(call))
-            .collect()
+        let Some(until) = until else {
+            return tool.execute_erased(args).await;
+        };
+        tokio::time::timeout_at(until, tool.execute_erased(args))
+            .await
+            .unwrap_or_else(|_| {
+                Err(ToolError::Timeout {
+                    name: func_name.to_string(),
+                    elapsed: started.elapsed(),
+                })
+            })
     }
 }
 
@@ -216,6 +378,11 @@ impl std::fmt::Debug for ToolRegistry {
         let names: Vec<_> = tools.keys().collect();
         f.debug_struct("ToolRegistry")
             .field("tools", &names)
+            .field("validate_arguments", &self.validate_arguments)
+            .field("max_concurrency", &self.max_concurrency)
+            .field("timeout", &self.timeout)
+            .field("tool_timeouts", &self.tool_timeouts)
+            .field("total_timeout", &self.total_timeout)
             .finish()
     }
 }
@@ -227,6 +394,7 @@ mod tests {
     use crate::{ResponseMessage, ToolCallFunction};
     use schemars::JsonSchema;
     use serde::{Deserialize, Serialize};
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[derive(Debug, Deserialize, JsonSchema)]
     struct MultiplyParams {
@@ -245,12 +413,12 @@ mod tests {
         type Params = MultiplyParams;
         type Output = MultiplyResult;
 
-        fn name(&self) -> &'static str {
-            "multiply"
+        fn name(&self) -> String {
+            "multiply".to_string()
         }
 
-        fn description(&self) -> &'static str {
-            "Multiply two numbers"
+        fn description(&self) -> String {
+            "Multiply two numbers".to_string()
         }
 
         async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -263,6 +431,7 @@ mod tests {
     #[derive(Debug, Deserialize, JsonSchema)]
     struct GreetParams {
         name: String,
+        title: Option<String>,
     }
 
     #[derive(Serialize)]
@@ -276,21 +445,69 @@ mod tests {
         type Params = GreetParams;
         type Output = GreetResult;
 
-        fn name(&self) -> &'static str {
-            "greet"
+        fn name(&self) -> String {
+            "greet".to_string()
         }
 
-        fn description(&self) -> &'static str {
-            "Greet someone"
+        fn description(&self) -> String {
+            "Greet someone".to_string()
         }
 
         async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
             Ok(GreetResult {
-                greeting: format!("Hello, {}!", params.name),
+                greeting: match params.title {
+                    Some(title) => format!("Hello, {} {}!", title, params.name),
+                    None => format!("Hello, {}!", params.name),
+                },
             })
         }
     }
 
+    #[derive(Debug, Deserialize, JsonSchema)]
+    struct SleepParams {
+        id: u32,
+        millis: u64,
+    }
+
+    #[derive(Serialize)]
+    struct SleepResult {
+        id: u32,
+    }
+
+    struct SleepTool;
+
+    impl Tool for SleepTool {
+        type Params = SleepParams;
+        type Output = SleepResult;
+
+        fn name(&self) -> String {
+            "sleep".to_string()
+        }
+
+        fn description(&self) -> String {
+            "Sleep for a while".to_string()
+        }
+
+        async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
+            tokio::time::sleep(Duration::from_millis(params.millis)).await;
+            Ok(SleepResult { id: params.id })
+        }
+    }
+
+    fn sleep_call(id: u32, millis: u64) -> ToolCall {
+        ToolCall::new(ToolCallFunction::with_arguments(
+            "sleep",
+            serde_json::json!({"id": id, "millis": millis}),
+        ))
+    }
+
+    fn sleep_registry() -> ToolRegistry {
+        let mut registry = ToolRegistry::new();
+        registry.register(SleepTool);
+        registry.register(MultiplyTool);
+        registry
+    }
+
     #[test]
     fn test_registry_new() {
         let registry = ToolRegistry::new();
@@ -403,6 +620,249 @@ mod tests {
         assert!(results.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_registry_execute_calls_concurrently_in_order() {
+        let registry = sleep_registry();
+        let calls = vec![sleep_call(1, 300), sleep_call(2, 10), sleep_call(3, 150)];
+
+        let started = std::time::Instant::now();
+        let results = registry.execute_calls(&calls).await;
+        let elapsed = started.elapsed();
+
+        let ids: Vec<_> = results
+            .iter()
+            .map(|r| r.as_ref().unwrap()["id"].clone())
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert!(elapsed < Duration::from_millis(450), "took {:?}", elapsed);
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_calls_max_concurrency() {
+        let registry = sleep_registry().with_max_concurrency(1);
+        let calls = vec![sleep_call(1, 100), sleep_call(2, 100), sleep_call(3, 100)];
+
+        let started = std::time::Instant::now();
+        let results = registry.execute_calls(&calls).await;
+
+        assert!(started.elapsed() >= Duration::from_millis(300));
+        assert!(results.iter().all(|r| r.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_registry_tool_timeout() {
+        let registry = sleep_registry()
+            .with_timeout(Duration::from_secs(5))
+            .with_tool_timeout("sleep", Duration::from_millis(50));
+        let calls = vec![
+            sleep_call(1, 5_000),
+            ToolCall::new(ToolCallFunction::with_arguments(
+                "multiply",
+                serde_json::json!({"a": 2, "b": 3}),
+            )),
+        ];
+
+        let started = std::time::Instant::now();
+        let results = registry.execute_calls(&calls).await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(matches!(
+            &results[0],
+            Err(ToolError::Timeout { name, .. }) if name == "sleep"
+        ));
+        assert_eq!(results[1].as_ref().unwrap()["product"], 6);
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_default_timeout() {
+        let registry = sleep_registry().with_timeout(Duration::from_millis(50));
+
+        let result = registry.execute(&sleep_call(1, 5_000)).await;
+        assert!(matches!(result, Err(ToolError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_registry_total_timeout_keeps_finished_results() {
+        let registry = sleep_registry()
+            .with_max_concurrency(1)
+            .with_total_timeout(Duration::from_millis(200));
+        let calls = vec![sleep_call(1, 10), sleep_call(2, 5_000), sleep_call(3, 10)];
+
+        let started = std::time::Instant::now();
+        let results = registry.execute_calls(&calls).await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(results[0].as_ref().unwrap()["id"], 1);
+        assert!(matches!(results[1], Err(ToolError::Timeout { .. })));
+        assert!(matches!(results[2], Err(ToolError::Timeout { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_registry_total_timeout_does_not_start_queued_calls() {
+        let started_calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&started_calls);
+        let mut registry = ToolRegistry::new()
+            .with_max_concurrency(1)
+            .with_total_timeout(Duration::from_millis(50));
+        registry.register_dynamic(DynamicTool::new(
+            ToolDefinition::function("block", serde_json::json!({"type": "object"})),
+            move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                // Blocks the thread, so the deadline passes without the
+                // timeout getting a chance to interrupt the first call
+                std::thread::sleep(Duration::from_millis(100));
+                async { Ok(serde_json::json!({})) }
+            },
+        ));
+        let call = ToolCall::new(ToolCallFunction::with_arguments(
+            "block",
+            serde_json::json!({}),
+        ));
+
+        let results = registry.execute_calls(&[call.clone(), call]).await;
+
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(ToolError::Timeout { .. })));
+        assert_eq!(started_calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_registry_execute_calls_blocking_concurrently() {
+        let registry = sleep_registry();
+        let calls = vec![sleep_call(1, 200), sleep_call(2, 200)];
+
+        let started = std::time::Instant::now();
+        let results = registry.execute_calls_blocking(&calls);
+
+        assert!(started.elapsed() < Duration::from_millis(350));
+        assert_eq!(results[1].as_ref().unwrap()["id"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_calls_blocking_inside_current_thread_runtime() {
+        let registry = sleep_registry();
+        let calls = vec![sleep_call(1, 10), sleep_call(2, 10)];
+
+        let results = registry.execute_calls_blocking(&calls);
+
+        assert_eq!(results[0].as_ref().unwrap()["id"], 1);
+        assert_eq!(results[1].as_ref().unwrap()["id"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_registry_register_dynamic() {
+        let mut registry = ToolRegistry::new();
+        registry.register(MultiplyTool);
+        registry.register_dynamic(DynamicTool::new(
+            ToolDefinition::function("upper", serde_json::json!({"type": "object"}))
+                .with_description("Uppercase text"),
+            |args| async move {
+                let text = args["text"].as_str().unwrap_or_default();
+                Ok(serde_json::json!({ "text": text.to_uppercase() }))
+            },
+        ));
+
+        assert_eq!(registry.len(), 2);
+        assert!(registry.definitions().iter().any(|d| d.name() == "upper"));
+
+        let call = ToolCall::new(ToolCallFunction::with_arguments(
+            "upper",
+            serde_json::json!({"text": "abc"}),
+        ));
+        let result = registry.execute(&call).await.unwrap();
+        assert_eq!(result["text"], "ABC");
+    }
+
+    #[test]
+    fn test_registry_unregister() {
+        let mut registry = ToolRegistry::new();
+        registry.register(MultiplyTool);
+        registry.register(GreetTool);
+
+        assert!(registry.unregister("multiply"));
+        assert!(!registry.unregister("multiply"));
+        assert_eq!(registry.len(), 1);
+        assert!(!registry.contains("multiply"));
+        assert!(registry.contains("greet"));
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_invalid_arguments() {
+        let mut registry = ToolRegistry::new();
+        registry.register(MultiplyTool);
+
+        let call = ToolCall::new(ToolCallFunction::with_arguments(
+            "multiply",
+            serde_json::json!({"a": "three"}),
+        ));
+        let result = registry.execute(&call).await;
+
+        let Err(ToolError::InvalidArguments { name, violations }) = result else {
+            panic!("expected InvalidArguments, got {:?}", result);
+        };
+        assert_eq!(name, "multiply");
+        let violations: Vec<_> = violations.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            violations,
+            vec![
+                "$.b: required property is missing",
+                "$.a: expected integer, got string",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_explicit_null_optional_argument() {
+        let mut registry = ToolRegistry::new();
+        registry.register(GreetTool);
+
+        let call = ToolCall::new(ToolCallFunction::with_arguments(
+            "greet",
+            serde_json::json!({"name": "Alice", "title": null}),
+        ));
+        let result = registry.execute(&call).await.unwrap();
+
+        assert_eq!(result["greeting"], "Hello, Alice!");
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_without_argument_validation() {
+        let mut registry = ToolRegistry::new().with_argument_validation(false);
+        registry.register(MultiplyTool);
+
+        let call = ToolCall::new(ToolCallFunction::with_arguments(
+            "multiply",
+            serde_json::json!({"a": "three"}),
+        ));
+        let result = registry.execute(&call).await;
+
+        assert!(matches!(result, Err(ToolError::DeserializationError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_registry_validates_dynamic_tools() {
+        let mut registry = ToolRegistry::new();
+        registry.register_dynamic(DynamicTool::new(
+            ToolDefinition::function(
+                "echo",
+                serde_json::json!({
+                    "type": "object",
+                    "properties": {"text": {"type": "string"}},
+                    "required": ["text"]
+                }),
+            ),
+            |args| async move { Ok(args) },
+        ));
+
+        let call = ToolCall::new(ToolCallFunction::with_arguments(
+            "echo",
+            serde_json::json!({}),
+        ));
+        let result = registry.execute(&call).await;
+
+        assert!(matches!(result, Err(ToolError::InvalidArguments { .. })));
+    }
+
     #[test]
     fn test_registry_clone() {
         let mut registry = ToolRegistry::new();
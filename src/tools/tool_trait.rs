@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::ToolDefinition;
 
+use super::schema_normalization::normalize_schema;
 use super::tool_error::ToolResult;
 
 /// Trait for defining type-safe tools with auto-generated JSON schemas
@@ -50,12 +51,12 @@ use super::tool_error::ToolResult;
 ///     type Params = WeatherParams;
 ///     type Output = WeatherResult;
 ///
-///     fn name(&self) -> &'static str {
-///         "get_weather"
+///     fn name(&self) -> String {
+///         "get_weather".to_string()
 ///     }
 ///
-///     fn description(&self) -> &'static str {
-///         "Get the current weather for a location"
+///     fn description(&self) -> String {
+///         "Get the current weather for a location".to_string()
 ///     }
 ///
 ///     async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -74,10 +75,10 @@ pub trait Tool: Send + Sync {
     type Output: Serialize + Send;
 
     /// Returns the name of the tool (used as function name)
-    fn name(&self) -> &'static str;
+    fn name(&self) -> String;
 
     /// Returns a description of what the tool does
-    fn description(&self) -> &'static str;
+    fn description(&self) -> String;
 
     /// Execute the tool with the given parameters
     ///
@@ -89,10 +90,14 @@ pub trait Tool: Send + Sync {
 
     /// Generate the JSON schema for the tool's parameters
     ///
-    /// This is auto-implemented using `schemars`.
+    /// This is auto-implemented using `schemars`, followed by
+    /// [`normalize_schema`] so the schema is self-contained and free of
+    /// constructs small models handle poorly.
     fn parameters_schema(&self) -> serde_json::Value {
         let schema = schema_for!(Self::Params);
-        serde_json::to_value(schema).unwrap_or_else(|_| serde_json::json!({}))
+        serde_json::to_value(schema)
+            .map(normalize_schema)
+            .unwrap_or_else(|_| serde_json::json!({}))
     }
 
     /// Convert this tool to a `ToolDefinition` for use in chat requests
@@ -125,12 +130,12 @@ mod tests {
         type Params = TestParams;
         type Output = TestOutput;
 
-        fn name(&self) -> &'static str {
-            "test_tool"
+        fn name(&self) -> String {
+            "test_tool".to_string()
         }
 
-        fn description(&self) -> &'static str {
-            "A test tool"
+        fn description(&self) -> String {
+            "A test tool".to_string()
         }
 
         async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
@@ -0,0 +1,381 @@
+//! Tests for the tool-calling agent loop
+//!
+//! All tests use mockito for HTTP mocking - no real Ollama server required.
+
+use async_trait::async_trait;
+use mockito::{Matcher, Server};
+use ollama_oxide::conveniences::Agent;
+use ollama_oxide::tools::{Tool, ToolError, ToolRegistry, ToolResult};
+use ollama_oxide::{
+    ChatMessage, ChatRequest, ClientConfig, Error, OllamaClient, Result, Transport,
+    TransportRequest, TransportResponse,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct AddParams {
+    a: i32,
+    b: i32,
+}
+
+#[derive(Serialize)]
+struct AddResult {
+    sum: i32,
+}
+
+struct AddTool;
+
+impl Tool for AddTool {
+    type Params = AddParams;
+    type Output = AddResult;
+
+    fn name(&self) -> String {
+        "add".to_string()
+    }
+
+    fn description(&self) -> String {
+        "Add two numbers".to_string()
+    }
+
+    async fn execute(&self, params: Self::Params) -> ToolResult<Self::Output> {
+        if params.a < 0 {
+            return Err(ToolError::ExecutionError("negative input".to_string()));
+        }
+        Ok(AddResult {
+            sum: params.a + params.b,
+        })
+    }
+}
+
+fn make_config(base_url: String) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    }
+}
+
+fn agent() -> Agent {
+    let mut registry = ToolRegistry::new();
+    registry.register(AddTool);
+    Agent::new(registry)
+}
+
+fn request() -> ChatRequest {
+    ChatRequest::new("qwen3:0.6b", [ChatMessage::user("What is 2 + 3?")])
+}
+
+fn tool_call_body(a: i32, b: i32) -> String {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [{"function": {"name": "add", "arguments": {"a": a, "b": b}}}]
+        },
+        "done": true
+    })
+    .to_string()
+}
+
+fn answer_body(content: &str) -> String {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": true
+    })
+    .to_string()
+}
+
+// ============================================================================
+// Async Agent Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_agent_run_executes_tools_until_answer() {
+    let mut server = Server::new_async().await;
+    let first = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::PartialJson(json!({
+            "tools": [{"type": "function", "function": {"name": "add"}}]
+        })))
+        .with_status(200)
+        .with_body(tool_call_body(2, 3))
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::PartialJson(json!({
+            "messages": [
+                {"role": "user", "content": "What is 2 + 3?"},
+                {"role": "assistant", "tool_calls": [{"function": {"name": "add"}}]},
+                {"role": "tool", "content": "{\"sum\":5}", "tool_name": "add"}
+            ]
+        })))
+        .with_status(200)
+        .with_body(answer_body("2 + 3 = 5"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let run = agent().run(&client, &request()).await.unwrap();
+
+    assert_eq!(run.response.content(), Some("2 + 3 = 5"));
+    assert_eq!(run.iterations, 2);
+    assert_eq!(run.tool_invocations.len(), 1);
+    assert_eq!(run.tool_invocations[0].iteration, 1);
+    assert_eq!(run.tool_invocations[0].call.function_name(), Some("add"));
+    assert_eq!(run.tool_invocations[0].result.as_ref().unwrap()["sum"], 5);
+    assert_eq!(run.messages.len(), 4);
+    assert!(run.messages[3].is_assistant());
+    first.assert_async().await;
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agent_run_keeps_thinking() {
+    let mut server = Server::new_async().await;
+    let _first = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(
+            json!({
+                "model": "qwen3:0.6b",
+                "message": {
+                    "role": "assistant",
+                    "content": "",
+                    "thinking": "I should use the add tool.",
+                    "tool_calls": [{"function": {"name": "add", "arguments": {"a": 2, "b": 3}}}]
+                },
+                "done": true
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::PartialJson(json!({
+            "messages": [
+                {"role": "user"},
+                {"role": "assistant", "thinking": "I should use the add tool."},
+                {"role": "tool", "tool_name": "add"}
+            ]
+        })))
+        .with_status(200)
+        .with_body(
+            json!({
+                "model": "qwen3:0.6b",
+                "message": {"role": "assistant", "content": "5", "thinking": "Done."},
+                "done": true
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let run = agent().run(&client, &request()).await.unwrap();
+
+    assert_eq!(
+        run.messages[1].thinking.as_deref(),
+        Some("I should use the add tool.")
+    );
+    assert_eq!(run.messages[2].tool_name.as_deref(), Some("add"));
+    assert_eq!(run.messages[3].thinking.as_deref(), Some("Done."));
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agent_run_without_tool_calls() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(answer_body("Hello"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let run = agent().run(&client, &request()).await.unwrap();
+
+    assert_eq!(run.iterations, 1);
+    assert!(run.tool_invocations.is_empty());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agent_run_sends_tool_errors_to_model() {
+    let mut server = Server::new_async().await;
+    let _first = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(tool_call_body(-1, 3))
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::Regex(
+            "Error: execution error: negative input".to_string(),
+        ))
+        .with_status(200)
+        .with_body(answer_body("I cannot add negative numbers"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let run = agent().run(&client, &request()).await.unwrap();
+
+    assert!(run.tool_invocations[0].result.is_err());
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agent_run_sends_schema_violations_to_model() {
+    let mut server = Server::new_async().await;
+    let _first = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(
+            json!({
+                "model": "qwen3:0.6b",
+                "message": {
+                    "role": "assistant",
+                    "content": "",
+                    "tool_calls": [{"function": {"name": "add", "arguments": {"a": "two"}}}]
+                },
+                "done": true
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let second = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r"invalid arguments for tool 'add'".to_string()),
+            Matcher::Regex(r"- \$\.b: required property is missing".to_string()),
+            Matcher::Regex(r"- \$\.a: expected integer, got string".to_string()),
+        ]))
+        .with_status(200)
+        .with_body(answer_body("2 + 3 = 5"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let run = agent().run(&client, &request()).await.unwrap();
+
+    assert!(matches!(
+        run.tool_invocations[0].result,
+        Err(ToolError::InvalidArguments { .. })
+    ));
+    second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agent_run_stops_at_max_iterations() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(tool_call_body(1, 1))
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let result = agent()
+        .with_max_iterations(3)
+        .run(&client, &request())
+        .await;
+
+    assert!(matches!(result, Err(Error::MaxIterationsExceededError(3))));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_agent_run_propagates_chat_errors() {
+    let mut server = Server::new_async().await;
+    let _mock = server
+        .mock("POST", "/api/chat")
+        .with_status(404)
+        .with_body(r#"{"error": "model 'qwen3:0.6b' not found"}"#)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let result = agent().run(&client, &request()).await;
+
+    assert!(result.unwrap_err().is_model_not_found());
+}
+
+// ============================================================================
+// Blocking Agent Tests
+// ============================================================================
+
+#[test]
+fn test_agent_run_blocking_executes_tools_until_answer() {
+    let mut server = Server::new();
+    let first = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(tool_call_body(4, 5))
+        .expect(1)
+        .create();
+    let second = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::Regex(r#"\{\\"sum\\":9\}"#.to_string()))
+        .with_status(200)
+        .with_body(answer_body("9"))
+        .expect(1)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let run = agent().run_blocking(&client, &request()).unwrap();
+
+    assert_eq!(run.response.content(), Some("9"));
+    assert_eq!(run.tool_invocations.len(), 1);
+    first.assert();
+    second.assert();
+}
+
+/// Transport answering chat requests with a fixed list of bodies, in order
+#[derive(Debug)]
+struct ScriptedTransport(Mutex<Vec<String>>);
+
+#[async_trait]
+impl Transport for ScriptedTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        self.send_blocking(request)
+    }
+
+    fn send_blocking(&self, _request: TransportRequest) -> Result<TransportResponse> {
+        let body = self.0.lock().unwrap().remove(0);
+        Ok(TransportResponse::json(200, &serde_json::from_str(&body)?))
+    }
+}
+
+#[tokio::test]
+async fn test_agent_run_blocking_inside_current_thread_runtime() {
+    let transport = ScriptedTransport(Mutex::new(vec![tool_call_body(1, 2), answer_body("3")]));
+    let client = OllamaClient::with_transport(ClientConfig::default(), transport).unwrap();
+
+    let run = agent().run_blocking(&client, &request()).unwrap();
+
+    assert_eq!(run.response.content(), Some("3"));
+    assert_eq!(run.tool_invocations.len(), 1);
+}
@@ -0,0 +1,324 @@
+//! Tests for recording OllamaClient traffic to cassettes and replaying it
+
+use futures::StreamExt;
+use ollama_oxide::testing::{Cassette, CassetteMode, MockOllama, MockResponse};
+use ollama_oxide::{
+    ChatMessage, ChatRequest, ClientConfig, Error, OllamaApiAsync, OllamaApiSync, OllamaClient,
+    PullRequest,
+};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+fn make_config(base_url: String, mode: CassetteMode) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(30),
+        max_retries: 2,
+        ..Default::default()
+    }
+    .with_cassette(mode)
+}
+
+/// Cassette path in a temporary directory removed when dropped
+struct TempCassette(PathBuf);
+
+impl TempCassette {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!(
+            "ollama-oxide-cassette-{}-{}",
+            std::process::id(),
+            name
+        ));
+        Self(dir.join("cassette.json"))
+    }
+
+    fn record(&self) -> CassetteMode {
+        CassetteMode::Record(self.0.clone())
+    }
+
+    fn replay(&self) -> CassetteMode {
+        CassetteMode::Replay(self.0.clone())
+    }
+
+    fn replay_timed(&self) -> CassetteMode {
+        CassetteMode::ReplayTimed(self.0.clone())
+    }
+}
+
+impl Drop for TempCassette {
+    fn drop(&mut self) {
+        if let Some(dir) = self.0.parent() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+fn chat_reply(content: &str, done: bool) -> Value {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": done
+    })
+}
+
+fn chat_request(prompt: &str) -> ChatRequest {
+    ChatRequest::new("qwen3:0.6b", [ChatMessage::user(prompt)])
+}
+
+/// Replay client whose base URL has no server behind it
+fn replay_client(cassette: &TempCassette) -> OllamaClient {
+    replay_client_with(cassette.replay())
+}
+
+fn replay_client_with(mode: CassetteMode) -> OllamaClient {
+    OllamaClient::new(make_config("http://127.0.0.1:9".to_string(), mode)).unwrap()
+}
+
+// ============================================================================
+// Record Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_record_writes_interactions() {
+    let cassette = TempCassette::new("record");
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!", true)));
+    let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+
+    client.chat(&chat_request("Hello")).await.unwrap();
+
+    let recorded = Cassette::load(&cassette.0).unwrap();
+    assert_eq!(recorded.interactions.len(), 1);
+    let interaction = &recorded.interactions[0];
+    assert_eq!(interaction.request.method, "POST");
+    assert_eq!(interaction.request.path, "/api/chat");
+    assert_eq!(
+        interaction.request.body.as_ref().unwrap()["messages"][0]["content"],
+        "Hello"
+    );
+    assert_eq!(interaction.response.status, 200);
+    let body: Value = serde_json::from_str(&interaction.response.body).unwrap();
+    assert_eq!(body["message"]["content"], "Hi!");
+}
+
+#[tokio::test]
+async fn test_record_stream_keeps_chunks_and_timings() {
+    let cassette = TempCassette::new("record-stream");
+    let server = MockOllama::start().unwrap();
+    server.on_pull(
+        MockResponse::stream([
+            json!({"status": "pulling manifest"}),
+            json!({"status": "success"}),
+        ])
+        .with_chunk_delay(Duration::from_millis(50)),
+    );
+    let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+
+    let events: Vec<_> = client
+        .pull_model_stream(&PullRequest::new("llama3.2"))
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(events.len(), 2);
+
+    let response = &Cassette::load(&cassette.0).unwrap().interactions[0].response;
+    assert_eq!(response.chunks.len(), 2);
+    assert!(response.body.is_empty());
+    assert_eq!(
+        response.chunks[0].data,
+        "{\"status\":\"pulling manifest\"}\n"
+    );
+    assert!(response.chunks[1].offset_ms >= response.chunks[0].offset_ms + 40);
+}
+
+// ============================================================================
+// Replay Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_replay_without_server() {
+    let cassette = TempCassette::new("replay");
+    {
+        let server = MockOllama::start().unwrap();
+        server
+            .on_chat(MockResponse::error(503, "busy"))
+            .on_chat(MockResponse::json(chat_reply("ok", true)))
+            .on_version(MockResponse::json(json!({"version": "0.12.6"})));
+        let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+
+        client.chat(&chat_request("Hello")).await.unwrap();
+        client.version().await.unwrap();
+    }
+
+    let client = replay_client(&cassette);
+
+    // The retry is replayed too: 503 first, then 200
+    let response = client.chat(&chat_request("Hello")).await.unwrap();
+    assert_eq!(response.content(), Some("ok"));
+    assert_eq!(client.version().await.unwrap().version, "0.12.6");
+}
+
+#[test]
+fn test_replay_stream_blocking() {
+    let cassette = TempCassette::new("replay-stream");
+    {
+        let server = MockOllama::start().unwrap();
+        server.on_pull(MockResponse::stream([
+            json!({"status": "pulling manifest"}),
+            json!({"status": "downloading", "digest": "sha256:ab", "total": 10, "completed": 5}),
+            json!({"status": "success"}),
+        ]));
+        let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+
+        let events: Vec<_> = client
+            .pull_model_stream_blocking(&PullRequest::new("llama3.2"))
+            .unwrap()
+            .collect();
+        assert_eq!(events.len(), 3);
+    }
+
+    let client = replay_client(&cassette);
+    let events: Vec<_> = client
+        .pull_model_stream_blocking(&PullRequest::new("llama3.2"))
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect();
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1].completed, Some(5));
+    assert!(events[2].is_success());
+}
+
+#[tokio::test]
+async fn test_replay_async_stream() {
+    let cassette = TempCassette::new("replay-chat-stream");
+    {
+        let server = MockOllama::start().unwrap();
+        server.on_chat(MockResponse::stream([
+            chat_reply("Hel", false),
+            chat_reply("lo", true),
+        ]));
+        let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+        let chunks: Vec<_> = client
+            .chat_stream(&chat_request("Hi"))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 2);
+    }
+
+    let client = replay_client(&cassette);
+    let text: String = client
+        .chat_stream(&chat_request("Hi"))
+        .await
+        .unwrap()
+        .map(|chunk| chunk.unwrap().content().unwrap_or_default().to_string())
+        .collect::<Vec<_>>()
+        .await
+        .concat();
+
+    assert_eq!(text, "Hello");
+}
+
+#[tokio::test]
+async fn test_replay_async_stream_keeps_chunk_timings() {
+    let cassette = TempCassette::new("replay-stream-timings");
+    {
+        let server = MockOllama::start().unwrap();
+        server.on_chat(
+            MockResponse::stream([chat_reply("Hel", false), chat_reply("lo", true)])
+                .with_chunk_delay(Duration::from_millis(50)),
+        );
+        let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+        let chunks: Vec<_> = client
+            .chat_stream(&chat_request("Hi"))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 2);
+    }
+    let recorded = &Cassette::load(&cassette.0).unwrap().interactions[0].response;
+    let last_offset = Duration::from_millis(recorded.chunks[1].offset_ms);
+
+    let client = replay_client_with(cassette.replay_timed());
+    let started = Instant::now();
+    let mut stream = client.chat_stream(&chat_request("Hi")).await.unwrap();
+
+    let first = stream.next().await.unwrap().unwrap();
+    assert_eq!(first.content(), Some("Hel"));
+    assert!(started.elapsed() < last_offset);
+    let second = stream.next().await.unwrap().unwrap();
+    assert_eq!(second.content(), Some("lo"));
+    assert!(started.elapsed() >= last_offset);
+}
+
+#[test]
+fn test_replay_does_not_wait_by_default() {
+    let delay = Duration::from_millis(300);
+    let cassette = TempCassette::new("replay-instant");
+    {
+        let server = MockOllama::start().unwrap();
+        server
+            .on_version(MockResponse::json(json!({"version": "0.12.6"})).with_delay(delay))
+            .on_pull(
+                MockResponse::stream([
+                    json!({"status": "pulling manifest"}),
+                    json!({"status": "success"}),
+                ])
+                .with_chunk_delay(delay),
+            );
+        let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+        client.version_blocking().unwrap();
+        let events: Vec<_> = client
+            .pull_model_stream_blocking(&PullRequest::new("llama3.2"))
+            .unwrap()
+            .collect();
+        assert_eq!(events.len(), 2);
+    }
+
+    let client = replay_client(&cassette);
+    let started = Instant::now();
+    assert_eq!(client.version_blocking().unwrap().version, "0.12.6");
+    let events: Vec<_> = client
+        .pull_model_stream_blocking(&PullRequest::new("llama3.2"))
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect();
+
+    assert_eq!(events.len(), 2);
+    assert!(started.elapsed() < delay);
+}
+
+#[test]
+fn test_replay_mismatch_reports_diff() {
+    let cassette = TempCassette::new("mismatch");
+    {
+        let server = MockOllama::start().unwrap();
+        server.on_chat(MockResponse::json(chat_reply("Hi!", true)));
+        let client = OllamaClient::new(make_config(server.url(), cassette.record())).unwrap();
+        client.chat_blocking(&chat_request("Hello")).unwrap();
+    }
+
+    let client = replay_client(&cassette);
+    let result = client.chat_blocking(&chat_request("Goodbye"));
+
+    let Err(Error::CassetteMismatchError(report)) = result else {
+        panic!("expected CassetteMismatchError, got {:?}", result);
+    };
+    assert!(report.contains("POST /api/chat"));
+    assert!(report.contains("-       \"content\": \"Hello\","));
+    assert!(report.contains("+       \"content\": \"Goodbye\","));
+}
+
+#[test]
+fn test_replay_missing_cassette_fails_on_creation() {
+    let cassette = TempCassette::new("missing");
+
+    let result = OllamaClient::new(ClientConfig::default().with_cassette(cassette.replay()));
+
+    assert!(matches!(result, Err(Error::IoError(_))));
+}
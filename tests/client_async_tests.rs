@@ -1,7 +1,7 @@
 // Client Async API Tests - Phase 0 TDD
 // These tests validate the async API functionality using mocking
 
-use ollama_oxide::{ClientConfig, OllamaApiAsync, OllamaClient};
+use ollama_oxide::{ApiErrorKind, ClientConfig, Error, OllamaApiAsync, OllamaClient};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -26,6 +26,7 @@ async fn test_version_async_successful() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -54,6 +55,7 @@ async fn test_version_async_different_version() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -90,6 +92,7 @@ async fn test_version_async_retry_logic_success_on_second_attempt() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -118,6 +121,7 @@ async fn test_version_async_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -127,6 +131,42 @@ async fn test_version_async_max_retries_exceeded() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_version_async_error_body() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/version")
+        .with_status(503)
+        .with_body(r#"{"error":"server busy"}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(1),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let result = client.version().await;
+
+    match result {
+        Err(Error::ApiError {
+            status,
+            kind,
+            message,
+        }) => {
+            assert_eq!(status, 503);
+            assert_eq!(kind, ApiErrorKind::ServerError);
+            assert_eq!(message, "server busy");
+        }
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_version_async_json_deserialization_error() {
     let mut server = mockito::Server::new_async().await;
@@ -143,6 +183,7 @@ async fn test_version_async_json_deserialization_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -168,6 +209,7 @@ async fn test_version_async_invalid_json() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -194,6 +236,7 @@ async fn test_version_async_concurrent_calls() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = Arc::new(OllamaClient::new(config).unwrap());
@@ -233,6 +276,7 @@ async fn test_version_async_404_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 0, // No retries for this test
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -259,6 +303,7 @@ async fn test_version_async_with_zero_retries() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
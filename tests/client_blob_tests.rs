@@ -0,0 +1,318 @@
+//! Unit tests for /api/blobs endpoints and GGUF-based model creation
+//!
+//! All tests use mockito for HTTP mocking - no real Ollama server required.
+
+use mockito::{Matcher, Server};
+use ollama_oxide::{
+    ClientConfig, CreateRequest, Error, OllamaApiAsync, OllamaApiSync, OllamaClient,
+};
+use serde_json::json;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// SHA-256 digest of `MODEL_CONTENT`
+const MODEL_DIGEST: &str =
+    "sha256:b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+const MODEL_CONTENT: &[u8] = b"hello world";
+
+/// SHA-256 digest of `ADAPTER_CONTENT`
+const ADAPTER_DIGEST: &str =
+    "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+const ADAPTER_CONTENT: &[u8] = b"hello";
+
+fn make_config(base_url: String) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(30),
+        max_retries: 0,
+        ..Default::default()
+    }
+}
+
+/// Temporary file removed when dropped
+struct TempFile(PathBuf);
+
+impl TempFile {
+    fn new(name: &str, content: &[u8]) -> Self {
+        let dir =
+            std::env::temp_dir().join(format!("ollama-oxide-blob-{}-{}", std::process::id(), name));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        Self(path)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if let Some(dir) = self.0.parent() {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+// ============================================================================
+// Async Client Tests (with mocking)
+// ============================================================================
+
+#[tokio::test]
+async fn test_blob_exists_async_true() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("HEAD", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    assert!(client.blob_exists(MODEL_DIGEST).await.unwrap());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_blob_exists_async_false_on_404() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("HEAD", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    assert!(!client.blob_exists(MODEL_DIGEST).await.unwrap());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_blob_exists_async_server_error() {
+    let mut server = Server::new_async().await;
+    let _mock = server
+        .mock("HEAD", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .with_status(500)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    let result = client.blob_exists(MODEL_DIGEST).await;
+
+    assert!(matches!(result, Err(Error::HttpStatusError(500))));
+}
+
+#[tokio::test]
+async fn test_push_blob_async_streams_file() {
+    let file = TempFile::new("model.gguf", MODEL_CONTENT);
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .match_header("content-length", "11")
+        .match_body("hello world")
+        .with_status(201)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    client.push_blob(MODEL_DIGEST, &file.0).await.unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_push_blob_async_digest_mismatch() {
+    let file = TempFile::new("mismatch.gguf", MODEL_CONTENT);
+    let mut server = Server::new_async().await;
+    let _mock = server
+        .mock("POST", format!("/api/blobs/{}", ADAPTER_DIGEST).as_str())
+        .with_status(400)
+        .with_body(r#"{"error": "digest mismatch"}"#)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    let result = client.push_blob(ADAPTER_DIGEST, &file.0).await;
+
+    assert!(matches!(result, Err(Error::ApiError { status: 400, .. })));
+}
+
+#[tokio::test]
+async fn test_push_blob_async_missing_file() {
+    let server = Server::new_async().await;
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    let result = client
+        .push_blob(
+            MODEL_DIGEST,
+            std::path::Path::new("/nonexistent/model.gguf"),
+        )
+        .await;
+
+    assert!(matches!(result, Err(Error::IoError(_))));
+}
+
+#[tokio::test]
+async fn test_create_from_gguf_async_uploads_missing_blob() {
+    let file = TempFile::new("llama.gguf", MODEL_CONTENT);
+    let mut server = Server::new_async().await;
+    let head = server
+        .mock("HEAD", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .with_status(404)
+        .create_async()
+        .await;
+    let upload = server
+        .mock("POST", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .match_body("hello world")
+        .with_status(201)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/api/create")
+        .match_body(Matcher::PartialJson(json!({
+            "model": "custom",
+            "files": {"llama.gguf": MODEL_DIGEST},
+            "system": "Be brief.",
+            "stream": false
+        })))
+        .with_status(200)
+        .with_body(r#"{"status": "success"}"#)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::new("custom").with_system("Be brief.");
+
+    let response = client
+        .create_from_gguf(&request, &file.0, &[])
+        .await
+        .unwrap();
+
+    assert!(response.is_success());
+    head.assert_async().await;
+    upload.assert_async().await;
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_from_gguf_async_skips_existing_blobs() {
+    let model = TempFile::new("base.gguf", MODEL_CONTENT);
+    let adapter = TempFile::new("lora.gguf", ADAPTER_CONTENT);
+    let mut server = Server::new_async().await;
+    let _head = server
+        .mock("HEAD", Matcher::Regex("^/api/blobs/sha256:".to_string()))
+        .with_status(200)
+        .expect(2)
+        .create_async()
+        .await;
+    let upload = server
+        .mock("POST", Matcher::Regex("^/api/blobs/".to_string()))
+        .expect(0)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/api/create")
+        .match_body(Matcher::PartialJson(json!({
+            "model": "tuned",
+            "files": {"base.gguf": MODEL_DIGEST},
+            "adapters": {"lora.gguf": ADAPTER_DIGEST}
+        })))
+        .with_status(200)
+        .with_body(r#"{"status": "success"}"#)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::new("tuned");
+
+    let response = client
+        .create_from_gguf(&request, &model.0, &[adapter.0.as_path()])
+        .await
+        .unwrap();
+
+    assert!(response.is_success());
+    upload.assert_async().await;
+    create.assert_async().await;
+}
+
+// ============================================================================
+// Sync Client Tests (with mocking)
+// ============================================================================
+
+#[test]
+fn test_blob_exists_sync() {
+    let mut server = Server::new();
+    let found = server
+        .mock("HEAD", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .with_status(200)
+        .create();
+    let missing = server
+        .mock("HEAD", format!("/api/blobs/{}", ADAPTER_DIGEST).as_str())
+        .with_status(404)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    assert!(client.blob_exists_blocking(MODEL_DIGEST).unwrap());
+    assert!(!client.blob_exists_blocking(ADAPTER_DIGEST).unwrap());
+    found.assert();
+    missing.assert();
+}
+
+#[test]
+fn test_push_blob_sync_streams_file() {
+    let file = TempFile::new("sync.gguf", MODEL_CONTENT);
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .match_header("content-length", "11")
+        .match_body("hello world")
+        .with_status(201)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    client.push_blob_blocking(MODEL_DIGEST, &file.0).unwrap();
+    mock.assert();
+}
+
+#[test]
+fn test_create_from_gguf_sync_with_adapter() {
+    let model = TempFile::new("weights.gguf", MODEL_CONTENT);
+    let adapter = TempFile::new("adapter.gguf", ADAPTER_CONTENT);
+    let mut server = Server::new();
+    let _head_model = server
+        .mock("HEAD", format!("/api/blobs/{}", MODEL_DIGEST).as_str())
+        .with_status(200)
+        .create();
+    let _head_adapter = server
+        .mock("HEAD", format!("/api/blobs/{}", ADAPTER_DIGEST).as_str())
+        .with_status(404)
+        .create();
+    let upload = server
+        .mock("POST", format!("/api/blobs/{}", ADAPTER_DIGEST).as_str())
+        .match_body("hello")
+        .with_status(201)
+        .create();
+    let create = server
+        .mock("POST", "/api/create")
+        .match_body(Matcher::PartialJson(json!({
+            "model": "tuned",
+            "files": {"weights.gguf": MODEL_DIGEST},
+            "adapters": {"adapter.gguf": ADAPTER_DIGEST}
+        })))
+        .with_status(200)
+        .with_body(r#"{"status": "success"}"#)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::new("tuned");
+
+    let response = client
+        .create_from_gguf_blocking(&request, &model.0, &[adapter.0.as_path()])
+        .unwrap();
+
+    assert!(response.is_success());
+    upload.assert();
+    create.assert();
+}
@@ -1,9 +1,10 @@
 //! Tests for chat API methods (POST /api/chat)
 
+use futures::StreamExt;
 use ollama_oxide::{
-    ChatMessage, ChatRequest, ChatResponse, ChatRole, ClientConfig, FormatSetting,
-    KeepAliveSetting, ModelOptions, OllamaApiAsync, OllamaApiSync, OllamaClient, ResponseMessage,
-    ThinkSetting,
+    ApiErrorKind, ChatMessage, ChatRequest, ChatResponse, ChatRole, ClientConfig, Error,
+    FormatSetting, KeepAliveSetting, ModelOptions, OllamaApiAsync, OllamaApiSync, OllamaClient,
+    ResponseMessage, ThinkSetting,
 };
 #[cfg(feature = "tools")]
 use ollama_oxide::{ToolCall, ToolCallFunction, ToolDefinition, ToolFunction};
@@ -597,6 +598,7 @@ async fn test_chat_async_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -636,6 +638,7 @@ async fn test_chat_async_multi_turn() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -683,6 +686,7 @@ async fn test_chat_async_with_tools() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -716,6 +720,7 @@ async fn test_chat_async_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -727,6 +732,107 @@ async fn test_chat_async_model_not_found() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_chat_async_model_needs_pulling() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error":"model \"llama3.2\" not found, try pulling it first"}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("llama3.2", [ChatMessage::user("Hello")]);
+    let error = client.chat(&request).await.unwrap_err();
+
+    match &error {
+        Error::ApiError {
+            status,
+            kind,
+            message,
+        } => {
+            assert_eq!(*status, 404);
+            assert_eq!(*kind, ApiErrorKind::ModelNeedsPulling);
+            assert_eq!(
+                message,
+                "model \"llama3.2\" not found, try pulling it first"
+            );
+        }
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+    assert!(error.is_model_not_found());
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_async_invalid_request() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(400)
+        .with_body(r#"{"error":"invalid message format"}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let error = client.chat(&request).await.unwrap_err();
+
+    assert_eq!(error.status_code(), Some(400));
+    assert_eq!(error.api_error_kind(), Some(ApiErrorKind::InvalidRequest));
+    assert!(error.to_string().contains("invalid message format"));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_async_server_error_message_after_retries() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(500)
+        .with_body(r#"{"error":"llama runner process has terminated"}"#)
+        .expect(2)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 1,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let error = client.chat(&request).await.unwrap_err();
+
+    assert_eq!(error.status_code(), Some(500));
+    assert_eq!(error.api_error_kind(), Some(ApiErrorKind::ServerError));
+
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_chat_async_retry_on_server_error() {
     let mut server = mockito::Server::new_async().await;
@@ -751,6 +857,7 @@ async fn test_chat_async_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -763,6 +870,196 @@ async fn test_chat_async_retry_on_server_error() {
     mock_success.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_chat_async_rejects_invalid_options_before_sending() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/chat")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+    let request = ChatRequest::new("model", [ChatMessage::user("Hi")])
+        .with_options(ModelOptions::new().with_min_p(1.5));
+    let result = client.chat(&request).await;
+
+    assert!(matches!(result, Err(Error::ValidationError(msg)) if msg.contains("min_p")));
+    mock.assert_async().await;
+}
+
+// ============================================================================
+// Streaming API Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_chat_stream_yields_chunks() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .match_body(mockito::Matcher::Json(json!({
+            "model": "qwen3:0.6b",
+            "messages": [{"role": "user", "content": "Hello"}],
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(concat!(
+            r#"{"model":"qwen3:0.6b","message":{"role":"assistant","content":"Hel"},"done":false}"#,
+            "\n",
+            r#"{"model":"qwen3:0.6b","message":{"role":"assistant","content":"lo!"},"done":false}"#,
+            "\n",
+            r#"{"model":"qwen3:0.6b","message":{"role":"assistant","content":""},"done":true,"done_reason":"stop","total_duration":1000000000,"prompt_eval_count":5,"eval_count":2,"eval_duration":500000000}"#,
+            "\n"
+        ))
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let stream = client.chat_stream(&request).await.unwrap();
+    let chunks: Vec<ChatResponse> = stream.map(|c| c.unwrap()).collect().await;
+
+    assert_eq!(chunks.len(), 3);
+    let text: String = chunks.iter().filter_map(|c| c.content()).collect();
+    assert_eq!(text, "Hello!");
+
+    let last = chunks.last().unwrap();
+    assert!(last.is_done());
+    assert_eq!(last.done_reason(), Some("stop"));
+    assert_eq!(last.prompt_tokens(), Some(5));
+    assert_eq!(last.completion_tokens(), Some(2));
+    assert_eq!(last.tokens_per_second(), Some(4.0));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_stream_ends_on_done_chunk() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"message":{"content":"Hi"},"done":false}"#,
+            "\n",
+            r#"{"message":{"content":""},"done":true}"#,
+            "\n",
+            r#"{"message":{"content":"ignored"},"done":false}"#,
+            "\n"
+        ))
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("model", [ChatMessage::user("Hello")]);
+    let chunks: Vec<_> = client.chat_stream(&request).await.unwrap().collect().await;
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks[1].as_ref().unwrap().is_done());
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_stream_does_not_modify_request() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(r#"{"message":{"content":"Hi"},"done":true}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("model", [ChatMessage::user("Hello")]);
+    let chunks: Vec<_> = client.chat_stream(&request).await.unwrap().collect().await;
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(request.stream, Some(false));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_stream_model_not_found() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("nonexistent", [ChatMessage::user("Hello")]);
+    let result = client.chat_stream(&request).await;
+
+    assert!(result.is_err());
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_stream_invalid_chunk_yields_error() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body("{\"message\":{\"content\":\"Hi\"},\"done\":false}\nnot json\n")
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("model", [ChatMessage::user("Hello")]);
+    let chunks: Vec<_> = client.chat_stream(&request).await.unwrap().collect().await;
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks[0].is_ok());
+    assert!(chunks[1].is_err());
+
+    mock.assert_async().await;
+}
+
 // ============================================================================
 // Sync API Tests
 // ============================================================================
@@ -788,6 +1085,7 @@ fn test_chat_sync_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -809,6 +1107,7 @@ fn test_chat_sync_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -820,6 +1119,39 @@ fn test_chat_sync_model_not_found() {
     mock.assert();
 }
 
+#[test]
+fn test_chat_sync_model_not_found_message() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(404)
+        .with_body(r#"{"error":"model 'nonexistent' not found"}"#)
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = ChatRequest::new("nonexistent", [ChatMessage::user("Hello")]);
+    let error = client.chat_blocking(&request).unwrap_err();
+
+    assert!(matches!(
+        error,
+        Error::ApiError {
+            status: 404,
+            kind: ApiErrorKind::ModelNotFound,
+            ..
+        }
+    ));
+
+    mock.assert();
+}
+
 #[test]
 fn test_chat_sync_retry_on_server_error() {
     let mut server = mockito::Server::new();
@@ -842,6 +1174,7 @@ fn test_chat_sync_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -1,7 +1,8 @@
 // Client Configuration Tests - Phase 0 TDD
 // These tests validate the ClientConfig struct
 
-use ollama_oxide::ClientConfig;
+use ollama_oxide::{Auth, ClientConfig, ClientIdentity, RetryPolicy};
+use reqwest::header::{HeaderName, HeaderValue};
 use std::time::Duration;
 
 #[test]
@@ -17,6 +18,89 @@ fn test_client_config_default_values() {
     assert_eq!(config.base_url, "http://localhost:11434");
     assert_eq!(config.timeout, Duration::from_secs(30));
     assert_eq!(config.max_retries, 3);
+    assert_eq!(config.retry_policy, RetryPolicy::default());
+    assert!(config.headers.is_empty());
+    assert!(config.auth.is_none());
+    assert_eq!(config.connect_timeout, None);
+    assert_eq!(config.read_timeout, None);
+    assert_eq!(config.http_proxy, None);
+    assert_eq!(config.https_proxy, None);
+    assert!(config.root_certificates.is_empty());
+    assert!(config.identity.is_none());
+    assert!(!config.accept_invalid_certs);
+    assert_eq!(config.pool_idle_timeout, None);
+    assert_eq!(config.pool_max_idle_per_host, None);
+    assert_eq!(config.tcp_keepalive, None);
+}
+
+#[test]
+fn test_client_config_builder_methods() {
+    let config = ClientConfig::default()
+        .with_base_url("https://ollama.lab:11434")
+        .with_timeout(Duration::from_secs(90))
+        .with_max_retries(1)
+        .with_retry_policy(RetryPolicy::default().with_jitter(0.0))
+        .with_header(
+            HeaderName::from_static("x-tenant"),
+            HeaderValue::from_static("research"),
+        )
+        .with_auth(Auth::bearer("token"))
+        .with_connect_timeout(Duration::from_secs(2))
+        .with_read_timeout(Duration::from_secs(20))
+        .with_root_certificate(b"pem".to_vec())
+        .with_identity(ClientIdentity::pkcs12(b"der".to_vec(), "secret"))
+        .with_accept_invalid_certs(true)
+        .with_pool_idle_timeout(Duration::from_secs(15))
+        .with_pool_max_idle_per_host(2)
+        .with_tcp_keepalive(Duration::from_secs(60));
+
+    assert_eq!(config.base_url, "https://ollama.lab:11434");
+    assert_eq!(config.timeout, Duration::from_secs(90));
+    assert_eq!(config.max_retries, 1);
+    assert_eq!(config.retry_policy.jitter, 0.0);
+    assert_eq!(config.headers["x-tenant"], "research");
+    assert!(matches!(config.auth, Some(Auth::Bearer(_))));
+    assert_eq!(config.connect_timeout, Some(Duration::from_secs(2)));
+    assert_eq!(config.read_timeout, Some(Duration::from_secs(20)));
+    assert_eq!(config.root_certificates, vec![b"pem".to_vec()]);
+    assert!(matches!(
+        config.identity,
+        Some(ClientIdentity::Pkcs12 { .. })
+    ));
+    assert!(config.accept_invalid_certs);
+    assert_eq!(config.pool_idle_timeout, Some(Duration::from_secs(15)));
+    assert_eq!(config.pool_max_idle_per_host, Some(2));
+    assert_eq!(config.tcp_keepalive, Some(Duration::from_secs(60)));
+}
+
+#[test]
+fn test_client_config_with_proxy_sets_both_schemes() {
+    let config = ClientConfig::default().with_proxy("http://proxy:3128");
+
+    assert_eq!(config.http_proxy.as_deref(), Some("http://proxy:3128"));
+    assert_eq!(config.https_proxy.as_deref(), Some("http://proxy:3128"));
+
+    let config = ClientConfig::default().with_https_proxy("http://secure-proxy:3128");
+    assert_eq!(config.http_proxy, None);
+    assert_eq!(
+        config.https_proxy.as_deref(),
+        Some("http://secure-proxy:3128")
+    );
+}
+
+#[test]
+fn test_client_config_custom_retry_policy() {
+    let config = ClientConfig {
+        retry_policy: RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(500))
+            .with_max_delay(Duration::from_secs(30))
+            .with_retryable_statuses([429]),
+        ..Default::default()
+    };
+
+    assert_eq!(config.retry_policy.base_delay, Duration::from_millis(500));
+    assert_eq!(config.retry_policy.max_delay, Duration::from_secs(30));
+    assert_eq!(config.retry_policy.retryable_statuses, vec![429]);
 }
 
 #[test]
@@ -25,6 +109,7 @@ fn test_client_config_custom_values() {
         base_url: "http://example.com:8080".to_string(),
         timeout: Duration::from_secs(60),
         max_retries: 5,
+        ..Default::default()
     };
 
     assert_eq!(config.base_url, "http://example.com:8080");
@@ -38,6 +123,7 @@ fn test_client_config_clone() {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let cloned = config.clone();
@@ -56,12 +142,25 @@ fn test_client_config_debug() {
     assert!(debug.contains("localhost"));
 }
 
+#[test]
+fn test_client_config_debug_redacts_credentials() {
+    let config = ClientConfig {
+        auth: Some(Auth::bearer("secret-token")),
+        ..Default::default()
+    };
+    let debug = format!("{:?}", config);
+
+    assert!(debug.contains("Bearer"));
+    assert!(!debug.contains("secret-token"));
+}
+
 #[test]
 fn test_client_config_with_zero_timeout() {
     let config = ClientConfig {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(0),
         max_retries: 3,
+        ..Default::default()
     };
 
     assert_eq!(config.timeout, Duration::from_secs(0));
@@ -73,6 +172,7 @@ fn test_client_config_with_zero_retries() {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 0,
+        ..Default::default()
     };
 
     assert_eq!(config.max_retries, 0);
@@ -84,6 +184,7 @@ fn test_client_config_with_https_url() {
         base_url: "https://secure.example.com".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     assert_eq!(config.base_url, "https://secure.example.com");
@@ -95,6 +196,7 @@ fn test_client_config_with_custom_port() {
         base_url: "http://localhost:9999".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     assert_eq!(config.base_url, "http://localhost:9999");
@@ -106,6 +208,7 @@ fn test_client_config_with_long_timeout() {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(300),
         max_retries: 3,
+        ..Default::default()
     };
 
     assert_eq!(config.timeout, Duration::from_secs(300));
@@ -117,6 +220,7 @@ fn test_client_config_with_many_retries() {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 10,
+        ..Default::default()
     };
 
     assert_eq!(config.max_retries, 10);
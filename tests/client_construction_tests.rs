@@ -24,6 +24,7 @@ fn test_client_creation_with_custom_config() {
         base_url: "http://localhost:11434".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config);
@@ -36,6 +37,7 @@ fn test_client_creation_with_invalid_url() {
         base_url: "not-a-valid-url".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let result = OllamaClient::new(config);
@@ -48,6 +50,7 @@ fn test_client_creation_with_invalid_url_missing_scheme() {
         base_url: "localhost:11434".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let result = OllamaClient::new(config);
@@ -60,6 +63,7 @@ fn test_client_creation_with_empty_url() {
         base_url: "".to_string(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let result = OllamaClient::new(config);
@@ -25,6 +25,7 @@ async fn test_copy_model_async_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -49,6 +50,7 @@ async fn test_copy_model_async_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -81,6 +83,7 @@ async fn test_copy_model_async_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -110,6 +113,7 @@ async fn test_copy_model_async_different_models() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -141,6 +145,7 @@ fn test_copy_model_sync_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -161,6 +166,7 @@ fn test_copy_model_sync_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -191,6 +197,7 @@ fn test_copy_model_sync_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -219,6 +226,7 @@ fn test_copy_model_sync_different_models() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -248,6 +256,7 @@ async fn test_copy_model_async_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -272,6 +281,7 @@ fn test_copy_model_sync_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -296,6 +306,7 @@ async fn test_copy_model_async_bad_request() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 2, // Should NOT retry on 4xx
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -320,6 +331,7 @@ fn test_copy_model_sync_bad_request() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
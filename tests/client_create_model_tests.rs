@@ -2,8 +2,9 @@
 //!
 //! All tests use mockito for HTTP mocking - no real Ollama server required.
 
+use futures::StreamExt;
 use mockito::{Matcher, Server};
-use ollama_oxide::{ChatMessage, ClientConfig, OllamaApiAsync, OllamaApiSync, OllamaClient};
+use ollama_oxide::{ChatMessage, ClientConfig, Error, OllamaApiAsync, OllamaApiSync, OllamaClient};
 
 use ollama_oxide::{CreateRequest, CreateResponse, LicenseSetting};
 use serde_json::json;
@@ -14,6 +15,7 @@ fn make_config(base_url: String) -> ClientConfig {
         base_url,
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     }
 }
 
@@ -331,6 +333,171 @@ fn test_create_model_sync_not_found() {
     mock.assert();
 }
 
+// ============================================================================
+// Streaming Client Tests (with mocking)
+// ============================================================================
+
+const CREATE_STREAM_BODY: &str = concat!(
+    r#"{"status":"reading model metadata"}"#,
+    "\n",
+    r#"{"status":"quantizing F16 model to Q4_K_M"}"#,
+    "\n",
+    r#"{"status":"using existing layer sha256:aaa"}"#,
+    "\n",
+    r#"{"status":"writing manifest"}"#,
+    "\n",
+    r#"{"status":"success"}"#,
+    "\n"
+);
+
+#[tokio::test]
+async fn test_create_model_stream_async_yields_status() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .match_body(Matcher::Json(json!({
+            "model": "qwen-q4",
+            "from": "qwen3:0.6b",
+            "quantize": "q4_K_M",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(CREATE_STREAM_BODY)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::from_model("qwen-q4", "qwen3:0.6b").with_quantize("q4_K_M");
+    let stream = client.create_model_stream(&request).await.unwrap();
+    let events: Vec<CreateResponse> = stream.map(|e| e.unwrap()).collect().await;
+
+    let statuses: Vec<&str> = events.iter().filter_map(|e| e.status()).collect();
+    assert_eq!(
+        statuses,
+        [
+            "reading model metadata",
+            "quantizing F16 model to Q4_K_M",
+            "using existing layer sha256:aaa",
+            "writing manifest",
+            "success"
+        ]
+    );
+    assert!(events.last().unwrap().is_success());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_model_stream_async_error_mid_stream() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"status":"reading model metadata"}"#,
+            "\n",
+            r#"{"error":"quantization is only supported for F16 and F32 models"}"#,
+            "\n"
+        ))
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::from_model("qwen-q4", "qwen3:0.6b").with_quantize("q4_K_M");
+    let events: Vec<_> = client
+        .create_model_stream(&request)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events[0].as_ref().unwrap().status(),
+        Some("reading model metadata")
+    );
+    match &events[1] {
+        Err(Error::ApiError { message, .. }) => assert!(message.contains("quantization")),
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_model_stream_async_not_found() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .with_status(404)
+        .with_body(r#"{"error": "model 'nonexistent' not found"}"#)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::from_model("my-model", "nonexistent");
+    let result = client.create_model_stream(&request).await;
+
+    assert!(matches!(result, Err(e) if e.is_model_not_found()));
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_create_model_stream_sync_yields_status() {
+    let mut server = Server::new();
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .match_body(Matcher::PartialJson(json!({
+            "model": "qwen-q4",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_body(CREATE_STREAM_BODY)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::from_model("qwen-q4", "qwen3:0.6b").with_quantize("q4_K_M");
+    let events: Vec<CreateResponse> = client
+        .create_model_stream_blocking(&request)
+        .unwrap()
+        .map(|e| e.unwrap())
+        .collect();
+
+    assert_eq!(events.len(), 5);
+    assert!(events.last().unwrap().is_success());
+    mock.assert();
+}
+
+#[test]
+fn test_create_model_stream_sync_error_mid_stream() {
+    let mut server = Server::new();
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"status":"reading model metadata"}"#,
+            "\n",
+            r#"{"error":"invalid model name"}"#,
+            "\n",
+            r#"{"status":"success"}"#,
+            "\n"
+        ))
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = CreateRequest::from_model("bad name", "qwen3:0.6b");
+    let mut events = client.create_model_stream_blocking(&request).unwrap();
+
+    assert!(events.next().unwrap().is_ok());
+    assert!(matches!(events.next(), Some(Err(Error::ApiError { .. }))));
+    assert!(events.next().is_none());
+    mock.assert();
+}
+
 // ============================================================================
 // CreateResponse Tests
 // ============================================================================
@@ -1,6 +1,8 @@
 //! Tests for delete_model API methods (DELETE /api/delete)
 
-use ollama_oxide::{ClientConfig, DeleteRequest, OllamaApiAsync, OllamaApiSync, OllamaClient};
+use ollama_oxide::{
+    ApiErrorKind, ClientConfig, DeleteRequest, OllamaApiAsync, OllamaApiSync, OllamaClient,
+};
 use std::time::Duration;
 
 // ============================================================================
@@ -24,6 +26,7 @@ async fn test_delete_model_async_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -48,6 +51,7 @@ async fn test_delete_model_async_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -58,6 +62,34 @@ async fn test_delete_model_async_model_not_found() {
     mock.assert_async().await;
 }
 
+#[tokio::test]
+async fn test_delete_model_async_model_not_found_message() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("DELETE", "/api/delete")
+        .with_status(404)
+        .with_body(r#"{"error":"model 'nonexistent' not found"}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = DeleteRequest::new("nonexistent");
+    let error = client.delete_model(&request).await.unwrap_err();
+
+    assert_eq!(error.status_code(), Some(404));
+    assert_eq!(error.api_error_kind(), Some(ApiErrorKind::ModelNotFound));
+    assert!(error.to_string().contains("model 'nonexistent' not found"));
+    mock.assert_async().await;
+}
+
 #[tokio::test]
 async fn test_delete_model_async_retry_on_server_error() {
     let mut server = mockito::Server::new_async().await;
@@ -80,6 +112,7 @@ async fn test_delete_model_async_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -111,6 +144,7 @@ fn test_delete_model_sync_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -134,6 +168,7 @@ fn test_delete_model_sync_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -144,6 +179,31 @@ fn test_delete_model_sync_model_not_found() {
     mock.assert();
 }
 
+#[test]
+fn test_delete_model_sync_model_not_found_message() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("DELETE", "/api/delete")
+        .with_status(404)
+        .with_body(r#"{"error":"model 'nonexistent' not found"}"#)
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = DeleteRequest::new("nonexistent");
+    let error = client.delete_model_blocking(&request).unwrap_err();
+
+    assert!(error.is_model_not_found());
+    mock.assert();
+}
+
 #[test]
 fn test_delete_model_sync_retry_on_server_error() {
     let mut server = mockito::Server::new();
@@ -164,6 +224,7 @@ fn test_delete_model_sync_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -194,6 +255,7 @@ async fn test_delete_model_async_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -218,6 +280,7 @@ fn test_delete_model_sync_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
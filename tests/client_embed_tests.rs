@@ -378,6 +378,7 @@ async fn test_embed_async_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -416,6 +417,7 @@ async fn test_embed_async_multiple_inputs() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -444,6 +446,7 @@ async fn test_embed_async_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -479,6 +482,7 @@ async fn test_embed_async_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -506,6 +510,7 @@ async fn test_embed_async_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -541,6 +546,7 @@ fn test_embed_sync_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -562,6 +568,7 @@ fn test_embed_sync_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -595,6 +602,7 @@ fn test_embed_sync_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -1,9 +1,10 @@
 //! Tests for generate API methods (POST /api/generate)
 
+use futures::StreamExt;
 use ollama_oxide::{
-    ClientConfig, FormatSetting, GenerateRequest, GenerateResponse, KeepAliveSetting, Logprob,
-    ModelOptions, OllamaApiAsync, OllamaApiSync, OllamaClient, StopSetting, ThinkSetting,
-    TokenLogprob,
+    ApiErrorKind, ClientConfig, Error, FormatSetting, GenerateRequest, GenerateResponse,
+    KeepAliveSetting, Logprob, ModelOptions, OllamaApiAsync, OllamaApiSync, OllamaClient,
+    StopSetting, ThinkSetting, TokenLogprob,
 };
 use std::time::Duration;
 
@@ -614,6 +615,7 @@ async fn test_generate_async_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -652,6 +654,7 @@ async fn test_generate_async_with_options() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -679,6 +682,7 @@ async fn test_generate_async_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -714,6 +718,7 @@ async fn test_generate_async_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -741,6 +746,7 @@ async fn test_generate_async_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -777,6 +783,7 @@ fn test_generate_sync_success() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -801,6 +808,7 @@ fn test_generate_sync_model_not_found() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -834,6 +842,7 @@ fn test_generate_sync_retry_on_server_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -846,6 +855,365 @@ fn test_generate_sync_retry_on_server_error() {
     mock_success.assert();
 }
 
+// ============================================================================
+// Option Validation Tests
+// ============================================================================
+
+fn invalid_options_request() -> GenerateRequest {
+    GenerateRequest::new("model", "Hello")
+        .with_options(ModelOptions::new().with_temperature(-1.0).with_top_p(2.0))
+}
+
+#[tokio::test]
+async fn test_generate_async_rejects_invalid_options_before_sending() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/generate")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+    let result = client.generate(&invalid_options_request()).await;
+
+    match result {
+        Err(Error::ValidationError(message)) => {
+            assert!(message.contains("temperature"));
+            assert!(message.contains("top_p"));
+        }
+        other => panic!("expected ValidationError, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_generate_stream_async_rejects_invalid_options() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/generate")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+    let result = client.generate_stream(&invalid_options_request()).await;
+
+    assert!(matches!(result, Err(Error::ValidationError(_))));
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_generate_sync_rejects_invalid_options_before_sending() {
+    let mut server = mockito::Server::new();
+    let mock = server.mock("POST", "/api/generate").expect(0).create();
+
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+    let result = client.generate_blocking(&invalid_options_request());
+
+    assert!(matches!(result, Err(Error::ValidationError(_))));
+    mock.assert();
+}
+
+#[tokio::test]
+async fn test_generate_async_sends_extra_options() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/generate")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "options": {"repeat_penalty": 1.5, "num_thread": 4, "mirostat": 2}
+        })))
+        .with_status(200)
+        .with_body(r#"{"model":"model","response":"Hi","done":true}"#)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+    let request = GenerateRequest::new("model", "Hello").with_options(
+        ModelOptions::new()
+            .with_repeat_penalty(1.5)
+            .with_num_thread(4)
+            .with_extra("mirostat", 2),
+    );
+    client.generate(&request).await.unwrap();
+
+    mock.assert_async().await;
+}
+
+// ============================================================================
+// Streaming API Tests
+// ============================================================================
+
+const GENERATE_STREAM_BODY: &str = concat!(
+    r#"{"model":"qwen3:0.6b","response":"The sky","done":false}"#,
+    "\n",
+    r#"{"model":"qwen3:0.6b","response":" is blue.","done":false}"#,
+    "\n",
+    r#"{"model":"qwen3:0.6b","response":"","done":true,"done_reason":"stop","total_duration":1000000000,"eval_count":4,"eval_duration":1000000000}"#,
+    "\n"
+);
+
+#[tokio::test]
+async fn test_generate_stream_async_yields_chunks() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "model": "qwen3:0.6b",
+            "prompt": "Why is the sky blue?",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(GENERATE_STREAM_BODY)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Why is the sky blue?");
+    let stream = client.generate_stream(&request).await.unwrap();
+    let chunks: Vec<GenerateResponse> = stream.map(|c| c.unwrap()).collect().await;
+
+    assert_eq!(chunks.len(), 3);
+    let text: String = chunks.iter().filter_map(|c| c.text()).collect();
+    assert_eq!(text, "The sky is blue.");
+
+    let last = chunks.last().unwrap();
+    assert!(last.is_done());
+    assert_eq!(last.done_reason.as_deref(), Some("stop"));
+    assert_eq!(last.tokens_per_second(), Some(4.0));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_generate_stream_async_error_mid_stream() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"model":"qwen3:0.6b","response":"The","done":false}"#,
+            "\n",
+            r#"{"error":"model runner has unexpectedly stopped"}"#,
+            "\n",
+            r#"{"model":"qwen3:0.6b","response":" sky","done":false}"#,
+            "\n"
+        ))
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Why is the sky blue?");
+    let items: Vec<_> = client
+        .generate_stream(&request)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    // The error ends the stream; nothing after it is yielded
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].as_ref().unwrap().text(), Some("The"));
+    match &items[1] {
+        Err(Error::ApiError { message, .. }) => {
+            assert_eq!(message, "model runner has unexpectedly stopped")
+        }
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_generate_stream_async_model_not_found() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .with_status(404)
+        .with_body(r#"{"error":"model 'nonexistent' not found"}"#)
+        .create_async()
+        .await;
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("nonexistent", "Hello");
+    let result = client.generate_stream(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status: 404,
+            kind: ApiErrorKind::ModelNotFound,
+            ..
+        })
+    ));
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_generate_stream_sync_yields_chunks() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .match_body(mockito::Matcher::Json(serde_json::json!({
+            "model": "qwen3:0.6b",
+            "prompt": "Why is the sky blue?",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(GENERATE_STREAM_BODY)
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Why is the sky blue?");
+    let chunks: Vec<GenerateResponse> = client
+        .generate_stream_blocking(&request)
+        .unwrap()
+        .map(|c| c.unwrap())
+        .collect();
+
+    assert_eq!(chunks.len(), 3);
+    let text: String = chunks.iter().filter_map(|c| c.text()).collect();
+    assert_eq!(text, "The sky is blue.");
+    assert!(chunks.last().unwrap().is_done());
+
+    mock.assert();
+}
+
+#[test]
+fn test_generate_stream_sync_trailing_line_without_newline() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"response":"Hi","done":false}"#,
+            "\n",
+            r#"{"response":"","done":true}"#
+        ))
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Hello");
+    let chunks: Vec<GenerateResponse> = client
+        .generate_stream_blocking(&request)
+        .unwrap()
+        .map(|c| c.unwrap())
+        .collect();
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks[1].is_done());
+
+    mock.assert();
+}
+
+#[test]
+fn test_generate_stream_sync_error_mid_stream() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"response":"The","done":false}"#,
+            "\n",
+            r#"{"error":"model runner has unexpectedly stopped"}"#,
+            "\n"
+        ))
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Hello");
+    let mut iter = client.generate_stream_blocking(&request).unwrap();
+
+    assert_eq!(iter.next().unwrap().unwrap().text(), Some("The"));
+    assert!(matches!(iter.next(), Some(Err(Error::ApiError { .. }))));
+    assert!(iter.next().is_none());
+
+    mock.assert();
+}
+
+#[test]
+fn test_generate_stream_sync_model_not_found() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/generate")
+        .with_status(404)
+        .with_body(r#"{"error":"model 'nonexistent' not found"}"#)
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let request = GenerateRequest::new("nonexistent", "Hello");
+    let result = client.generate_stream_blocking(&request);
+
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status: 404,
+            kind: ApiErrorKind::ModelNotFound,
+            ..
+        })
+    ));
+
+    mock.assert();
+}
+
 // ============================================================================
 // Type Safety Tests
 // ============================================================================
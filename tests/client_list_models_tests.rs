@@ -32,6 +32,7 @@ async fn test_list_models_async_with_mock() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -60,6 +61,7 @@ async fn test_list_models_async_empty_response() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -103,6 +105,7 @@ async fn test_list_models_async_full_response() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -153,6 +156,7 @@ async fn test_list_models_async_multiple_models() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -191,6 +195,7 @@ async fn test_list_models_async_retry_on_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -231,6 +236,7 @@ fn test_list_models_sync_with_mock() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -258,6 +264,7 @@ fn test_list_models_sync_empty_response() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -300,6 +307,7 @@ fn test_list_models_sync_full_response() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -343,6 +351,7 @@ fn test_list_models_sync_retry_on_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 1,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -374,6 +383,7 @@ async fn test_list_models_async_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -397,6 +407,7 @@ fn test_list_models_sync_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 2,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -1,7 +1,12 @@
-//! Tests for POST /api/pull endpoint (pull_model, pull_model_blocking)
+//! Tests for POST /api/pull endpoint (pull_model, pull_model_blocking,
+//! pull_model_stream, pull_model_stream_blocking)
 
+use futures::StreamExt;
 use mockito::{Matcher, Server};
-use ollama_oxide::{ClientConfig, OllamaApiAsync, OllamaApiSync, OllamaClient, PullRequest};
+use ollama_oxide::{
+    ApiErrorKind, ClientConfig, Error, OllamaApiAsync, OllamaApiSync, OllamaClient,
+    ProgressResponse, PullRequest, TransferProgress,
+};
 use std::time::Duration;
 
 fn make_config(base_url: String) -> ClientConfig {
@@ -9,6 +14,7 @@ fn make_config(base_url: String) -> ClientConfig {
         base_url,
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     }
 }
 
@@ -199,3 +205,280 @@ fn test_pull_model_blocking_empty_response() {
     assert_eq!(response.status(), None);
     mock.assert();
 }
+
+// ============================================================================
+// Streaming Tests
+// ============================================================================
+
+const PULL_STREAM_BODY: &str = concat!(
+    r#"{"status":"pulling manifest"}"#,
+    "\n",
+    r#"{"status":"pulling aaa","digest":"sha256:aaa","total":1000}"#,
+    "\n",
+    r#"{"status":"pulling aaa","digest":"sha256:aaa","total":1000,"completed":500}"#,
+    "\n",
+    r#"{"status":"pulling bbb","digest":"sha256:bbb","total":3000,"completed":1500}"#,
+    "\n",
+    r#"{"status":"pulling aaa","digest":"sha256:aaa","total":1000,"completed":1000}"#,
+    "\n",
+    r#"{"status":"pulling bbb","digest":"sha256:bbb","total":3000,"completed":3000}"#,
+    "\n",
+    r#"{"status":"verifying sha256 digest"}"#,
+    "\n",
+    r#"{"status":"writing manifest"}"#,
+    "\n",
+    r#"{"status":"success"}"#,
+    "\n"
+);
+
+#[tokio::test]
+async fn test_pull_model_stream_yields_progress() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/pull")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": "llama3.2:latest",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(PULL_STREAM_BODY)
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("llama3.2:latest");
+    let stream = client.pull_model_stream(&request).await.unwrap();
+    let events: Vec<ProgressResponse> = stream.map(|e| e.unwrap()).collect().await;
+
+    assert_eq!(events.len(), 9);
+    assert_eq!(events[0].status(), Some("pulling manifest"));
+    assert_eq!(events[2].digest(), Some("sha256:aaa"));
+    assert_eq!(events[2].completed, Some(500));
+    assert_eq!(events[2].percentage(), Some(50.0));
+    assert!(events.last().unwrap().is_success());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_pull_model_stream_aggregates_progress() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/pull")
+        .with_status(200)
+        .with_body(PULL_STREAM_BODY)
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("llama3.2:latest");
+    let mut stream = client.pull_model_stream(&request).await.unwrap();
+    let mut progress = TransferProgress::new();
+    let mut percentages = Vec::new();
+
+    while let Some(event) = stream.next().await {
+        progress.update(&event.unwrap());
+        percentages.push(progress.percentage());
+    }
+
+    assert_eq!(percentages[0], None);
+    assert_eq!(percentages[1], Some(0.0));
+    assert_eq!(percentages[2], Some(50.0));
+    assert_eq!(percentages[3], Some(50.0));
+    assert_eq!(percentages[5], Some(100.0));
+    assert_eq!(progress.completed(), 4000);
+    assert_eq!(progress.total(), 4000);
+    assert_eq!(progress.layer_count(), 2);
+    assert!(progress.is_success());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_pull_model_stream_outlives_timeout() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/pull")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            for completed in [0, 500, 1000] {
+                writeln!(
+                    w,
+                    r#"{{"status":"pulling aaa","digest":"sha256:aaa","total":1000,"completed":{}}}"#,
+                    completed
+                )?;
+                w.flush()?;
+                std::thread::sleep(Duration::from_millis(400));
+            }
+            writeln!(w, r#"{{"status":"success"}}"#)
+        })
+        .create_async()
+        .await;
+
+    // Each chunk arrives well within the timeout, the whole body does not
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(1),
+        max_retries: 0,
+        ..Default::default()
+    };
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("llama3.2:latest");
+    let stream = client.pull_model_stream(&request).await.unwrap();
+    let events: Vec<_> = stream.collect().await;
+
+    assert_eq!(events.len(), 4);
+    assert!(events.iter().all(|e| e.is_ok()));
+    assert!(events.last().unwrap().as_ref().unwrap().is_success());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_pull_model_stream_error_mid_stream() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/pull")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"status":"pulling manifest"}"#,
+            "\n",
+            r#"{"error":"pull model manifest: file does not exist"}"#,
+            "\n"
+        ))
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("nonexistent:latest");
+    let events: Vec<_> = client
+        .pull_model_stream(&request)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    assert!(events[0].is_ok());
+    assert!(
+        matches!(&events[1], Err(Error::ApiError { kind: ApiErrorKind::ModelNotFound, message, .. }) if message.contains("file does not exist"))
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_pull_model_stream_not_found() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/pull")
+        .with_status(404)
+        .with_body(r#"{"error": "model not found"}"#)
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("nonexistent:latest");
+    let result = client.pull_model_stream(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status: 404,
+            kind: ApiErrorKind::ModelNotFound,
+            ..
+        })
+    ));
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_pull_model_stream_blocking_yields_progress() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/api/pull")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": "gemma:7b",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(PULL_STREAM_BODY)
+        .create();
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("gemma:7b");
+    let mut progress = TransferProgress::new();
+    let mut count = 0;
+    for event in client.pull_model_stream_blocking(&request).unwrap() {
+        progress.update(&event.unwrap());
+        count += 1;
+    }
+
+    assert_eq!(count, 9);
+    assert_eq!(progress.percentage(), Some(100.0));
+    assert!(progress.is_success());
+    mock.assert();
+}
+
+#[test]
+fn test_pull_model_stream_blocking_outlives_timeout() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/api/pull")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            for status in [
+                "pulling manifest",
+                "verifying sha256 digest",
+                "writing manifest",
+            ] {
+                writeln!(w, r#"{{"status":"{}"}}"#, status)?;
+                w.flush()?;
+                std::thread::sleep(Duration::from_millis(400));
+            }
+            writeln!(w, r#"{{"status":"success"}}"#)
+        })
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(1),
+        max_retries: 0,
+        ..Default::default()
+    };
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("gemma:7b");
+    let events: Vec<_> = client
+        .pull_model_stream_blocking(&request)
+        .unwrap()
+        .collect();
+
+    assert_eq!(events.len(), 4);
+    assert!(events.last().unwrap().as_ref().unwrap().is_success());
+    mock.assert();
+}
+
+#[test]
+fn test_pull_model_stream_blocking_not_found() {
+    let mut server = Server::new();
+    let mock = server.mock("POST", "/api/pull").with_status(404).create();
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PullRequest::new("nonexistent:latest");
+    let result = client.pull_model_stream_blocking(&request);
+
+    assert!(matches!(result, Err(Error::HttpStatusError(404))));
+    mock.assert();
+}
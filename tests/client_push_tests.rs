@@ -1,7 +1,12 @@
-//! Tests for POST /api/push endpoint (push_model, push_model_blocking)
+//! Tests for POST /api/push endpoint (push_model, push_model_blocking,
+//! push_model_stream, push_model_stream_blocking)
 
+use futures::StreamExt;
 use mockito::{Matcher, Server};
-use ollama_oxide::{ClientConfig, OllamaApiAsync, OllamaApiSync, OllamaClient, PushRequest};
+use ollama_oxide::{
+    ApiErrorKind, ClientConfig, Error, OllamaApiAsync, OllamaApiSync, OllamaClient,
+    ProgressResponse, PushRequest, TransferProgress,
+};
 use std::time::Duration;
 
 fn make_config(base_url: String) -> ClientConfig {
@@ -9,6 +14,7 @@ fn make_config(base_url: String) -> ClientConfig {
         base_url,
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     }
 }
 
@@ -220,3 +226,169 @@ fn test_push_model_blocking_empty_response() {
     assert_eq!(response.status(), None);
     mock.assert();
 }
+
+// ============================================================================
+// Streaming Tests
+// ============================================================================
+
+const PUSH_STREAM_BODY: &str = concat!(
+    r#"{"status":"retrieving manifest"}"#,
+    "\n",
+    r#"{"status":"starting upload","digest":"sha256:aaa","total":2000}"#,
+    "\n",
+    r#"{"status":"starting upload","digest":"sha256:aaa","total":2000,"completed":2000}"#,
+    "\n",
+    r#"{"status":"pushing manifest"}"#,
+    "\n",
+    r#"{"status":"success"}"#,
+    "\n"
+);
+
+#[tokio::test]
+async fn test_push_model_stream_yields_progress() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/push")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": "myuser/mymodel:latest",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(PUSH_STREAM_BODY)
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PushRequest::new("myuser/mymodel:latest");
+    let stream = client.push_model_stream(&request).await.unwrap();
+    let events: Vec<ProgressResponse> = stream.map(|e| e.unwrap()).collect().await;
+
+    assert_eq!(events.len(), 5);
+    assert_eq!(events[1].digest(), Some("sha256:aaa"));
+    assert_eq!(events[1].total, Some(2000));
+    assert!(events.last().unwrap().is_success());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_push_model_stream_unauthorized() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/push")
+        .with_status(401)
+        .with_body(r#"{"error": "unauthorized"}"#)
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PushRequest::new("myuser/mymodel:latest");
+    let result = client.push_model_stream(&request).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::ApiError {
+            status: 401,
+            kind: ApiErrorKind::Unauthorized,
+            ..
+        })
+    ));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_push_model_stream_error_mid_stream() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/push")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"status":"retrieving manifest"}"#,
+            "\n",
+            r#"{"error":"unauthorized: access denied"}"#,
+            "\n"
+        ))
+        .create_async()
+        .await;
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PushRequest::new("myuser/mymodel:latest");
+    let events: Vec<_> = client
+        .push_model_stream(&request)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    assert!(events[0].is_ok());
+    assert!(
+        matches!(&events[1], Err(Error::ApiError { status: 200, message, .. }) if message.contains("access denied"))
+    );
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_push_model_stream_blocking_yields_progress() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/api/push")
+        .match_body(Matcher::Json(serde_json::json!({
+            "model": "myuser/mymodel:latest",
+            "stream": true
+        })))
+        .with_status(200)
+        .with_body(PUSH_STREAM_BODY)
+        .create();
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PushRequest::new("myuser/mymodel:latest");
+    let mut progress = TransferProgress::new();
+    for event in client.push_model_stream_blocking(&request).unwrap() {
+        progress.update(&event.unwrap());
+    }
+
+    assert_eq!(progress.completed(), 2000);
+    assert_eq!(progress.percentage(), Some(100.0));
+    assert!(progress.is_success());
+    mock.assert();
+}
+
+#[test]
+fn test_push_model_stream_blocking_error_mid_stream() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/api/push")
+        .with_status(200)
+        .with_body(concat!(
+            r#"{"status":"retrieving manifest"}"#,
+            "\n",
+            r#"{"error":"unauthorized: access denied"}"#,
+            "\n"
+        ))
+        .create();
+
+    let config = make_config(server.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let request = PushRequest::new("myuser/mymodel:latest");
+    let events: Vec<_> = client
+        .push_model_stream_blocking(&request)
+        .unwrap()
+        .collect();
+
+    assert_eq!(events.len(), 2);
+    assert!(events[0].is_ok());
+    assert!(
+        matches!(&events[1], Err(Error::ApiError { status: 200, message, .. }) if message.contains("access denied"))
+    );
+    mock.assert();
+}
@@ -0,0 +1,252 @@
+//! Tests for RetryPolicy behavior across client helpers
+
+use ollama_oxide::{
+    ChatMessage, ChatRequest, ClientConfig, CreateRequest, Error, OllamaApiAsync, OllamaApiSync,
+    OllamaClient, RetryPolicy,
+};
+use std::time::{Duration, Instant};
+
+fn make_config(base_url: String, max_retries: u32, retry_policy: RetryPolicy) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(5),
+        max_retries,
+        retry_policy,
+        ..Default::default()
+    }
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::default()
+        .with_base_delay(Duration::from_millis(10))
+        .with_jitter(0.0)
+}
+
+// ============================================================================
+// Backoff Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_retry_backoff_is_exponential() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/version")
+        .with_status(503)
+        .expect(4)
+        .create_async()
+        .await;
+
+    let policy = RetryPolicy::default()
+        .with_base_delay(Duration::from_millis(50))
+        .with_multiplier(2.0)
+        .with_jitter(0.0);
+    let client = OllamaClient::new(make_config(server.url(), 3, policy)).unwrap();
+
+    let start = Instant::now();
+    let result = client.version().await;
+
+    // 50ms + 100ms + 200ms between the four attempts
+    assert!(start.elapsed() >= Duration::from_millis(350));
+    assert_eq!(result.unwrap_err().status_code(), Some(503));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_retry_after_header_is_honored() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock_busy = server
+        .mock("GET", "/api/version")
+        .with_status(429)
+        .with_header("retry-after", "1")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mock_ok = server
+        .mock("GET", "/api/version")
+        .with_status(200)
+        .with_body(r#"{"version":"0.12.6"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url(), 1, fast_policy())).unwrap();
+
+    let start = Instant::now();
+    let response = client.version().await.unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    mock_busy.assert_async().await;
+    mock_ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_status_not_in_policy_is_not_retried() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/version")
+        .with_status(500)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let policy = fast_policy().with_retryable_statuses([503]);
+    let client = OllamaClient::new(make_config(server.url(), 3, policy)).unwrap();
+    let result = client.version().await;
+
+    assert!(result.is_err());
+    mock.assert_async().await;
+}
+
+// ============================================================================
+// POST Retry Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_create_post_not_retried_by_default() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .with_status(500)
+        .with_body(r#"{"error":"internal error"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url(), 3, fast_policy())).unwrap();
+    let request = CreateRequest::from_model("mario", "qwen3:0.6b");
+    let result = client.create_model(&request).await;
+
+    assert_eq!(result.unwrap_err().status_code(), Some(500));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_post_retried_when_allowed() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock_fail = server
+        .mock("POST", "/api/create")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mock_ok = server
+        .mock("POST", "/api/create")
+        .with_status(200)
+        .with_body(r#"{"status":"success"}"#)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let policy = fast_policy().allow_post_retry("/api/create");
+    let client = OllamaClient::new(make_config(server.url(), 3, policy)).unwrap();
+    let request = CreateRequest::from_model("mario", "qwen3:0.6b");
+    let response = client.create_model(&request).await.unwrap();
+
+    assert!(response.is_success());
+    mock_fail.assert_async().await;
+    mock_ok.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_post_not_retried_when_denied() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let policy = fast_policy().deny_post_retry("/api/chat");
+    let client = OllamaClient::new(make_config(server.url(), 3, policy)).unwrap();
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let result = client.chat(&request).await;
+
+    assert!(result.is_err());
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_create_post_not_retried_by_default_blocking() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("POST", "/api/create")
+        .with_status(503)
+        .expect(1)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url(), 3, fast_policy())).unwrap();
+    let request = CreateRequest::from_model("mario", "qwen3:0.6b");
+    let result = client.create_model_blocking(&request);
+
+    assert!(result.is_err());
+    mock.assert();
+}
+
+#[test]
+fn test_retry_after_header_is_honored_blocking() {
+    let mut server = mockito::Server::new();
+
+    let mock_busy = server
+        .mock("GET", "/api/version")
+        .with_status(503)
+        .with_header("retry-after", "1")
+        .expect(1)
+        .create();
+
+    let mock_ok = server
+        .mock("GET", "/api/version")
+        .with_status(200)
+        .with_body(r#"{"version":"0.12.6"}"#)
+        .expect(1)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url(), 1, fast_policy())).unwrap();
+
+    let start = Instant::now();
+    let response = client.version_blocking().unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+    assert!(start.elapsed() >= Duration::from_secs(1));
+    mock_busy.assert();
+    mock_ok.assert();
+}
+
+// ============================================================================
+// Network Error Tests
+// ============================================================================
+
+/// Address with nothing listening on it
+fn closed_port_url() -> String {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+    format!("http://127.0.0.1:{}", port)
+}
+
+#[tokio::test]
+async fn test_connection_refused_is_retried_for_any_endpoint() {
+    let client = OllamaClient::new(make_config(closed_port_url(), 2, fast_policy())).unwrap();
+    let request = CreateRequest::from_model("mario", "qwen3:0.6b");
+    let result = client.create_model(&request).await;
+
+    assert!(matches!(result, Err(Error::MaxRetriesExceededError(2))));
+}
+
+#[test]
+fn test_connection_refused_is_retried_blocking() {
+    let client = OllamaClient::new(make_config(closed_port_url(), 1, fast_policy())).unwrap();
+    let result = client.version_blocking();
+
+    assert!(matches!(result, Err(Error::MaxRetriesExceededError(1))));
+}
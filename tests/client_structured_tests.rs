@@ -0,0 +1,255 @@
+//! Tests for typed structured output (chat_structured, generate_structured)
+//!
+//! All tests use mockito for HTTP mocking - no real Ollama server required.
+
+use mockito::{Matcher, Server};
+use ollama_oxide::{
+    ChatMessage, ChatRequest, ClientConfig, Error, FormatSetting, GenerateRequest, OllamaApiAsync,
+    OllamaApiSync, OllamaClient,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+struct Capital {
+    country: String,
+    city: String,
+}
+
+fn make_config(base_url: String) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(5),
+        max_retries: 0,
+        ..Default::default()
+    }
+}
+
+fn chat_body(content: &str) -> String {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": true
+    })
+    .to_string()
+}
+
+fn generate_body(response: &str) -> String {
+    json!({"model": "qwen3:0.6b", "response": response, "done": true}).to_string()
+}
+
+fn chat_request() -> ChatRequest {
+    ChatRequest::new(
+        "qwen3:0.6b",
+        [ChatMessage::user("What is the capital of France?")],
+    )
+}
+
+// ============================================================================
+// FormatSetting Tests
+// ============================================================================
+
+#[test]
+fn test_format_setting_schema_for() {
+    let FormatSetting::Schema(schema) = FormatSetting::schema_for::<Capital>() else {
+        panic!("expected a schema format");
+    };
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["country"]["type"], "string");
+    assert_eq!(schema["required"], json!(["country", "city"]));
+}
+
+// ============================================================================
+// Async API Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_chat_structured_async_success() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::PartialJson(json!({
+            "format": {"type": "object", "required": ["country", "city"]},
+            "stream": false
+        })))
+        .with_status(200)
+        .with_body(chat_body(r#"{"country": "France", "city": "Paris"}"#))
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let capital: Capital = client.chat_structured(&chat_request(), 0).await.unwrap();
+
+    assert_eq!(
+        capital,
+        Capital {
+            country: "France".to_string(),
+            city: "Paris".to_string()
+        }
+    );
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_structured_async_parse_error_keeps_raw_text() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(chat_body(r#"{"country": "France"}"#))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let result = client.chat_structured::<Capital>(&chat_request(), 0).await;
+
+    match result {
+        Err(Error::StructuredOutputError { message, raw }) => {
+            assert!(message.contains("city"));
+            assert_eq!(raw, r#"{"country": "France"}"#);
+        }
+        other => panic!("expected StructuredOutputError, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_structured_async_corrects_invalid_reply() {
+    let mut server = Server::new_async().await;
+    let first = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(chat_body("Paris, obviously"))
+        .expect(1)
+        .create_async()
+        .await;
+    let corrected = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("Paris, obviously".to_string()),
+            Matcher::Regex("could not be parsed".to_string()),
+        ]))
+        .with_status(200)
+        .with_body(chat_body(r#"{"country": "France", "city": "Paris"}"#))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let capital: Capital = client.chat_structured(&chat_request(), 2).await.unwrap();
+
+    assert_eq!(capital.city, "Paris");
+    first.assert_async().await;
+    corrected.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_chat_structured_async_gives_up_after_max_corrections() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/chat")
+        .with_status(200)
+        .with_body(chat_body("not json"))
+        .expect(3)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let result = client.chat_structured::<Capital>(&chat_request(), 2).await;
+
+    assert!(matches!(result, Err(Error::StructuredOutputError { raw, .. }) if raw == "not json"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_generate_structured_async_success() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/api/generate")
+        .match_body(Matcher::PartialJson(json!({
+            "prompt": "Capital of Italy?",
+            "format": {"type": "object"}
+        })))
+        .with_status(200)
+        .with_body(generate_body(r#"{"country": "Italy", "city": "Rome"}"#))
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Capital of Italy?");
+    let capital: Capital = client.generate_structured(&request, 0).await.unwrap();
+
+    assert_eq!(capital.city, "Rome");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_generate_structured_async_corrects_invalid_reply() {
+    let mut server = Server::new_async().await;
+    let first = server
+        .mock("POST", "/api/generate")
+        .with_status(200)
+        .with_body(generate_body("Rome"))
+        .expect(1)
+        .create_async()
+        .await;
+    let corrected = server
+        .mock("POST", "/api/generate")
+        .match_body(Matcher::Regex("could not be parsed".to_string()))
+        .with_status(200)
+        .with_body(generate_body(r#"{"country": "Italy", "city": "Rome"}"#))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Capital of Italy?");
+    let capital: Capital = client.generate_structured(&request, 1).await.unwrap();
+
+    assert_eq!(capital.country, "Italy");
+    first.assert_async().await;
+    corrected.assert_async().await;
+}
+
+// ============================================================================
+// Sync API Tests
+// ============================================================================
+
+#[test]
+fn test_chat_structured_sync_success() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/api/chat")
+        .match_body(Matcher::PartialJson(json!({"format": {"type": "object"}})))
+        .with_status(200)
+        .with_body(chat_body(r#"{"country": "Spain", "city": "Madrid"}"#))
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let capital: Capital = client.chat_structured_blocking(&chat_request(), 0).unwrap();
+
+    assert_eq!(capital.city, "Madrid");
+    mock.assert();
+}
+
+#[test]
+fn test_generate_structured_sync_parse_error() {
+    let mut server = Server::new();
+    let mock = server
+        .mock("POST", "/api/generate")
+        .with_status(200)
+        .with_body(generate_body("Madrid"))
+        .expect(2)
+        .create();
+
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+    let request = GenerateRequest::new("qwen3:0.6b", "Capital of Spain?");
+    let result = client.generate_structured_blocking::<Capital>(&request, 1);
+
+    assert!(matches!(result, Err(Error::StructuredOutputError { raw, .. }) if raw == "Madrid"));
+    mock.assert();
+}
@@ -1,8 +1,11 @@
 // Client Sync API Tests - Phase 0 TDD
 // These tests validate the sync (blocking) API functionality
 
-use ollama_oxide::{ClientConfig, OllamaApiSync, OllamaClient};
+use ollama_oxide::{ClientConfig, Error, OllamaApiSync, OllamaClient};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
 use std::time::Duration;
 
@@ -24,6 +27,7 @@ fn test_version_sync_successful() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -51,6 +55,7 @@ fn test_version_sync_different_version() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -85,6 +90,7 @@ fn test_version_sync_retry_logic_success_on_second_attempt() {
         base_url: server.url(),
         timeout: Duration::from_secs(5),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -112,6 +118,7 @@ fn test_version_sync_max_retries_exceeded() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -136,6 +143,7 @@ fn test_version_sync_json_deserialization_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -160,6 +168,7 @@ fn test_version_sync_invalid_json() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -185,6 +194,7 @@ fn test_version_sync_concurrent_calls_from_threads() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = Arc::new(OllamaClient::new(config).unwrap());
@@ -220,6 +230,7 @@ fn test_version_sync_404_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -229,6 +240,31 @@ fn test_version_sync_404_error() {
     mock.assert();
 }
 
+#[test]
+fn test_version_sync_404_without_error_body() {
+    let mut server = mockito::Server::new();
+
+    let mock = server
+        .mock("GET", "/api/version")
+        .with_status(404)
+        .with_body("404 page not found")
+        .create();
+
+    let config = ClientConfig {
+        base_url: server.url(),
+        timeout: Duration::from_secs(30),
+        max_retries: 0,
+        ..Default::default()
+    };
+
+    let client = OllamaClient::new(config).unwrap();
+    let result = client.version_blocking();
+
+    // Bodies without an "error" field only report the status code
+    assert!(matches!(result, Err(Error::HttpStatusError(404))));
+    mock.assert();
+}
+
 #[test]
 fn test_version_sync_with_zero_retries() {
     let mut server = mockito::Server::new();
@@ -245,6 +281,7 @@ fn test_version_sync_with_zero_retries() {
         base_url: server.url(),
         timeout: Duration::from_secs(30),
         max_retries: 0,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -268,6 +305,7 @@ fn test_version_sync_500_error() {
         base_url: server.url(),
         timeout: Duration::from_secs(1),
         max_retries: 3,
+        ..Default::default()
     };
 
     let client = OllamaClient::new(config).unwrap();
@@ -276,3 +314,93 @@ fn test_version_sync_500_error() {
     assert!(result.is_err());
     mock.assert();
 }
+
+/// Start a keep-alive HTTP server answering every request with a version
+/// response, returning its URL and a counter of accepted connections
+fn spawn_keep_alive_server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let connections = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&connections);
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { break };
+            counter.fetch_add(1, Ordering::SeqCst);
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut writer = stream;
+                let body = r#"{"version":"0.12.6"}"#;
+                loop {
+                    // Read request headers up to the blank line
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        match reader.read_line(&mut line) {
+                            Ok(0) | Err(_) => return,
+                            Ok(_) if line == "\r\n" => break,
+                            Ok(_) => {}
+                        }
+                    }
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    if writer.write_all(response.as_bytes()).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    (url, connections)
+}
+
+#[test]
+fn test_version_sync_reuses_pooled_connection() {
+    let (url, connections) = spawn_keep_alive_server();
+    let client = OllamaClient::with_base_url(url).unwrap();
+    let clone = client.clone();
+
+    for _ in 0..3 {
+        assert_eq!(client.version_blocking().unwrap().version, "0.12.6");
+    }
+    assert_eq!(clone.version_blocking().unwrap().version, "0.12.6");
+
+    // All sequential requests, including those made through the clone,
+    // share one pooled connection
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_version_sync_from_spawn_blocking() {
+    let mut server = mockito::Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/api/version")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"version":"0.12.6"}"#)
+        .expect(4)
+        .create_async()
+        .await;
+
+    // Created inside the runtime, used from blocking tasks
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+
+    let mut handles = vec![];
+    for _ in 0..4 {
+        let client = client.clone();
+        handles.push(tokio::task::spawn_blocking(move || {
+            client.version_blocking()
+        }));
+    }
+
+    for handle in handles {
+        assert_eq!(handle.await.unwrap().unwrap().version, "0.12.6");
+    }
+
+    mock.assert_async().await;
+}
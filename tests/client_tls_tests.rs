@@ -0,0 +1,365 @@
+//! Tests for proxy, TLS and connection tuning options
+//!
+//! TLS tests run against a local HTTPS server using the certificates in
+//! `tests/fixtures/tls`.
+
+use mockito::{Matcher, Server};
+use ollama_oxide::{
+    ClientConfig, ClientIdentity, Error, OllamaApiAsync, OllamaApiSync, OllamaClient,
+};
+use openssl::ssl::{SslAcceptor, SslFiletype, SslMethod, SslVerifyMode};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+const CA_PEM: &[u8] = include_bytes!("fixtures/tls/ca.pem");
+const CLIENT_PEM: &[u8] = include_bytes!("fixtures/tls/client.pem");
+const CLIENT_KEY: &[u8] = include_bytes!("fixtures/tls/client.key");
+const VERSION_BODY: &str = r#"{"version":"0.12.6"}"#;
+
+/// Local HTTPS server answering every request with a version response
+struct TlsServer {
+    url: String,
+    connections: Arc<AtomicUsize>,
+}
+
+impl TlsServer {
+    /// Start the server, optionally requiring a client certificate
+    fn start(require_client_cert: bool) -> Self {
+        let fixtures = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tls");
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor
+            .set_private_key_file(format!("{}/server.key", fixtures), SslFiletype::PEM)
+            .unwrap();
+        acceptor
+            .set_certificate_chain_file(format!("{}/server.pem", fixtures))
+            .unwrap();
+        if require_client_cert {
+            acceptor
+                .set_ca_file(format!("{}/ca.pem", fixtures))
+                .unwrap();
+            acceptor.set_verify(SslVerifyMode::PEER | SslVerifyMode::FAIL_IF_NO_PEER_CERT);
+        }
+        let acceptor = Arc::new(acceptor.build());
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("https://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&connections);
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { break };
+                let acceptor = Arc::clone(&acceptor);
+                let counter = Arc::clone(&counter);
+                thread::spawn(move || {
+                    let Ok(stream) = acceptor.accept(stream) else {
+                        return;
+                    };
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    serve_keep_alive(stream);
+                });
+            }
+        });
+
+        Self { url, connections }
+    }
+
+    fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+/// Answer requests on one connection until the client closes it
+fn serve_keep_alive<S: std::io::Read + Write>(stream: S) {
+    let mut reader = BufReader::new(stream);
+    loop {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(_) if line == "\r\n" => break,
+                Ok(_) => {}
+            }
+        }
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+            VERSION_BODY.len(),
+            VERSION_BODY
+        );
+        let stream = reader.get_mut();
+        if stream.write_all(response.as_bytes()).is_err() || stream.flush().is_err() {
+            return;
+        }
+    }
+}
+
+fn make_config(base_url: &str) -> ClientConfig {
+    ClientConfig::default()
+        .with_base_url(base_url)
+        .with_timeout(Duration::from_secs(5))
+        .with_max_retries(0)
+}
+
+// ============================================================================
+// Server Certificates
+// ============================================================================
+
+#[tokio::test]
+async fn test_untrusted_certificate_rejected() {
+    let server = TlsServer::start(false);
+    let client = OllamaClient::new(make_config(&server.url)).unwrap();
+
+    let result = client.version().await;
+
+    assert!(result.is_err());
+    assert_eq!(server.connections(), 0);
+}
+
+#[tokio::test]
+async fn test_root_certificate_async() {
+    let server = TlsServer::start(false);
+    let config = make_config(&server.url).with_root_certificate(CA_PEM);
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version().await.unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+}
+
+#[test]
+fn test_root_certificate_sync() {
+    let server = TlsServer::start(false);
+    let config = make_config(&server.url).with_root_certificate(CA_PEM);
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version_blocking().unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+}
+
+#[tokio::test]
+async fn test_accept_invalid_certs_async() {
+    let server = TlsServer::start(false);
+    let config = make_config(&server.url).with_accept_invalid_certs(true);
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version().await.unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+}
+
+#[test]
+fn test_accept_invalid_certs_sync() {
+    let server = TlsServer::start(false);
+    let config = make_config(&server.url).with_accept_invalid_certs(true);
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version_blocking().unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+}
+
+#[test]
+fn test_invalid_root_certificate_rejected_at_construction() {
+    let config =
+        make_config("https://127.0.0.1:1").with_root_certificate(b"not a certificate".to_vec());
+
+    let result = OllamaClient::new(config);
+
+    assert!(matches!(result, Err(Error::ConfigError(msg)) if msg.contains("root certificate")));
+}
+
+// ============================================================================
+// Mutual TLS
+// ============================================================================
+
+#[tokio::test]
+async fn test_mtls_without_identity_rejected() {
+    let server = TlsServer::start(true);
+    let config = make_config(&server.url).with_root_certificate(CA_PEM);
+    let client = OllamaClient::new(config).unwrap();
+
+    let result = client.version().await;
+
+    assert!(result.is_err());
+    assert_eq!(server.connections(), 0);
+}
+
+#[tokio::test]
+async fn test_mtls_with_identity_async() {
+    let server = TlsServer::start(true);
+    let config = make_config(&server.url)
+        .with_root_certificate(CA_PEM)
+        .with_identity(ClientIdentity::pem(CLIENT_PEM, CLIENT_KEY));
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version().await.unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+}
+
+#[test]
+fn test_mtls_with_identity_sync() {
+    let server = TlsServer::start(true);
+    let config = make_config(&server.url)
+        .with_root_certificate(CA_PEM)
+        .with_identity(ClientIdentity::pem(CLIENT_PEM, CLIENT_KEY));
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version_blocking().unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+}
+
+#[test]
+fn test_invalid_identity_rejected_at_construction() {
+    let config = make_config("https://127.0.0.1:1")
+        .with_identity(ClientIdentity::pem(CLIENT_PEM, b"not a key".to_vec()));
+
+    let result = OllamaClient::new(config);
+
+    assert!(matches!(result, Err(Error::ConfigError(msg)) if msg.contains("client identity")));
+}
+
+#[test]
+fn test_identity_debug_redacts_key() {
+    let identity = ClientIdentity::pem(CLIENT_PEM, CLIENT_KEY);
+    let debug = format!("{:?}", identity);
+
+    assert!(debug.contains("redacted"));
+    assert!(!debug.contains("PRIVATE KEY"));
+}
+
+// ============================================================================
+// Connection Pool
+// ============================================================================
+
+#[tokio::test]
+async fn test_connections_reused_by_default() {
+    let server = TlsServer::start(false);
+    let config = make_config(&server.url).with_root_certificate(CA_PEM);
+    let client = OllamaClient::new(config).unwrap();
+
+    client.version().await.unwrap();
+    client.version().await.unwrap();
+
+    assert_eq!(server.connections(), 1);
+}
+
+#[tokio::test]
+async fn test_pool_max_idle_per_host_zero_disables_reuse() {
+    let server = TlsServer::start(false);
+    let config = make_config(&server.url)
+        .with_root_certificate(CA_PEM)
+        .with_pool_max_idle_per_host(0)
+        .with_pool_idle_timeout(Duration::from_secs(5))
+        .with_tcp_keepalive(Duration::from_secs(30));
+    let client = OllamaClient::new(config).unwrap();
+
+    client.version().await.unwrap();
+    client.version().await.unwrap();
+
+    assert_eq!(server.connections(), 2);
+}
+
+// ============================================================================
+// Timeouts
+// ============================================================================
+
+#[tokio::test]
+async fn test_read_timeout_limits_each_read() {
+    let mut server = Server::new_async().await;
+    let _mock = server
+        .mock("GET", "/api/version")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            thread::sleep(Duration::from_millis(500));
+            w.write_all(VERSION_BODY.as_bytes())
+        })
+        .create_async()
+        .await;
+
+    // The total timeout is generous, the gap before the body is not
+    let config = make_config(&server.url()).with_read_timeout(Duration::from_millis(100));
+    let client = OllamaClient::new(config).unwrap();
+
+    let result = client.version().await;
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_read_timeout_replaces_timeout_for_blocking() {
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/api/version")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            thread::sleep(Duration::from_millis(500));
+            w.write_all(VERSION_BODY.as_bytes())
+        })
+        .create();
+
+    let config = make_config(&server.url()).with_read_timeout(Duration::from_millis(100));
+    let client = OllamaClient::new(config).unwrap();
+
+    let result = client.version_blocking();
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Proxies
+// ============================================================================
+
+#[tokio::test]
+async fn test_http_proxy_async() {
+    let mut proxy = Server::new_async().await;
+    let mock = proxy
+        .mock("GET", Matcher::Regex("/api/version$".to_string()))
+        .with_status(200)
+        .with_body(VERSION_BODY)
+        .create_async()
+        .await;
+
+    // The target host does not exist; only the proxy can answer
+    let config = make_config("http://ollama.invalid:11434").with_http_proxy(proxy.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version().await.unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_http_proxy_sync() {
+    let mut proxy = Server::new();
+    let mock = proxy
+        .mock("GET", Matcher::Regex("/api/version$".to_string()))
+        .with_status(200)
+        .with_body(VERSION_BODY)
+        .create();
+
+    let config = make_config("http://ollama.invalid:11434").with_proxy(proxy.url());
+    let client = OllamaClient::new(config).unwrap();
+
+    let response = client.version_blocking().unwrap();
+
+    assert_eq!(response.version, "0.12.6");
+    mock.assert();
+}
+
+#[test]
+fn test_invalid_proxy_url_rejected_at_construction() {
+    let config = make_config("http://localhost:11434").with_https_proxy("not a url");
+
+    let result = OllamaClient::new(config);
+
+    assert!(matches!(result, Err(Error::ConfigError(msg)) if msg.contains("proxy")));
+}
@@ -1,7 +1,7 @@
 // Error Type Tests - Phase 0 TDD
 // These tests validate the Error enum and its implementations
 
-use ollama_oxide::{Error, Result};
+use ollama_oxide::{ApiErrorKind, Error, Result};
 
 #[test]
 fn test_error_is_send_sync() {
@@ -28,13 +28,48 @@ fn test_serialization_error_display() {
 #[test]
 fn test_api_error_display() {
     let error = Error::ApiError {
+        status: 404,
+        kind: ApiErrorKind::ModelNotFound,
         message: "model not found".to_string(),
     };
     let display = format!("{}", error);
     assert!(display.contains("API error"));
+    assert!(display.contains("404"));
     assert!(display.contains("model not found"));
 }
 
+#[test]
+fn test_api_error_accessors() {
+    let error = Error::ApiError {
+        status: 404,
+        kind: ApiErrorKind::ModelNeedsPulling,
+        message: "model \"llama3.2\" not found, try pulling it first".to_string(),
+    };
+    assert_eq!(error.status_code(), Some(404));
+    assert_eq!(
+        error.api_error_kind(),
+        Some(ApiErrorKind::ModelNeedsPulling)
+    );
+    assert!(error.is_model_not_found());
+
+    let error = Error::HttpStatusError(503);
+    assert_eq!(error.status_code(), Some(503));
+    assert_eq!(error.api_error_kind(), None);
+    assert!(!error.is_model_not_found());
+}
+
+#[test]
+fn test_api_error_kind_classify() {
+    assert_eq!(
+        ApiErrorKind::classify(404, "model 'x' not found"),
+        ApiErrorKind::ModelNotFound
+    );
+    assert_eq!(
+        ApiErrorKind::classify(400, "invalid input type"),
+        ApiErrorKind::InvalidRequest
+    );
+}
+
 #[test]
 fn test_connection_error_display() {
     let error = Error::ConnectionError("timeout".to_string());
@@ -75,6 +110,59 @@ fn test_max_retries_exceeded_error_display() {
     assert!(display.contains("exceeded"));
 }
 
+#[test]
+fn test_max_iterations_exceeded_error_display() {
+    let error = Error::MaxIterationsExceededError(10);
+    let display = format!("{}", error);
+    assert!(display.contains("10"));
+    assert!(display.contains("iterations"));
+}
+
+#[test]
+fn test_auth_error_display() {
+    let error = Error::AuthError("token expired".to_string());
+    let display = format!("{}", error);
+    assert!(display.contains("Authentication error"));
+    assert!(display.contains("token expired"));
+}
+
+#[test]
+fn test_config_error_display() {
+    let error = Error::ConfigError("invalid proxy URL".to_string());
+    let display = format!("{}", error);
+    assert!(display.contains("Invalid configuration"));
+    assert!(display.contains("invalid proxy URL"));
+}
+
+#[test]
+fn test_io_error_from_std() {
+    let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "model.gguf missing");
+    let error: Error = io_error.into();
+    assert!(matches!(error, Error::IoError(_)));
+    let display = format!("{}", error);
+    assert!(display.contains("I/O error"));
+    assert!(display.contains("model.gguf missing"));
+}
+
+#[test]
+fn test_validation_error_display() {
+    let error = Error::ValidationError("top_p must be between 0 and 1, got 1.5".to_string());
+    let display = format!("{}", error);
+    assert!(display.contains("Validation error"));
+    assert!(display.contains("top_p"));
+}
+
+#[test]
+fn test_structured_output_error_display() {
+    let error = Error::StructuredOutputError {
+        message: "missing field `name`".to_string(),
+        raw: r#"{"age": 3}"#.to_string(),
+    };
+    let display = format!("{}", error);
+    assert!(display.contains("Structured output error"));
+    assert!(display.contains("missing field `name`"));
+}
+
 #[test]
 fn test_error_is_std_error() {
     let error = Error::HttpError("test".to_string());
@@ -95,6 +183,8 @@ fn test_result_type_alias() {
 #[test]
 fn test_error_debug_format() {
     let error = Error::ApiError {
+        status: 500,
+        kind: ApiErrorKind::ServerError,
         message: "test error".to_string(),
     };
     let debug = format!("{:?}", error);
@@ -0,0 +1,319 @@
+//! Tests for Middleware hooks around OllamaClient requests
+
+use futures::StreamExt;
+use ollama_oxide::testing::{MockOllama, MockResponse};
+use ollama_oxide::{
+    ChatMessage, ChatRequest, Error, GenerateRequest, Middleware, OllamaApiAsync, OllamaApiSync,
+    OllamaClient, Result, TransportRequest, TransportResponse,
+};
+use reqwest::header::{HeaderName, HeaderValue};
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+fn chat_reply(content: &str) -> Value {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": true
+    })
+}
+
+fn chat(model: &str) -> ChatRequest {
+    ChatRequest::new(model, [ChatMessage::user("Hello")])
+}
+
+/// Keeps a line for every hook call, shared between clones
+#[derive(Debug, Clone, Default)]
+struct EventLog(Arc<Mutex<Vec<String>>>);
+
+impl EventLog {
+    fn push(&self, event: String) {
+        self.0.lock().unwrap().push(event);
+    }
+
+    fn events(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+/// Logs every hook under a name
+#[derive(Debug)]
+struct Recorder {
+    name: &'static str,
+    log: EventLog,
+}
+
+impl Middleware for Recorder {
+    fn before_request(&self, request: &mut TransportRequest) -> Result<Option<TransportResponse>> {
+        self.log
+            .push(format!("{} before {}", self.name, request.path));
+        Ok(None)
+    }
+
+    fn after_response(
+        &self,
+        _request: &TransportRequest,
+        response: &TransportResponse,
+        _elapsed: Duration,
+    ) {
+        self.log
+            .push(format!("{} after {}", self.name, response.status));
+    }
+
+    fn on_error(&self, _request: &TransportRequest, error: &Error, _elapsed: Duration) {
+        self.log.push(format!("{} error {}", self.name, error));
+    }
+}
+
+/// Adds a trace ID header to every request
+#[derive(Debug)]
+struct TraceId(&'static str);
+
+impl Middleware for TraceId {
+    fn before_request(&self, request: &mut TransportRequest) -> Result<Option<TransportResponse>> {
+        request.headers.insert(
+            HeaderName::from_static("x-trace-id"),
+            HeaderValue::from_static(self.0),
+        );
+        Ok(None)
+    }
+}
+
+/// Rejects chat requests for models outside a fixed list
+#[derive(Debug)]
+struct ModelAllowlist(&'static [&'static str]);
+
+impl Middleware for ModelAllowlist {
+    fn before_request(&self, request: &mut TransportRequest) -> Result<Option<TransportResponse>> {
+        if let Some(chat) = request.body_json::<ChatRequest>().transpose()?
+            && !self.0.contains(&chat.model.as_str())
+        {
+            return Err(Error::ValidationError(format!(
+                "model '{}' is not allowed",
+                chat.model
+            )));
+        }
+        Ok(None)
+    }
+}
+
+/// Answers repeated requests from the successful responses it has seen
+#[derive(Debug, Default)]
+struct ResponseCache(Mutex<HashMap<String, Vec<u8>>>);
+
+impl ResponseCache {
+    fn key(request: &TransportRequest) -> String {
+        format!("{} {:?}", request.path, request.body)
+    }
+}
+
+impl Middleware for ResponseCache {
+    fn before_request(&self, request: &mut TransportRequest) -> Result<Option<TransportResponse>> {
+        let cache = self.0.lock().unwrap();
+        Ok(cache.get(&Self::key(request)).map(|body| {
+            let body = serde_json::from_slice(body).unwrap();
+            TransportResponse::json(200, &body)
+        }))
+    }
+
+    fn after_response(
+        &self,
+        request: &TransportRequest,
+        response: &TransportResponse,
+        _elapsed: Duration,
+    ) {
+        if let (true, Some(body)) = (response.is_success(), response.body.as_bytes()) {
+            let mut cache = self.0.lock().unwrap();
+            cache.insert(Self::key(request), body.to_vec());
+        }
+    }
+}
+
+// ============================================================================
+// Request Hook Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_middleware_adds_trace_header() {
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!")));
+    let client = server
+        .client()
+        .unwrap()
+        .with_middleware(TraceId("trace-42"));
+
+    client.chat(&chat("qwen3:0.6b")).await.unwrap();
+
+    let recorded = server.last_request().unwrap();
+    assert_eq!(recorded.header("x-trace-id"), Some("trace-42"));
+}
+
+#[test]
+fn test_middleware_rejects_model_outside_allowlist() {
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!")));
+    let log = EventLog::default();
+    let client = OllamaClient::with_base_url(server.url())
+        .unwrap()
+        .with_middleware(Recorder {
+            name: "log",
+            log: log.clone(),
+        })
+        .with_middleware(ModelAllowlist(&["qwen3:0.6b"]));
+
+    assert!(client.chat_blocking(&chat("qwen3:0.6b")).is_ok());
+    let result = client.chat_blocking(&chat("llama3:70b"));
+
+    assert!(
+        matches!(result, Err(Error::ValidationError(message)) if message.contains("llama3:70b"))
+    );
+    assert_eq!(server.requests_to("/api/chat").len(), 1);
+    assert_eq!(
+        log.events(),
+        [
+            "log before /api/chat",
+            "log after 200",
+            "log before /api/chat",
+            "log error Validation error: model 'llama3:70b' is not allowed",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_middleware_short_circuits_with_cached_response() {
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!")));
+    let log = EventLog::default();
+    let client = server
+        .client()
+        .unwrap()
+        .with_middleware(ResponseCache::default())
+        .with_middleware(Recorder {
+            name: "inner",
+            log: log.clone(),
+        });
+
+    let first = client.chat(&chat("qwen3:0.6b")).await.unwrap();
+    let second = client.chat(&chat("qwen3:0.6b")).await.unwrap();
+
+    assert_eq!(first.content(), Some("Hi!"));
+    assert_eq!(second.content(), Some("Hi!"));
+    assert_eq!(server.requests_to("/api/chat").len(), 1);
+    // The cached answer skips the middlewares registered after the cache
+    assert_eq!(log.events(), ["inner before /api/chat", "inner after 200"]);
+}
+
+// ============================================================================
+// Response Hook Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_after_hooks_run_in_reverse_order_with_status() {
+    let server = MockOllama::start().unwrap();
+    server.on_generate(MockResponse::error(404, "model 'nope' not found"));
+    let log = EventLog::default();
+    let client = server
+        .client()
+        .unwrap()
+        .with_middleware(Recorder {
+            name: "outer",
+            log: log.clone(),
+        })
+        .with_middleware(Recorder {
+            name: "inner",
+            log: log.clone(),
+        });
+
+    let result = client.generate(&GenerateRequest::new("nope", "Hi")).await;
+
+    assert_eq!(result.unwrap_err().status_code(), Some(404));
+    assert_eq!(
+        log.events(),
+        [
+            "outer before /api/generate",
+            "inner before /api/generate",
+            "inner after 404",
+            "outer after 404",
+        ]
+    );
+}
+
+#[test]
+fn test_after_hook_sees_typed_body_and_timing() {
+    #[derive(Debug)]
+    struct Observation {
+        model: Option<String>,
+        content: Option<Value>,
+        elapsed: Duration,
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct Observed(Arc<Mutex<Vec<Observation>>>);
+
+    impl Middleware for Observed {
+        fn after_response(
+            &self,
+            request: &TransportRequest,
+            response: &TransportResponse,
+            elapsed: Duration,
+        ) {
+            let observation = Observation {
+                model: request
+                    .body_json::<ChatRequest>()
+                    .map(|chat| chat.unwrap().model),
+                content: response
+                    .body_json::<Value>()
+                    .map(|body| body.unwrap()["message"]["content"].clone()),
+                elapsed,
+            };
+            self.0.lock().unwrap().push(observation);
+        }
+    }
+
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!")).with_delay(Duration::from_millis(50)));
+    let observed = Observed::default();
+    let client = OllamaClient::with_base_url(server.url())
+        .unwrap()
+        .with_middleware(observed.clone());
+
+    let response = client.chat_blocking(&chat("qwen3:0.6b")).unwrap();
+
+    assert_eq!(response.content(), Some("Hi!"));
+    let observed = observed.0.lock().unwrap();
+    assert_eq!(observed[0].model.as_deref(), Some("qwen3:0.6b"));
+    assert_eq!(observed[0].content, Some(json!("Hi!")));
+    assert!(
+        observed[0].elapsed >= Duration::from_millis(50),
+        "{:?}",
+        observed
+    );
+}
+
+#[tokio::test]
+async fn test_streaming_response_is_passed_on_unread() {
+    let server = MockOllama::start().unwrap();
+    server.on_generate(MockResponse::stream([
+        json!({"response": "Hel", "done": false}),
+        json!({"response": "lo", "done": true}),
+    ]));
+    let log = EventLog::default();
+    let client = server.client().unwrap().with_middleware(Recorder {
+        name: "log",
+        log: log.clone(),
+    });
+
+    let text: String = client
+        .generate_stream(&GenerateRequest::new("qwen3:0.6b", "Hi"))
+        .await
+        .unwrap()
+        .map(|chunk| chunk.unwrap().text().unwrap_or_default().to_string())
+        .collect::<Vec<_>>()
+        .await
+        .concat();
+
+    assert_eq!(text, "Hello");
+    assert_eq!(log.events(), ["log before /api/generate", "log after 200"]);
+}
@@ -0,0 +1,238 @@
+//! Tests for the `testing` feature's MockOllama server against OllamaClient
+
+use futures::StreamExt;
+use ollama_oxide::testing::{MockOllama, MockResponse};
+use ollama_oxide::{
+    ApiErrorKind, ChatMessage, ChatRequest, ClientConfig, DeleteRequest, Error, GenerateRequest,
+    OllamaApiAsync, OllamaApiSync, OllamaClient, PullRequest,
+};
+use serde_json::{Value, json};
+use std::time::{Duration, Instant};
+
+fn make_config(base_url: String, max_retries: u32) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(30),
+        max_retries,
+        ..Default::default()
+    }
+}
+
+fn chat_reply(content: &str, done: bool) -> Value {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": done
+    })
+}
+
+// ============================================================================
+// Scripted Response Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_mock_ollama_chat_records_request() {
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!", true)));
+    let client = server.client().unwrap();
+
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let response = client.chat(&request).await.unwrap();
+
+    assert_eq!(response.content(), Some("Hi!"));
+    let recorded = server.last_request().unwrap();
+    assert_eq!(recorded.method, "POST");
+    assert_eq!(recorded.path, "/api/chat");
+    assert_eq!(recorded.header("content-type"), Some("application/json"));
+    let sent: ChatRequest = recorded.body_json().unwrap();
+    assert_eq!(sent.messages[0].content, "Hello");
+    assert_eq!(sent.stream, Some(false));
+}
+
+#[test]
+fn test_mock_ollama_blocking_client() {
+    let server = MockOllama::start().unwrap();
+    server.on_version(MockResponse::json(json!({"version": "0.12.6"})));
+    server.on_delete(MockResponse::empty(200));
+    let client = OllamaClient::with_base_url(server.url()).unwrap();
+
+    assert_eq!(client.version_blocking().unwrap().version, "0.12.6");
+    client
+        .delete_model_blocking(&DeleteRequest::new("old:latest"))
+        .unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1].method, "DELETE");
+    assert_eq!(
+        requests[1].body_json::<Value>().unwrap()["model"],
+        "old:latest"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_ollama_chat_stream() {
+    let server = MockOllama::start().unwrap();
+    server.on_chat(MockResponse::stream([
+        chat_reply("Hel", false),
+        chat_reply("lo", false),
+        chat_reply("", true),
+    ]));
+    let client = server.client().unwrap();
+
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let chunks: Vec<_> = client
+        .chat_stream(&request)
+        .await
+        .unwrap()
+        .map(|chunk| chunk.unwrap())
+        .collect()
+        .await;
+
+    let text: String = chunks.iter().filter_map(|c| c.content()).collect();
+    assert_eq!(text, "Hello");
+    assert!(chunks.last().unwrap().done.unwrap_or_default());
+    assert_eq!(
+        server.requests_to("/api/chat")[0]
+            .body_json::<Value>()
+            .unwrap()["stream"],
+        true
+    );
+}
+
+#[test]
+fn test_mock_ollama_generate_stream_blocking_with_chunk_delay() {
+    let server = MockOllama::start().unwrap();
+    server.on_generate(
+        MockResponse::stream([
+            json!({"model": "qwen3:0.6b", "response": "a", "done": false}),
+            json!({"model": "qwen3:0.6b", "response": "b", "done": true}),
+        ])
+        .with_chunk_delay(Duration::from_millis(50)),
+    );
+    let client = server.client().unwrap();
+
+    let start = Instant::now();
+    let request = GenerateRequest::new("qwen3:0.6b", "Say ab");
+    let text: String = client
+        .generate_stream_blocking(&request)
+        .unwrap()
+        .map(|chunk| chunk.unwrap().response.unwrap_or_default())
+        .collect();
+
+    assert_eq!(text, "ab");
+    assert!(start.elapsed() >= Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn test_mock_ollama_pull_stream() {
+    let server = MockOllama::start().unwrap();
+    server.on_pull(MockResponse::stream([
+        json!({"status": "pulling manifest"}),
+        json!({"status": "downloading", "digest": "sha256:ab", "total": 100, "completed": 50}),
+        json!({"status": "success"}),
+    ]));
+    let client = server.client().unwrap();
+
+    let events: Vec<_> = client
+        .pull_model_stream(&PullRequest::new("llama3.2"))
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 3);
+    assert!(events[2].is_success());
+}
+
+#[tokio::test]
+async fn test_mock_ollama_blob_routes_match_digest_paths() {
+    let server = MockOllama::start().unwrap();
+    server.on_blob_check(MockResponse::empty(404));
+    let client = server.client().unwrap();
+
+    assert!(!client.blob_exists("sha256:ab").await.unwrap());
+    assert_eq!(server.last_request().unwrap().path, "/api/blobs/sha256:ab");
+}
+
+// ============================================================================
+// Injected Failure Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_mock_ollama_queued_responses_drive_retries() {
+    let server = MockOllama::start().unwrap();
+    server
+        .on_chat(MockResponse::error(503, "busy"))
+        .on_chat(MockResponse::json(chat_reply("ok", true)));
+    let client = OllamaClient::new(make_config(server.url(), 2)).unwrap();
+
+    let request = ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hello")]);
+    let response = client.chat(&request).await.unwrap();
+
+    assert_eq!(response.content(), Some("ok"));
+    assert_eq!(server.requests_to("/api/chat").len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_ollama_error_response() {
+    let server = MockOllama::start().unwrap();
+    server.on_show(MockResponse::error(404, "model 'missing' not found"));
+    let client = server.client().unwrap();
+
+    let result = client
+        .show_model(&ollama_oxide::ShowRequest::new("missing"))
+        .await;
+
+    match result {
+        Err(Error::ApiError { status, kind, .. }) => {
+            assert_eq!(status, 404);
+            assert_eq!(kind, ApiErrorKind::ModelNotFound);
+        }
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_mock_ollama_delay_triggers_client_timeout() {
+    let server = MockOllama::start().unwrap();
+    server
+        .on_tags(MockResponse::json(json!({"models": []})).with_delay(Duration::from_millis(500)));
+    let config = ClientConfig {
+        timeout: Duration::from_millis(100),
+        ..make_config(server.url(), 0)
+    };
+    let client = OllamaClient::new(config).unwrap();
+
+    assert!(client.list_models().await.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_ollama_disconnect() {
+    let server = MockOllama::start().unwrap();
+    server
+        .on_version(MockResponse::disconnect())
+        .on_version(MockResponse::json(json!({"version": "0.12.6"})));
+    let client = OllamaClient::new(make_config(server.url(), 0)).unwrap();
+
+    assert!(client.version().await.is_err());
+    assert_eq!(client.version().await.unwrap().version, "0.12.6");
+}
+
+#[tokio::test]
+async fn test_mock_ollama_unscripted_request_and_reset() {
+    let server = MockOllama::start().unwrap();
+    server.on_ps(MockResponse::json(json!({"models": []})));
+    let client = OllamaClient::new(make_config(server.url(), 0)).unwrap();
+
+    assert!(client.list_running_models().await.is_ok());
+    server.reset();
+    assert!(server.requests().is_empty());
+
+    match client.list_running_models().await {
+        Err(Error::ApiError { status, .. }) => assert_eq!(status, 501),
+        other => panic!("expected ApiError, got {:?}", other),
+    }
+    assert_eq!(server.requests().len(), 1);
+}
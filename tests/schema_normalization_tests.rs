@@ -0,0 +1,327 @@
+//! Tests for Ollama-compatible schema normalization against tricky Rust types
+
+use std::collections::HashMap;
+
+use ollama_oxide::FormatSetting;
+use ollama_oxide::tools::{Tool, ToolRegistry, ToolResult, normalize_schema};
+use ollama_oxide::{ToolCall, ToolCallFunction};
+use schemars::{JsonSchema, schema_for};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Address {
+    street: String,
+    zip: Option<u32>,
+}
+
+/// Unit of temperature
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[allow(dead_code)]
+enum Unit {
+    Celsius,
+    Fahrenheit,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(dead_code)]
+enum Priority {
+    /// Can wait
+    Low,
+    /// Do it now
+    High,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(tag = "kind")]
+#[allow(dead_code)]
+enum Shape {
+    Circle { r: f64 },
+    Square { side: f64 },
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(dead_code)]
+enum Mixed {
+    Unit,
+    Tuple(i32),
+    Struct { x: i32 },
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Node {
+    value: i32,
+    children: Vec<Node>,
+    next: Option<Box<Node>>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[allow(dead_code)]
+struct Params {
+    /// The address
+    address: Address,
+    maybe_address: Option<Address>,
+    /// A unit
+    unit: Unit,
+    maybe_unit: Option<Unit>,
+    priority: Priority,
+    shape: Shape,
+    mixed: Mixed,
+    node: Node,
+    list: Vec<Address>,
+    map: HashMap<String, i32>,
+    pair: (i32, String),
+    small: u8,
+    #[serde(default)]
+    defaulted: String,
+    opt_list: Option<Vec<String>>,
+    opt_opt: Option<Option<i32>>,
+    wrapped: Wrapper<Unit>,
+}
+
+fn params_schema() -> Value {
+    normalize_schema(serde_json::to_value(schema_for!(Params)).unwrap())
+}
+
+/// Collect every key used anywhere in a schema
+fn keys(value: &Value, found: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                found.push(key.clone());
+                keys(child, found);
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| keys(item, found)),
+        _ => {}
+    }
+}
+
+// ============================================================================
+// Structure Tests
+// ============================================================================
+
+#[test]
+fn test_normalized_schema_has_no_meta_or_ref_keys() {
+    let mut found = Vec::new();
+    keys(&params_schema(), &mut found);
+
+    for key in ["$schema", "title", "$defs", "definitions", "$ref"] {
+        assert!(!found.iter().any(|k| k == key), "{} left in schema", key);
+    }
+}
+
+#[test]
+fn test_normalized_schema_has_no_null_types() {
+    let text = params_schema().to_string();
+    assert!(!text.contains("\"null\""), "{}", text);
+    assert!(!text.contains("uint8") && !text.contains("int32"));
+}
+
+#[test]
+fn test_normalized_required_excludes_options() {
+    assert_eq!(
+        params_schema()["required"],
+        json!([
+            "address", "unit", "priority", "shape", "mixed", "node", "list", "map", "pair",
+            "small", "wrapped"
+        ])
+    );
+}
+
+#[test]
+fn test_normalized_nested_struct_is_inlined() {
+    let schema = params_schema();
+
+    assert_eq!(
+        schema["properties"]["address"],
+        json!({
+            "description": "The address",
+            "type": "object",
+            "properties": {
+                "street": {"type": "string"},
+                "zip": {"type": "integer", "minimum": 0}
+            },
+            "required": ["street"]
+        })
+    );
+    assert_eq!(
+        schema["properties"]["maybe_address"]["properties"]["street"],
+        json!({"type": "string"})
+    );
+    assert_eq!(
+        schema["properties"]["list"]["items"]["required"],
+        json!(["street"])
+    );
+}
+
+#[test]
+fn test_normalized_enums_are_plain_arrays() {
+    let schema = params_schema();
+
+    assert_eq!(
+        schema["properties"]["unit"],
+        json!({"description": "A unit", "type": "string", "enum": ["celsius", "fahrenheit"]})
+    );
+    assert_eq!(
+        schema["properties"]["maybe_unit"],
+        json!({
+            "description": "Unit of temperature",
+            "type": "string",
+            "enum": ["celsius", "fahrenheit"]
+        })
+    );
+    assert_eq!(
+        schema["properties"]["priority"],
+        json!({
+            "description": "Low: Can wait\nHigh: Do it now",
+            "type": "string",
+            "enum": ["Low", "High"]
+        })
+    );
+    assert_eq!(
+        schema["properties"]["wrapped"]["properties"]["inner"]["enum"],
+        json!(["celsius", "fahrenheit"])
+    );
+}
+
+#[test]
+fn test_normalized_data_enums_keep_alternatives() {
+    let schema = params_schema();
+
+    let shapes = schema["properties"]["shape"]["oneOf"].as_array().unwrap();
+    assert_eq!(shapes.len(), 2);
+    assert_eq!(shapes[0]["properties"]["kind"]["const"], "Circle");
+    assert_eq!(shapes[1]["required"], json!(["kind", "side"]));
+
+    let mixed = schema["properties"]["mixed"]["oneOf"].as_array().unwrap();
+    assert_eq!(mixed.len(), 3);
+    assert_eq!(mixed[0], json!({"type": "string", "enum": ["Unit"]}));
+}
+
+#[test]
+fn test_normalized_recursive_type_is_cut() {
+    let schema = params_schema();
+    let node = &schema["properties"]["node"];
+
+    assert_eq!(node["properties"]["value"], json!({"type": "integer"}));
+    assert_eq!(
+        node["properties"]["children"],
+        json!({"type": "array", "items": {"type": "object"}})
+    );
+    assert_eq!(node["properties"]["next"], json!({"type": "object"}));
+    assert_eq!(node["required"], json!(["value", "children"]));
+}
+
+#[test]
+fn test_normalized_recursive_root() {
+    let schema = normalize_schema(serde_json::to_value(schema_for!(Node)).unwrap());
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(
+        schema["properties"]["children"]["items"],
+        json!({"type": "object"})
+    );
+    assert!(!schema.to_string().contains("$ref"));
+}
+
+#[test]
+fn test_normalized_collections_and_scalars() {
+    let schema = params_schema();
+    let properties = &schema["properties"];
+
+    assert_eq!(
+        properties["map"],
+        json!({"type": "object", "additionalProperties": {"type": "integer"}})
+    );
+    assert_eq!(
+        properties["pair"]["prefixItems"],
+        json!([{"type": "integer"}, {"type": "string"}])
+    );
+    assert_eq!(
+        properties["small"],
+        json!({"type": "integer", "maximum": 255, "minimum": 0})
+    );
+    assert_eq!(
+        properties["defaulted"],
+        json!({"type": "string", "default": ""})
+    );
+    assert_eq!(
+        properties["opt_list"],
+        json!({"type": "array", "items": {"type": "string"}})
+    );
+    assert_eq!(properties["opt_opt"], json!({"type": "integer"}));
+}
+
+// ============================================================================
+// Integration Tests
+// ============================================================================
+
+struct TrickyTool;
+
+impl Tool for TrickyTool {
+    type Params = Params;
+    type Output = bool;
+
+    fn name(&self) -> String {
+        "tricky".to_string()
+    }
+
+    fn description(&self) -> String {
+        "Accept tricky parameters".to_string()
+    }
+
+    async fn execute(&self, _params: Self::Params) -> ToolResult<Self::Output> {
+        Ok(true)
+    }
+}
+
+#[test]
+fn test_tool_definition_uses_normalized_schema() {
+    let definition = TrickyTool.to_definition();
+    assert_eq!(definition.function.parameters, params_schema());
+}
+
+#[test]
+fn test_format_setting_uses_normalized_schema() {
+    let FormatSetting::Schema(schema) = FormatSetting::schema_for::<Params>() else {
+        panic!("expected a schema format");
+    };
+    assert_eq!(schema, params_schema());
+}
+
+#[tokio::test]
+async fn test_normalized_schema_accepts_valid_arguments() {
+    let mut registry = ToolRegistry::new();
+    registry.register(TrickyTool);
+
+    let call = ToolCall::new(ToolCallFunction::with_arguments(
+        "tricky",
+        json!({
+            "address": {"street": "Main St"},
+            "maybe_address": {"street": "Side St", "zip": 12345},
+            "unit": "celsius",
+            "priority": "High",
+            "shape": {"kind": "Square", "side": 2.0},
+            "mixed": {"Struct": {"x": 1}},
+            "node": {"value": 1, "children": [{"value": 2, "children": []}]},
+            "list": [],
+            "map": {"a": 1},
+            "pair": [1, "one"],
+            "small": 7,
+            "opt_opt": 3,
+            "wrapped": {"inner": "fahrenheit"}
+        }),
+    ));
+
+    assert_eq!(registry.execute(&call).await.unwrap(), json!(true));
+}
@@ -0,0 +1,157 @@
+//! Tests for the `#[tool]` attribute macro
+
+use ollama_oxide::tools::{Tool, ToolError, ToolRegistry, ToolResult, tool};
+use ollama_oxide::{ToolCall, ToolCallFunction};
+use serde::Serialize;
+use serde_json::json;
+
+#[derive(Debug, Serialize)]
+struct Weather {
+    location: String,
+    unit: String,
+    temperature: f32,
+}
+
+/// Get the current weather for a location
+///
+/// Returns the temperature in the requested unit.
+#[tool]
+async fn get_weather(
+    /// City name, e.g. "Paris"
+    location: String,
+    /// Temperature unit, "celsius" or "fahrenheit"
+    unit: Option<String>,
+) -> ToolResult<Weather> {
+    let unit = unit.unwrap_or_else(|| "celsius".to_string());
+    let temperature = if unit == "fahrenheit" { 71.6 } else { 22.0 };
+    Ok(Weather {
+        location,
+        unit,
+        temperature,
+    })
+}
+
+/// Divide two numbers
+#[tool(name = "divide_numbers")]
+async fn divide(a: f64, b: f64) -> Result<f64, ToolError> {
+    if b == 0.0 {
+        return Err(ToolError::ExecutionError("division by zero".to_string()));
+    }
+    Ok(a / b)
+}
+
+#[tool(description = "Count the items")]
+async fn count(#[serde(default)] items: Vec<String>) -> ToolResult<usize> {
+    Ok(items.len())
+}
+
+// ============================================================================
+// Generated Definition Tests
+// ============================================================================
+
+#[test]
+fn test_tool_macro_name_and_description() {
+    assert_eq!(GetWeather.name(), "get_weather");
+    assert_eq!(
+        GetWeather.description(),
+        "Get the current weather for a location\n\nReturns the temperature in the requested unit."
+    );
+}
+
+#[test]
+fn test_tool_macro_parameter_schema() {
+    let schema = GetWeather.parameters_schema();
+
+    assert_eq!(schema["type"], "object");
+    assert!(schema.get("description").is_none());
+    assert_eq!(
+        schema["properties"]["location"]["description"],
+        "City name, e.g. \"Paris\""
+    );
+    assert_eq!(
+        schema["properties"]["unit"]["description"],
+        "Temperature unit, \"celsius\" or \"fahrenheit\""
+    );
+    assert_eq!(schema["required"], json!(["location"]));
+}
+
+#[test]
+fn test_tool_macro_arguments() {
+    assert_eq!(Divide.name(), "divide_numbers");
+    assert_eq!(Divide.description(), "Divide two numbers");
+    assert_eq!(Count.description(), "Count the items");
+
+    let definition = Count.to_definition();
+    assert_eq!(definition.name(), "count");
+    assert_eq!(definition.description(), Some("Count the items"));
+}
+
+// ============================================================================
+// Execution Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_tool_macro_execute() {
+    let params = GetWeatherParams {
+        location: "Paris".to_string(),
+        unit: Some("fahrenheit".to_string()),
+    };
+
+    let weather = GetWeather.execute(params).await.unwrap();
+    assert_eq!(weather.location, "Paris");
+    assert_eq!(weather.temperature, 71.6);
+}
+
+#[tokio::test]
+async fn test_tool_macro_function_still_callable() {
+    let weather = get_weather("Lisbon".to_string(), None).await.unwrap();
+    assert_eq!(weather.unit, "celsius");
+}
+
+#[tokio::test]
+async fn test_tool_macro_with_registry() {
+    let mut registry = ToolRegistry::new();
+    registry.register(GetWeather);
+    registry.register(Divide);
+    registry.register(Count);
+
+    let call = ToolCall::new(ToolCallFunction::with_arguments(
+        "get_weather",
+        json!({"location": "Paris"}),
+    ));
+    let result = registry.execute(&call).await.unwrap();
+    assert_eq!(result["location"], "Paris");
+    assert_eq!(result["unit"], "celsius");
+
+    let call = ToolCall::new(ToolCallFunction::with_arguments(
+        "divide_numbers",
+        json!({"a": 1.0, "b": 4.0}),
+    ));
+    assert_eq!(registry.execute(&call).await.unwrap(), json!(0.25));
+
+    let call = ToolCall::new(ToolCallFunction::with_arguments("count", json!({})));
+    assert_eq!(registry.execute(&call).await.unwrap(), json!(0));
+}
+
+#[tokio::test]
+async fn test_tool_macro_errors() {
+    let mut registry = ToolRegistry::new();
+    registry.register(Divide);
+
+    let call = ToolCall::new(ToolCallFunction::with_arguments(
+        "divide_numbers",
+        json!({"a": 1.0, "b": 0.0}),
+    ));
+    let result = registry.execute(&call).await;
+    assert!(matches!(result, Err(ToolError::ExecutionError(_))));
+
+    let call = ToolCall::new(ToolCallFunction::with_arguments(
+        "divide_numbers",
+        json!({"a": "one"}),
+    ));
+    let result = registry.execute(&call).await;
+    let Err(ToolError::InvalidArguments { violations, .. }) = result else {
+        panic!("expected InvalidArguments, got {:?}", result);
+    };
+    assert_eq!(violations.len(), 2);
+}
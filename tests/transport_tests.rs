@@ -0,0 +1,249 @@
+//! Tests for running OllamaClient over a custom in-memory transport
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use ollama_oxide::{
+    ChatMessage, ChatRequest, ClientConfig, Error, GenerateRequest, OllamaApiAsync, OllamaApiSync,
+    OllamaClient, RequestBody, ResponseBody, Result, RetryPolicy, Transport, TransportRequest,
+    TransportResponse,
+};
+use serde_json::{Value, json};
+use std::fmt;
+use std::io::Cursor;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+type Handler = dyn Fn(usize, &TransportRequest) -> Result<TransportResponse> + Send + Sync;
+
+/// Transport answering every request with a closure and keeping the requests
+#[derive(Clone)]
+struct MemoryTransport {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<TransportRequest>>>,
+}
+
+impl MemoryTransport {
+    /// `handler` gets the zero-based attempt number and the request
+    fn new(
+        handler: impl Fn(usize, &TransportRequest) -> Result<TransportResponse> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn requests(&self) -> Vec<TransportRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn answer(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let mut requests = self.requests.lock().unwrap();
+        let attempt = requests.len();
+        requests.push(request.clone());
+        drop(requests);
+        (self.handler)(attempt, &request)
+    }
+}
+
+impl fmt::Debug for MemoryTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryTransport").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl Transport for MemoryTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        self.answer(request)
+    }
+
+    fn send_blocking(&self, request: TransportRequest) -> Result<TransportResponse> {
+        self.answer(request)
+    }
+}
+
+fn make_client(transport: &MemoryTransport, max_retries: u32) -> OllamaClient {
+    let config = ClientConfig {
+        max_retries,
+        retry_policy: RetryPolicy::default()
+            .with_base_delay(Duration::from_millis(1))
+            .with_jitter(0.0),
+        ..Default::default()
+    };
+    OllamaClient::with_transport(config, transport.clone()).unwrap()
+}
+
+fn chat_reply(content: &str, done: bool) -> Value {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": done
+    })
+}
+
+fn ndjson(chunks: &[Value]) -> Vec<u8> {
+    chunks
+        .iter()
+        .map(|chunk| format!("{}\n", chunk))
+        .collect::<String>()
+        .into_bytes()
+}
+
+// ============================================================================
+// Request Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_transport_receives_path_and_json_body() {
+    let transport =
+        MemoryTransport::new(|_, _| Ok(TransportResponse::json(200, &chat_reply("Hi!", true))));
+    let client = make_client(&transport, 0);
+
+    let response = client
+        .chat(&ChatRequest::new(
+            "qwen3:0.6b",
+            [ChatMessage::user("Hello")],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.content(), Some("Hi!"));
+    let request = &transport.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/api/chat");
+    assert!(!request.streaming);
+    let RequestBody::Json(body) = &request.body else {
+        panic!("expected JSON body, got {:?}", request.body);
+    };
+    assert_eq!(body["messages"][0]["content"], "Hello");
+}
+
+#[test]
+fn test_blocking_get_has_no_body() {
+    let transport = MemoryTransport::new(|_, _| {
+        Ok(TransportResponse::json(200, &json!({"version": "0.12.6"})))
+    });
+    let client = make_client(&transport, 0);
+
+    assert_eq!(client.version_blocking().unwrap().version, "0.12.6");
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/api/version");
+    assert_eq!(request.body, RequestBody::Empty);
+}
+
+// ============================================================================
+// Streaming Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_async_stream_from_chunked_body() {
+    let transport = MemoryTransport::new(|_, _| {
+        let chunks = ndjson(&[chat_reply("Hel", false), chat_reply("lo", true)]);
+        // Split mid-object to check decoding across chunk boundaries
+        let (first, second) = chunks.split_at(20);
+        let body = futures::stream::iter([Ok(first.to_vec()), Ok(second.to_vec())]).boxed();
+        Ok(TransportResponse::new(200, ResponseBody::Stream(body)))
+    });
+    let client = make_client(&transport, 0);
+
+    let text: String = client
+        .chat_stream(&ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hi")]))
+        .await
+        .unwrap()
+        .map(|chunk| chunk.unwrap().content().unwrap_or_default().to_string())
+        .collect::<Vec<_>>()
+        .await
+        .concat();
+
+    assert_eq!(text, "Hello");
+    assert!(transport.requests()[0].streaming);
+}
+
+#[test]
+fn test_blocking_stream_from_reader_body() {
+    let transport = MemoryTransport::new(|_, _| {
+        let body = ndjson(&[
+            json!({"response": "Hel", "done": false}),
+            json!({"response": "lo", "done": true}),
+        ]);
+        Ok(TransportResponse::new(
+            200,
+            ResponseBody::Reader(Box::new(Cursor::new(body))),
+        ))
+    });
+    let client = make_client(&transport, 0);
+
+    let text: String = client
+        .generate_stream_blocking(&GenerateRequest::new("qwen3:0.6b", "Hi"))
+        .unwrap()
+        .map(|chunk| chunk.unwrap().text().unwrap_or_default().to_string())
+        .collect();
+
+    assert_eq!(text, "Hello");
+}
+
+// ============================================================================
+// Retry and Error Tests
+// ============================================================================
+
+#[test]
+fn test_connection_errors_are_retried_for_any_endpoint() {
+    let transport = MemoryTransport::new(|attempt, _| match attempt {
+        0 => Err(Error::ConnectionError("refused".to_string())),
+        _ => Ok(TransportResponse::json(200, &chat_reply("ok", true))),
+    });
+    let client = make_client(&transport, 2);
+
+    let response = client
+        .chat_blocking(&ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hi")]))
+        .unwrap();
+
+    assert_eq!(response.content(), Some("ok"));
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_retryable_status_is_retried_over_transport() {
+    let transport = MemoryTransport::new(|attempt, _| match attempt {
+        0 => Ok(TransportResponse::json(503, &json!({"error": "busy"}))),
+        _ => Ok(TransportResponse::json(200, &json!({"version": "0.12.6"}))),
+    });
+    let client = make_client(&transport, 2);
+
+    assert_eq!(client.version().await.unwrap().version, "0.12.6");
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_error_status_keeps_server_message() {
+    let transport = MemoryTransport::new(|_, _| {
+        Ok(TransportResponse::json(
+            404,
+            &json!({"error": "model 'nope' not found"}),
+        ))
+    });
+    let client = make_client(&transport, 2);
+
+    let result = client.generate(&GenerateRequest::new("nope", "Hi")).await;
+
+    let Err(error) = result else {
+        panic!("expected an error");
+    };
+    assert_eq!(error.status_code(), Some(404));
+    assert!(error.to_string().contains("model 'nope' not found"));
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn test_transport_errors_are_returned_unchanged() {
+    let transport = MemoryTransport::new(|_, _| Err(Error::AuthError("token expired".to_string())));
+    let client = make_client(&transport, 3);
+
+    let result = client.version_blocking();
+
+    assert!(matches!(result, Err(Error::AuthError(message)) if message == "token expired"));
+    assert_eq!(transport.requests().len(), 1);
+}
@@ -0,0 +1,202 @@
+//! Tests for OllamaClient over a Unix domain socket base URL
+#![cfg(unix)]
+
+use futures::StreamExt;
+use ollama_oxide::testing::{MockOllama, MockResponse};
+use ollama_oxide::{
+    Auth, ChatMessage, ChatRequest, ClientConfig, Error, GenerateRequest, OllamaApiAsync,
+    OllamaApiSync, OllamaClient, PullRequest,
+};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Path in the temporary directory, removed when dropped
+struct TempPath(PathBuf);
+
+impl TempPath {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!(
+            "ollama-oxide-{}-{}.sock",
+            std::process::id(),
+            name
+        )))
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn make_config(base_url: String) -> ClientConfig {
+    ClientConfig {
+        base_url,
+        timeout: Duration::from_secs(30),
+        max_retries: 0,
+        ..Default::default()
+    }
+}
+
+fn chat_reply(content: &str, done: bool) -> Value {
+    json!({
+        "model": "qwen3:0.6b",
+        "message": {"role": "assistant", "content": content},
+        "done": done
+    })
+}
+
+// ============================================================================
+// Async Tests
+// ============================================================================
+
+#[tokio::test]
+async fn test_unix_socket_chat_async() {
+    let socket = TempPath::new("chat");
+    let server = MockOllama::start_unix(&socket.0).unwrap();
+    server.on_chat(MockResponse::json(chat_reply("Hi!", true)));
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    let response = client
+        .chat(&ChatRequest::new(
+            "qwen3:0.6b",
+            [ChatMessage::user("Hello")],
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.content(), Some("Hi!"));
+    let recorded = server.last_request().unwrap();
+    assert_eq!(recorded.path, "/api/chat");
+    assert_eq!(recorded.header("host"), Some("localhost"));
+}
+
+#[tokio::test]
+async fn test_unix_socket_stream_async() {
+    let socket = TempPath::new("chat-stream");
+    let server = MockOllama::start_unix(&socket.0).unwrap();
+    server.on_chat(
+        MockResponse::stream([chat_reply("Hel", false), chat_reply("lo", true)])
+            .with_chunk_delay(Duration::from_millis(10)),
+    );
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    let text: String = client
+        .chat_stream(&ChatRequest::new("qwen3:0.6b", [ChatMessage::user("Hi")]))
+        .await
+        .unwrap()
+        .map(|chunk| chunk.unwrap().content().unwrap_or_default().to_string())
+        .collect::<Vec<_>>()
+        .await
+        .concat();
+
+    assert_eq!(text, "Hello");
+}
+
+#[tokio::test]
+async fn test_unix_socket_sends_credentials() {
+    let socket = TempPath::new("auth");
+    let server = MockOllama::start_unix(&socket.0).unwrap();
+    server.on_tags(MockResponse::json(json!({"models": []})));
+    let config = ClientConfig {
+        auth: Some(Auth::bearer("secret")),
+        ..make_config(server.url())
+    };
+    let client = OllamaClient::new(config).unwrap();
+
+    client.list_models().await.unwrap();
+
+    assert_eq!(
+        server.last_request().unwrap().header("authorization"),
+        Some("Bearer secret")
+    );
+}
+
+// ============================================================================
+// Blocking Tests
+// ============================================================================
+
+#[test]
+fn test_unix_socket_blocking() {
+    let socket = TempPath::new("blocking");
+    let server = MockOllama::start_unix(&socket.0).unwrap();
+    server
+        .on_version(MockResponse::json(json!({"version": "0.12.6"})))
+        .on_generate(MockResponse::stream([
+            json!({"response": "Hel", "done": false}),
+            json!({"response": "lo", "done": true}),
+        ]));
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    assert_eq!(client.version_blocking().unwrap().version, "0.12.6");
+    let text: String = client
+        .generate_stream_blocking(&GenerateRequest::new("qwen3:0.6b", "Hi"))
+        .unwrap()
+        .map(|chunk| chunk.unwrap().text().unwrap_or_default().to_string())
+        .collect();
+    assert_eq!(text, "Hello");
+}
+
+#[test]
+fn test_unix_socket_blocking_pull_stream() {
+    let socket = TempPath::new("pull");
+    let server = MockOllama::start_unix(&socket.0).unwrap();
+    server.on_pull(MockResponse::stream([
+        json!({"status": "pulling manifest"}),
+        json!({"status": "success"}),
+    ]));
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    let events: Vec<_> = client
+        .pull_model_stream_blocking(&PullRequest::new("llama3.2"))
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect();
+
+    assert_eq!(events.len(), 2);
+    assert!(events[1].is_success());
+}
+
+#[test]
+fn test_unix_socket_blob_upload_blocking() {
+    let socket = TempPath::new("blob");
+    let file = TempPath::new("blob-file");
+    std::fs::write(&file.0, b"hello world").unwrap();
+    let server = MockOllama::start_unix(&socket.0).unwrap();
+    server.on_blob_upload(MockResponse::empty(201));
+    let client = OllamaClient::new(make_config(server.url())).unwrap();
+
+    client.push_blob_blocking("sha256:abc", &file.0).unwrap();
+
+    let recorded = server.last_request().unwrap();
+    assert_eq!(recorded.path, "/api/blobs/sha256:abc");
+    assert_eq!(recorded.body, b"hello world");
+}
+
+// ============================================================================
+// Error Tests
+// ============================================================================
+
+#[test]
+fn test_unix_socket_relative_path_is_rejected() {
+    let result = OllamaClient::with_base_url("unix://ollama.sock");
+
+    assert!(
+        matches!(result, Err(Error::ConfigError(message)) if message.contains("absolute path"))
+    );
+}
+
+#[test]
+fn test_unix_socket_missing_is_connection_failure() {
+    let socket = TempPath::new("missing");
+    let client = OllamaClient::new(ClientConfig {
+        max_retries: 1,
+        ..make_config(format!("unix://{}", socket.0.display()))
+    })
+    .unwrap();
+
+    let result = client.version_blocking();
+
+    assert!(matches!(result, Err(Error::MaxRetriesExceededError(1))));
+}